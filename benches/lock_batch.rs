@@ -0,0 +1,37 @@
+//! Benchmark: add() por elemento vs add_batch() con un único lock
+//!
+//! Ejecutar con: cargo bench --bench lock_batch
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+#[path = "../exercises/concurrency/shared_counter.rs"]
+mod shared_counter;
+
+use shared_counter::SharedCounter;
+
+fn bench_lock_batch(c: &mut Criterion) {
+    let values: Vec<i32> = (0..1000).collect();
+    let mut group = c.benchmark_group("shared_counter_1000");
+    
+    group.bench_function("add_per_element", |b| {
+        b.iter(|| {
+            let counter = SharedCounter::new();
+            for &value in &values {
+                counter.add(black_box(value)).unwrap();
+            }
+            counter.get().unwrap()
+        })
+    });
+    
+    group.bench_function("add_batch", |b| {
+        b.iter(|| {
+            let counter = SharedCounter::new();
+            counter.add_batch(black_box(&values)).unwrap()
+        })
+    });
+    
+    group.finish();
+}
+
+criterion_group!(benches, bench_lock_batch);
+criterion_main!(benches);
@@ -0,0 +1,130 @@
+//! 🦀 Backoff Channel - Backpressure en canales acotados
+//!
+//! Un `sync_channel(capacity)` bloquea `send` cuando está lleno. Con
+//! `try_send` el productor decide qué hacer: aquí espera con backoff
+//! exponencial (1x, 2x, 4x... hasta `max_delay`) y, si el consumidor no
+//! se pone al día tras `max_attempts` intentos, devuelve el mensaje en
+//! lugar de bloquearse para siempre.
+
+use std::fmt;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, Receiver, SyncSender, TrySendError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Error de `BackoffSender::send`; devuelve el mensaje que no se pudo enviar
+#[derive(Debug, PartialEq)]
+pub enum SendError<T> {
+    /// El canal siguió lleno durante todos los intentos
+    Full(T),
+    /// El receptor ya no existe
+    Disconnected(T),
+}
+
+impl<T> fmt::Display for SendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SendError::Full(_) => write!(f, "Canal lleno tras agotar los reintentos"),
+            SendError::Disconnected(_) => write!(f, "Receptor desconectado"),
+        }
+    }
+}
+
+impl<T: fmt::Debug> std::error::Error for SendError<T> {}
+
+/// Sender que reintenta con backoff exponencial cuando el canal está lleno
+#[derive(Debug)]
+pub struct BackoffSender<T> {
+    sender: SyncSender<T>,
+    max_attempts: u32,
+    initial_delay: Duration,
+    max_delay: Duration,
+    retries: AtomicUsize,
+}
+
+impl<T> BackoffSender<T> {
+    pub fn new(sender: SyncSender<T>, max_attempts: u32, initial_delay: Duration, max_delay: Duration) -> Self {
+        Self {
+            sender,
+            max_attempts: max_attempts.max(1),
+            initial_delay,
+            max_delay,
+            retries: AtomicUsize::new(0),
+        }
+    }
+    
+    /// Envía `value`, esperando cada vez el doble mientras el canal esté lleno
+    pub fn send(&self, value: T) -> Result<(), SendError<T>> {
+        let mut value = value;
+        let mut delay = self.initial_delay;
+        
+        for attempt in 1..=self.max_attempts {
+            match self.sender.try_send(value) {
+                Ok(()) => return Ok(()),
+                Err(TrySendError::Disconnected(v)) => return Err(SendError::Disconnected(v)),
+                Err(TrySendError::Full(v)) if attempt == self.max_attempts => return Err(SendError::Full(v)),
+                Err(TrySendError::Full(v)) => {
+                    // CORREGIDO: try_send devuelve el valor, así no se pierde al reintentar
+                    value = v;
+                    self.retries.fetch_add(1, Ordering::Relaxed);
+                    thread::sleep(delay);
+                    delay = (delay * 2).min(self.max_delay);
+                }
+            }
+        }
+        unreachable!("max_attempts es al menos 1")
+    }
+    
+    /// Número total de esperas por canal lleno
+    pub fn retries(&self) -> usize {
+        self.retries.load(Ordering::Relaxed)
+    }
+}
+
+/// Crea un canal acotado con un `BackoffSender` como extremo emisor
+pub fn backoff_channel<T>(
+    capacity: usize,
+    max_attempts: u32,
+    initial_delay: Duration,
+) -> (BackoffSender<T>, Receiver<T>) {
+    let (sender, receiver) = mpsc::sync_channel(capacity);
+    // Con el límite en 64x el delay inicial la espera máxima por mensaje queda acotada
+    let sender = BackoffSender::new(sender, max_attempts, initial_delay, initial_delay * 64);
+    (sender, receiver)
+}
+
+/// Función que demuestra backpressure con un productor rápido y un consumidor lento
+pub fn demonstrate_backoff_channel() {
+    println!("\n✅ Demostrando backpressure con BackoffSender...");
+    
+    let (sender, receiver) = backoff_channel(1, 8, Duration::from_millis(1));
+    let start = Instant::now();
+    
+    let consumer = thread::spawn(move || {
+        let mut received = Vec::new();
+        for value in receiver {
+            thread::sleep(Duration::from_millis(10));
+            received.push(value);
+        }
+        received
+    });
+    
+    for i in 0..5 {
+        match sender.send(i) {
+            Ok(()) => println!("Enviado {} (reintentos acumulados: {})", i, sender.retries()),
+            Err(e) => println!("No se pudo enviar {}: {}", i, e),
+        }
+    }
+    let retries = sender.retries();
+    drop(sender);
+    
+    let received = consumer.join().unwrap();
+    println!("Recibidos en orden: {:?} en {:?}", received, start.elapsed());
+    println!("El productor esperó {} veces en lugar de bloquearse", retries);
+    
+    // Sin consumidor el mensaje vuelve al productor
+    let (sender, receiver) = backoff_channel(1, 3, Duration::from_millis(1));
+    sender.send("primero").unwrap();
+    drop(receiver);
+    println!("Tras cerrar el receptor: {:?}", sender.send("segundo"));
+}
@@ -0,0 +1,113 @@
+//! 🦀 Cancellation - Cancelación cooperativa de threads
+//!
+//! Rust no permite matar un thread desde fuera: podría quedarse con un
+//! lock tomado o dejar datos a medias. En su lugar, el thread comprueba
+//! un flag compartido en puntos seguros de su bucle y termina por sí
+//! mismo, liberando sus recursos con normalidad.
+
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Error devuelto por `CancellationToken::cancelled` tras una cancelación
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cancelled;
+
+impl fmt::Display for Cancelled {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Operación cancelada")
+    }
+}
+
+impl std::error::Error for Cancelled {}
+
+/// Flag de cancelación clonable: todos los clones comparten el mismo estado
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Pide a todos los que comparten el token que terminen; es idempotente
+    pub fn cancel(&self) {
+        // Release: lo escrito antes de cancelar es visible para quien vea el flag
+        self.cancelled.store(true, Ordering::Release);
+    }
+    
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Acquire)
+    }
+    
+    /// Para bucles que devuelven `Result`: `token.cancelled()?` sale en cuanto se cancela
+    pub fn cancelled(&self) -> Result<(), Cancelled> {
+        if self.is_cancelled() {
+            Err(Cancelled)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Procesa lotes hasta terminar o hasta que se cancele el token
+fn process_batches(token: &CancellationToken, batches: u32) -> Result<u32, Cancelled> {
+    let mut processed = 0;
+    for _ in 0..batches {
+        // CORREGIDO: Se comprueba entre lotes, nunca a mitad de uno
+        token.cancelled()?;
+        thread::sleep(Duration::from_millis(5));
+        processed += 1;
+    }
+    Ok(processed)
+}
+
+/// Función que demuestra cancelar workers de forma cooperativa
+pub fn demonstrate_cancellation() {
+    println!("\n✅ Demostrando cancelación cooperativa con CancellationToken...");
+    
+    let token = CancellationToken::new();
+    
+    let handles: Vec<_> = (0..3)
+        .map(|i| {
+            let token = token.clone();
+            thread::spawn(move || {
+                let mut iterations = 0u64;
+                while !token.is_cancelled() {
+                    iterations += 1;
+                    thread::sleep(Duration::from_millis(1));
+                }
+                (i, iterations)
+            })
+        })
+        .collect();
+    
+    thread::sleep(Duration::from_millis(30));
+    let cancelled_at = Instant::now();
+    token.cancel();
+    
+    for handle in handles {
+        let (i, iterations) = handle.join().unwrap();
+        println!("Worker {} terminó tras {} iteraciones", i, iterations);
+    }
+    println!("Todos los workers terminaron {:?} después de cancel()", cancelled_at.elapsed());
+    
+    let token = CancellationToken::new();
+    let worker = {
+        let token = token.clone();
+        thread::spawn(move || process_batches(&token, 100))
+    };
+    thread::sleep(Duration::from_millis(20));
+    token.cancel();
+    match worker.join().unwrap() {
+        Ok(processed) => println!("Procesados {} lotes", processed),
+        Err(e) => println!("Worker con Result: {}", e),
+    }
+    
+    // Un token nuevo no está cancelado: el trabajo completo termina con Ok
+    println!("Sin cancelar: {:?}", process_batches(&CancellationToken::new(), 3));
+}
@@ -4,30 +4,81 @@
 //! mostrando las mejores prácticas de concurrencia en Rust.
 
 use std::thread;
-use std::sync::{Arc, Mutex, RwLock};
+use std::sync::{Arc, Mutex, MutexGuard, RwLock};
 use std::sync::mpsc;
 use std::time::Duration;
 
+mod backoff_channel;
+mod cancellation;
+mod double_buffer;
+mod fn_barrier;
+mod instrumented_rwlock;
+mod latch;
+mod parallel_find;
+mod poison;
+mod rate_limiter;
+mod semaphore;
+mod sharded_map;
+mod shared_counter;
+mod structured_logging;
+mod thread_local_counter;
+mod wait_group;
+
+use shared_counter::SharedCounter;
+
 /// Estructura que representa un contador compartido
 #[derive(Debug)]
 struct Counter {
     value: i32,
+    /// Un sender por suscriptor; cada cambio de valor se envía a todos
+    subscribers: Vec<mpsc::Sender<i32>>,
 }
 
 impl Counter {
     fn new() -> Self {
-        Self { value: 0 }
+        Self {
+            value: 0,
+            subscribers: Vec::new(),
+        }
     }
     
     /// CORREGIDO: Método que incrementa el contador
     fn increment(&mut self) {
         self.value += 1;
+        self.notify();
+    }
+    
+    fn decrement(&mut self) {
+        self.value -= 1;
+        self.notify();
+    }
+    
+    fn reset(&mut self) {
+        self.value = 0;
+        self.notify();
     }
     
     /// CORREGIDO: Método que obtiene el valor
     fn get_value(&self) -> i32 {
         self.value
     }
+    
+    /// Canal que recibe el nuevo valor tras cada increment, decrement o reset
+    fn subscribe(&mut self) -> mpsc::Receiver<i32> {
+        let (tx, rx) = mpsc::channel();
+        self.subscribers.push(tx);
+        rx
+    }
+    
+    fn subscriber_count(&self) -> usize {
+        self.subscribers.len()
+    }
+    
+    fn notify(&mut self) {
+        let value = self.value;
+        // CORREGIDO: send falla si el receptor se soltó; ese suscriptor se descarta en lugar de hacer panic
+        self.subscribers.retain(|tx| tx.send(value).is_ok());
+    }
 }
 
 /// Función que demuestra threads correctos
@@ -305,17 +356,129 @@ fn demonstrate_error_handling_concurrency() {
     }
 }
 
+/// Adquiere el lock aunque esté envenenado por un panic en otro thread
+///
+/// El envenenamiento solo avisa de que un thread murió con el lock tomado;
+/// los datos siguen ahí y `into_inner()` devuelve el guard igualmente.
+fn recover_poisoned<T>(m: &Mutex<T>) -> MutexGuard<'_, T> {
+    match m.lock() {
+        Ok(guard) => guard,
+        Err(poisoned) => {
+            println!("⚠️  Mutex envenenado, recuperando el guard");
+            poisoned.into_inner()
+        }
+    }
+}
+
+/// Función que demuestra recuperación de un Mutex envenenado
+fn demonstrate_poison_recovery() {
+    println!("\n✅ Demostrando recuperación de Mutex envenenado...");
+    
+    let data = Arc::new(Mutex::new(vec![1, 2, 3]));
+    
+    // Un thread hace panic mientras tiene el lock
+    let data_clone = Arc::clone(&data);
+    let handle = thread::spawn(move || {
+        let _guard = data_clone.lock().unwrap();
+        panic!("Panic intencional con el lock adquirido");
+    });
+    
+    // CORREGIDO: El join devuelve Err pero no propagamos el panic
+    if handle.join().is_err() {
+        println!("El thread terminó con panic");
+    }
+    
+    println!("¿Mutex envenenado? {}", data.is_poisoned());
+    
+    // CORREGIDO: Recuperar el guard en lugar de hacer unwrap()
+    let guard = recover_poisoned(&data);
+    println!("Datos recuperados: {:?}", *guard);
+}
+
+/// Función que demuestra suscribirse a los cambios de un contador
+fn demonstrate_counter_subscription() {
+    println!("\n✅ Demostrando suscripción a cambios del contador...");
+    
+    let mut counter = Counter::new();
+    let ui = counter.subscribe();
+    let short_lived = counter.subscribe();
+    
+    let printer = thread::spawn(move || {
+        // El bucle termina cuando el contador (y con él el sender) se suelta
+        for value in ui {
+            println!("Suscriptor: el contador vale {}", value);
+        }
+    });
+    
+    counter.increment();
+    drop(short_lived);
+    counter.increment();
+    counter.decrement();
+    counter.reset();
+    println!("Suscriptores activos: {} (el soltado se descartó)", counter.subscriber_count());
+    
+    drop(counter);
+    printer.join().unwrap();
+}
+
+/// Función que demuestra operaciones por lotes con un solo lock
+fn demonstrate_batch_operations() {
+    println!("\n✅ Demostrando operaciones por lotes con un solo lock...");
+    
+    let counter = SharedCounter::new();
+    let mut handles = vec![];
+    
+    // CORREGIDO: Cada thread aplica su lote completo con un único lock
+    for i in 0..4 {
+        let counter_clone = counter.clone();
+        let handle = thread::spawn(move || {
+            let batch: Vec<i32> = (1..=100).map(|x| x * (i + 1)).collect();
+            match counter_clone.add_batch(&batch) {
+                Ok(total) => println!("Thread {} aplicó su lote. Total parcial: {}", i, total),
+                Err(e) => println!("Thread {}: {}", i, e),
+            }
+        });
+        handles.push(handle);
+    }
+    
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    
+    // Un valor suelto sigue disponible con add()
+    match counter.add(1).and_then(|_| counter.get()) {
+        Ok(total) => println!("Total final: {}", total),
+        Err(e) => println!("Error al leer el total: {}", e),
+    }
+}
+
 fn main() {
     println!("🦀 Rust Lab - Concurrency SOLUCIÓN CORRECTA");
     println!("{}", "=".repeat(60));
     
     demonstrate_threads_correct();
     demonstrate_rwlock_correct();
+    instrumented_rwlock::demonstrate_rwlock_stats();
     demonstrate_channels_correct();
     demonstrate_synchronization_correct();
     demonstrate_deadlock_prevention();
     demonstrate_lifetime_correct();
     demonstrate_error_handling_concurrency();
+    demonstrate_poison_recovery();
+    demonstrate_batch_operations();
+    demonstrate_counter_subscription();
+    structured_logging::demonstrate_structured_logging();
+    rate_limiter::demonstrate_rate_limiter();
+    sharded_map::demonstrate_sharded_map();
+    backoff_channel::demonstrate_backoff_channel();
+    cancellation::demonstrate_cancellation();
+    parallel_find::demonstrate_parallel_find();
+    semaphore::demonstrate_semaphore();
+    latch::demonstrate_latch();
+    fn_barrier::demonstrate_fn_barrier();
+    wait_group::demonstrate_wait_group();
+    double_buffer::demonstrate_double_buffer();
+    thread_local_counter::demonstrate_thread_local_counter();
     
     println!("\n✅ Todas las demostraciones completadas sin errores!");
     println!("🎯 Conceptos clave demostrados:");
@@ -323,9 +486,25 @@ fn main() {
     println!("   - Arc<T>: Referencias atómicas compartidas");
     println!("   - Mutex<T>: Exclusión mutua");
     println!("   - RwLock<T>: Lectores múltiples, escritor único");
+    println!("   - Lock instrumentation: Contar read y write locks con atómicos");
     println!("   - Channels: Comunicación entre threads");
     println!("   - Deadlock prevention: Prevención de bloqueos");
     println!("   - Error handling: Manejo de errores en concurrencia");
+    println!("   - Poisoning: Recuperación de Mutex envenenados");
+    println!("   - Batch locking: Un solo lock para todo el lote");
+    println!("   - Subscriptions: Un canal por suscriptor, descartando los que se soltaron");
+    println!("   - Structured logging: Eventos tipados con un trait Logger");
+    println!("   - Rate limiting: Token bucket compartido entre threads");
+    println!("   - Sharding: Un RwLock por shard en lugar de un lock global");
+    println!("   - Thread-local tallies: Contar sin contención y sumar al final");
+    println!("   - Backpressure: try_send con backoff en canales acotados");
+    println!("   - Cancellation: Los threads terminan solos al ver un flag compartido");
+    println!("   - Early termination: El primer thread que encuentra el elemento detiene la búsqueda de los demás");
+    println!("   - Semaphore: Como mucho N threads dentro a la vez");
+    println!("   - Latch: Señal de un solo uso que despierta a todos los que esperan");
+    println!("   - Leader callback: El último thread de la barrera trabaja antes de liberar al resto");
+    println!("   - WaitGroup: Esperar a threads lanzados dinámicamente sin guardar sus handles");
+    println!("   - Double buffering: Los lectores leen una copia mientras se prepara la otra");
 }
 
 
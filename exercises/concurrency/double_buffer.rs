@@ -0,0 +1,115 @@
+//! 🦀 Double Buffer - Compartir datos que se leen mucho y se escriben poco
+//!
+//! Con un único `RwLock`, mientras el escritor prepara el nuevo estado
+//! todos los lectores esperan. Con dos copias, el escritor trabaja en la
+//! que nadie está leyendo y al terminar cambia un índice atómico: los
+//! lectores siempre encuentran una copia completa y casi nunca se cruzan
+//! con el escritor.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, RwLock, RwLockReadGuard, RwLockWriteGuard};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use super::poison;
+
+/// Dos copias de `T` y el índice de la que está publicada
+#[derive(Debug)]
+pub struct DoubleBuffer<T: Clone> {
+    slots: [RwLock<T>; 2],
+    live: AtomicUsize,
+    /// Serializa a los escritores: solo uno puede preparar la copia inactiva
+    writer: Mutex<()>,
+}
+
+impl<T: Clone> DoubleBuffer<T> {
+    pub fn new(initial: T) -> Self {
+        Self {
+            slots: [RwLock::new(initial.clone()), RwLock::new(initial)],
+            live: AtomicUsize::new(0),
+            writer: Mutex::new(()),
+        }
+    }
+    
+    // Un panic en `update` solo puede dejar a medias la copia inactiva, que
+    // se vuelve a copiar entera en el siguiente `update`: el veneno se ignora
+    fn read_slot(&self, index: usize) -> RwLockReadGuard<'_, T> {
+        poison::read(&self.slots[index])
+    }
+    
+    fn write_slot(&self, index: usize) -> RwLockWriteGuard<'_, T> {
+        poison::write(&self.slots[index])
+    }
+    
+    /// Copia del estado publicado
+    pub fn read(&self) -> T {
+        let live = self.live.load(Ordering::Acquire);
+        self.read_slot(live).clone()
+    }
+    
+    /// Aplica `f` a una copia del estado publicado en la otra ranura y la publica
+    pub fn update(&self, f: impl FnOnce(&mut T)) {
+        let _writer = poison::lock(&self.writer);
+        // Solo el escritor cambia `live` y lo tenemos en exclusiva
+        let live = self.live.load(Ordering::Relaxed);
+        let next = 1 - live;
+        
+        let mut slot = self.write_slot(next);
+        slot.clone_from(&self.read_slot(live));
+        f(&mut slot);
+        // CORREGIDO: Se publica antes de soltar la ranura; un lector que llegue
+        // tarde a ella ve un estado ya publicado, nunca uno que aún no lo está
+        self.live.store(next, Ordering::Release);
+    }
+}
+
+/// Estado de ejemplo: la versión crece con cada publicación
+#[derive(Debug, Clone)]
+struct Snapshot {
+    version: u64,
+    values: Vec<u64>,
+}
+
+/// Función que demuestra lectores que nunca ven un estado a medias
+pub fn demonstrate_double_buffer() {
+    println!("\n✅ Demostrando DoubleBuffer para lecturas frecuentes...");
+    
+    let buffer = Arc::new(DoubleBuffer::new(Snapshot { version: 0, values: vec![0; 64] }));
+    let start = Instant::now();
+    
+    let readers: Vec<_> = (0..4)
+        .map(|_| {
+            let buffer = Arc::clone(&buffer);
+            thread::spawn(move || {
+                let mut reads = 0u64;
+                let mut inconsistent = 0u64;
+                while start.elapsed() < Duration::from_millis(50) {
+                    let snapshot = buffer.read();
+                    // Todos los valores se escriben con la versión: si difieren, la copia está a medias
+                    if snapshot.values.iter().any(|&v| v != snapshot.version) {
+                        inconsistent += 1;
+                    }
+                    reads += 1;
+                }
+                (reads, inconsistent)
+            })
+        })
+        .collect();
+    
+    for _ in 0..20 {
+        buffer.update(|snapshot| {
+            snapshot.version += 1;
+            for value in &mut snapshot.values {
+                *value = snapshot.version;
+            }
+        });
+        thread::sleep(Duration::from_millis(2));
+    }
+    
+    let (reads, inconsistent) = readers
+        .into_iter()
+        .map(|handle| handle.join().unwrap())
+        .fold((0, 0), |(r, i), (reads, inconsistent)| (r + reads, i + inconsistent));
+    println!("Lecturas: {}, estados a medias: {}", reads, inconsistent);
+    println!("Versión publicada: {}", buffer.read().version);
+}
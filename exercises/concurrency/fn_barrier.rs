@@ -0,0 +1,123 @@
+//! 🦀 Fn Barrier - Una `Barrier` que ejecuta un callback al completarse
+//!
+//! Con `std::sync::Barrier` el último thread en llegar se entera por
+//! `BarrierWaitResult::is_leader`, pero los demás ya están liberados
+//! mientras el líder trabaja. `FnBarrier` ejecuta `on_complete` en el
+//! último thread *antes* de liberar a nadie: cuando `wait` vuelve, el
+//! trabajo del líder (juntar resultados, limpiar, pasar de fase) ya está
+//! hecho y es visible para todos.
+
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::thread;
+
+use super::poison;
+
+#[derive(Debug, Default)]
+struct BarrierState {
+    /// Threads esperando en la ronda actual
+    arrived: usize,
+    /// Rondas completadas; distingue un despertar de la ronda actual de uno espurio
+    generation: usize,
+}
+
+/// Barrera reutilizable para `n` threads que llama a `on_complete` una vez por ronda
+pub struct FnBarrier {
+    n: usize,
+    state: Mutex<BarrierState>,
+    released: Condvar,
+    on_complete: Box<dyn Fn() + Send + Sync>,
+}
+
+impl FnBarrier {
+    /// Como `Barrier::new`, `n == 0` se trata como 1
+    pub fn new(n: usize, on_complete: impl Fn() + Send + Sync + 'static) -> Self {
+        Self {
+            n: n.max(1),
+            state: Mutex::new(BarrierState::default()),
+            released: Condvar::new(),
+            on_complete: Box::new(on_complete),
+        }
+    }
+    
+    fn lock_state(&self) -> MutexGuard<'_, BarrierState> {
+        poison::lock(&self.state)
+    }
+    
+    /// Bloquea hasta que lleguen `n` threads; devuelve `true` en el que ejecutó `on_complete`
+    ///
+    /// El callback se ejecuta con el lock tomado: no debe llamar a `wait`
+    /// sobre esta misma barrera. Si hace panic, los demás threads se
+    /// liberan igualmente y el panic sigue en el líder.
+    pub fn wait(&self) -> bool {
+        let mut state = self.lock_state();
+        let generation = state.generation;
+        state.arrived += 1;
+        
+        if state.arrived < self.n {
+            // CORREGIDO: Se compara la generación, no `arrived`, porque el líder lo pone a 0 al liberar
+            while state.generation == generation {
+                state = poison::wait(&self.released, state);
+            }
+            return false;
+        }
+        
+        let result = panic::catch_unwind(AssertUnwindSafe(|| (self.on_complete)()));
+        state.arrived = 0;
+        state.generation += 1;
+        drop(state);
+        self.released.notify_all();
+        
+        if let Err(payload) = result {
+            panic::resume_unwind(payload);
+        }
+        true
+    }
+}
+
+/// Función que demuestra un líder que cierra cada fase antes de liberar a los demás
+pub fn demonstrate_fn_barrier() {
+    println!("\n✅ Demostrando FnBarrier con callback del último thread...");
+    
+    const WORKERS: usize = 4;
+    const PHASES: usize = 3;
+    let processed = Arc::new(AtomicUsize::new(0));
+    let completed_phases = Arc::new(AtomicUsize::new(0));
+    
+    let barrier = {
+        let processed = Arc::clone(&processed);
+        let completed_phases = Arc::clone(&completed_phases);
+        Arc::new(FnBarrier::new(WORKERS, move || {
+            let phase = completed_phases.fetch_add(1, Ordering::SeqCst) + 1;
+            println!("Fase {} cerrada: {} elementos procesados", phase, processed.load(Ordering::SeqCst));
+        }))
+    };
+    
+    let handles: Vec<_> = (0..WORKERS)
+        .map(|i| {
+            let barrier = Arc::clone(&barrier);
+            let processed = Arc::clone(&processed);
+            let completed_phases = Arc::clone(&completed_phases);
+            thread::spawn(move || {
+                let mut leader_rounds = 0;
+                for phase in 0..PHASES {
+                    processed.fetch_add(10 * (i + 1), Ordering::SeqCst);
+                    if barrier.wait() {
+                        leader_rounds += 1;
+                    }
+                    // Tras wait, el callback de esta fase ya terminó
+                    assert!(completed_phases.load(Ordering::SeqCst) > phase);
+                }
+                leader_rounds
+            })
+        })
+        .collect();
+    
+    let leader_rounds: usize = handles.into_iter().map(|handle| handle.join().unwrap()).sum();
+    println!(
+        "Fases completadas: {}, rondas con líder: {} (una por fase)",
+        completed_phases.load(Ordering::SeqCst),
+        leader_rounds
+    );
+}
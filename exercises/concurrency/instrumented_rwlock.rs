@@ -0,0 +1,146 @@
+//! 🦀 Instrumented RwLock - Contar lecturas y escrituras de un `RwLock`
+//!
+//! Un `RwLock` no dice cuántas veces se leyó o escribió, ni cuántos
+//! lectores lo tienen a la vez. `InstrumentedRwLock` envuelve uno y lleva
+//! esas cuentas en atómicos, para poder ver en un test o en la demo si
+//! el patrón de uso es el esperado (muchas lecturas, pocas escrituras).
+
+use std::ops::Deref;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, LockResult, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
+use std::thread;
+
+/// Foto de los contadores de un `InstrumentedRwLock`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RwLockStats {
+    /// Read locks concedidos desde la creación
+    pub read_locks: usize,
+    /// Write locks concedidos desde la creación
+    pub write_locks: usize,
+    /// Lectores con el lock en el momento de la foto
+    pub current_readers: usize,
+}
+
+/// `RwLock<T>` que cuenta los locks que concede
+#[derive(Debug, Default)]
+pub struct InstrumentedRwLock<T> {
+    inner: RwLock<T>,
+    read_locks: AtomicUsize,
+    write_locks: AtomicUsize,
+    current_readers: AtomicUsize,
+}
+
+/// Read guard que descuenta su lector al soltarse
+pub struct InstrumentedReadGuard<'a, T> {
+    guard: RwLockReadGuard<'a, T>,
+    current_readers: &'a AtomicUsize,
+}
+
+impl<T> Deref for InstrumentedReadGuard<'_, T> {
+    type Target = T;
+    
+    fn deref(&self) -> &T {
+        &self.guard
+    }
+}
+
+impl<T> Drop for InstrumentedReadGuard<'_, T> {
+    fn drop(&mut self) {
+        // Se descuenta antes de que `guard` suelte el lock, así que nunca se cuenta
+        // a un lector que ya no lo tiene
+        self.current_readers.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+impl<T> InstrumentedRwLock<T> {
+    pub fn new(value: T) -> Self {
+        Self {
+            inner: RwLock::new(value),
+            read_locks: AtomicUsize::new(0),
+            write_locks: AtomicUsize::new(0),
+            current_readers: AtomicUsize::new(0),
+        }
+    }
+    
+    fn wrap_read<'a>(&'a self, guard: RwLockReadGuard<'a, T>) -> InstrumentedReadGuard<'a, T> {
+        self.read_locks.fetch_add(1, Ordering::Relaxed);
+        self.current_readers.fetch_add(1, Ordering::Relaxed);
+        InstrumentedReadGuard {
+            guard,
+            current_readers: &self.current_readers,
+        }
+    }
+    
+    /// Igual que `RwLock::read`; un lock envenenado también cuenta como concedido
+    pub fn read(&self) -> LockResult<InstrumentedReadGuard<'_, T>> {
+        match self.inner.read() {
+            Ok(guard) => Ok(self.wrap_read(guard)),
+            Err(poisoned) => Err(PoisonError::new(self.wrap_read(poisoned.into_inner()))),
+        }
+    }
+    
+    /// Igual que `RwLock::write`; un lock envenenado también cuenta como concedido
+    pub fn write(&self) -> LockResult<RwLockWriteGuard<'_, T>> {
+        let result = self.inner.write();
+        self.write_locks.fetch_add(1, Ordering::Relaxed);
+        result
+    }
+    
+    /// Lee los tres contadores
+    ///
+    /// Cada contador se lee por separado y sin parar a los demás threads,
+    /// así que con el lock en uso la foto no es atómica: `current_readers`
+    /// puede haber cambiado antes de que el llamador lo mire, y un lector
+    /// que acaba de conseguir el lock puede no estar contado todavía. Es
+    /// exacto solo cuando ningún otro thread toca el lock, como en un test
+    /// que ya hizo `join` de todos.
+    pub fn stats(&self) -> RwLockStats {
+        RwLockStats {
+            read_locks: self.read_locks.load(Ordering::Relaxed),
+            write_locks: self.write_locks.load(Ordering::Relaxed),
+            current_readers: self.current_readers.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Función que demuestra medir cuántas lecturas y escrituras recibe un RwLock
+pub fn demonstrate_rwlock_stats() {
+    println!("\n✅ Demostrando RwLock instrumentado...");
+    
+    let config = Arc::new(InstrumentedRwLock::new(vec![0u32; 16]));
+    
+    let readers: Vec<_> = (0..4)
+        .map(|_| {
+            let config = Arc::clone(&config);
+            thread::spawn(move || {
+                let mut max_seen = 0;
+                for _ in 0..1_000 {
+                    if let Ok(values) = config.read() {
+                        max_seen = max_seen.max(values.iter().copied().max().unwrap_or(0));
+                    }
+                }
+                max_seen
+            })
+        })
+        .collect();
+    
+    for round in 1..=10 {
+        if let Ok(mut values) = config.write() {
+            values.fill(round);
+        }
+        if round == 5 {
+            println!("A mitad de las escrituras: {:?}", config.stats());
+        }
+    }
+    
+    let max_seen = readers.into_iter().map(|handle| handle.join().unwrap()).max().unwrap_or(0);
+    let stats = config.stats();
+    println!("Mayor valor visto por los lectores: {}", max_seen);
+    println!(
+        "Total: {} lecturas, {} escrituras ({:.0} lecturas por escritura), lectores ahora: {}",
+        stats.read_locks,
+        stats.write_locks,
+        stats.read_locks as f64 / stats.write_locks as f64,
+        stats.current_readers
+    );
+}
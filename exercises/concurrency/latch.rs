@@ -0,0 +1,83 @@
+//! 🦀 Latch - Señal de un solo uso para "esperar hasta que esté listo"
+//!
+//! Una `Barrier` espera a que lleguen N threads y se puede reutilizar.
+//! Un latch es más simple: empieza cerrado, un thread lo abre con `set`
+//! y a partir de ahí todos los que esperan (y los que lleguen después)
+//! continúan. No se vuelve a cerrar.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::thread;
+use std::time::Duration;
+
+use super::poison;
+
+/// Evento de un solo uso construido con `Mutex<bool>` + `Condvar`
+#[derive(Debug, Default)]
+pub struct Latch {
+    is_set: Mutex<bool>,
+    opened: Condvar,
+}
+
+impl Latch {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    fn lock_flag(&self) -> MutexGuard<'_, bool> {
+        poison::lock(&self.is_set)
+    }
+    
+    /// Bloquea hasta que se llame a `set`; si ya se llamó, vuelve enseguida
+    pub fn wait(&self) {
+        let mut is_set = self.lock_flag();
+        // CORREGIDO: En bucle, porque wait puede despertar sin que se haya llamado a set
+        while !*is_set {
+            is_set = poison::wait(&self.opened, is_set);
+        }
+    }
+    
+    /// Abre el latch y despierta a todos los threads en espera
+    pub fn set(&self) {
+        *self.lock_flag() = true;
+        self.opened.notify_all();
+    }
+    
+    pub fn is_set(&self) -> bool {
+        *self.lock_flag()
+    }
+}
+
+/// Función que demuestra arrancar varios workers a la vez con un latch
+pub fn demonstrate_latch() {
+    println!("\n✅ Demostrando Latch como señal de arranque...");
+    
+    let ready = Arc::new(Latch::new());
+    let proceeded = Arc::new(AtomicUsize::new(0));
+    
+    let handles: Vec<_> = (0..4)
+        .map(|i| {
+            let ready = Arc::clone(&ready);
+            let proceeded = Arc::clone(&proceeded);
+            thread::spawn(move || {
+                println!("Worker {} esperando la señal", i);
+                ready.wait();
+                proceeded.fetch_add(1, Ordering::SeqCst);
+            })
+        })
+        .collect();
+    
+    // Simula la inicialización que los workers tienen que esperar
+    thread::sleep(Duration::from_millis(20));
+    println!("Antes de set: is_set = {}, continuaron = {}", ready.is_set(), proceeded.load(Ordering::SeqCst));
+    ready.set();
+    
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    println!("Después de set: continuaron = {}", proceeded.load(Ordering::SeqCst));
+    
+    // Un latch abierto no vuelve a bloquear
+    ready.wait();
+    println!("wait() tras set vuelve enseguida: is_set = {}", ready.is_set());
+}
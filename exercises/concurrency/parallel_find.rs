@@ -0,0 +1,94 @@
+//! 🦀 Parallel Find - Búsqueda en paralelo que para en cuanto alguien acierta
+//!
+//! Repartir un slice en trozos, uno por thread, acelera la búsqueda,
+//! pero sin coordinación cada thread recorre su trozo entero aunque otro
+//! ya haya encontrado lo que se buscaba. Con un `AtomicBool` compartido
+//! el que acierta lo marca y los demás lo comprueban en cada elemento y
+//! se retiran: nadie los interrumpe, terminan por sí mismos.
+
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::thread;
+use std::time::Instant;
+
+/// Índice de algún elemento que cumple `pred`, buscando con un thread por núcleo
+///
+/// No es necesariamente el primero: si varios threads aciertan casi a
+/// la vez se devuelve el menor de sus índices, pero un thread puede
+/// haberse retirado antes de llegar a una coincidencia anterior.
+pub fn parallel_find<T: Sync>(data: &[T], pred: impl Fn(&T) -> bool + Send + Sync) -> Option<usize> {
+    let threads = thread::available_parallelism().map_or(4, |n| n.get());
+    let chunk_size = data.len().div_ceil(threads).max(1);
+    let found = AtomicBool::new(false);
+    
+    thread::scope(|scope| {
+        let handles: Vec<_> = data
+            .chunks(chunk_size)
+            .enumerate()
+            .map(|(chunk_index, chunk)| {
+                let (found, pred) = (&found, &pred);
+                scope.spawn(move || {
+                    for (offset, item) in chunk.iter().enumerate() {
+                        // CORREGIDO: Relaxed basta; el índice vuelve por join, no a través del flag
+                        if found.load(Ordering::Relaxed) {
+                            return None;
+                        }
+                        if pred(item) {
+                            found.store(true, Ordering::Relaxed);
+                            return Some(chunk_index * chunk_size + offset);
+                        }
+                    }
+                    None
+                })
+            })
+            .collect();
+        
+        handles
+            .into_iter()
+            .filter_map(|handle| handle.join().unwrap_or_else(|panic| std::panic::resume_unwind(panic)))
+            .min()
+    })
+}
+
+/// Función que demuestra cuántos elementos se ahorran al parar los threads en cuanto hay coincidencia
+pub fn demonstrate_parallel_find() {
+    println!("\n✅ Demostrando búsqueda paralela con parada temprana...");
+    
+    let data: Vec<u64> = (0..20_000_000).collect();
+    let threads = thread::available_parallelism().map_or(4, |n| n.get());
+    let target = data.len() as u64 / threads as u64 / 10;
+    
+    // El predicado cuenta sus llamadas para ver cuánto trabajo hizo cada búsqueda
+    let examined = AtomicUsize::new(0);
+    let counting_pred = |target: u64| {
+        let examined = &examined;
+        move |&x: &u64| {
+            examined.fetch_add(1, Ordering::Relaxed);
+            x == target
+        }
+    };
+    
+    let start = Instant::now();
+    let sequential = data.iter().position(|&x| x == target);
+    println!("iter().position: {:?} en {:?}", sequential, start.elapsed());
+    
+    let start = Instant::now();
+    let found = parallel_find(&data, counting_pred(target));
+    println!(
+        "parallel_find con {} threads: {:?} en {:?}, {} de {} elementos examinados",
+        threads,
+        found,
+        start.elapsed(),
+        examined.swap(0, Ordering::Relaxed),
+        data.len()
+    );
+    
+    // Sin coincidencia nadie marca el flag y todos recorren su trozo entero
+    let start = Instant::now();
+    let missing = parallel_find(&data, counting_pred(u64::MAX));
+    println!(
+        "parallel_find sin coincidencia: {:?} en {:?}, {} elementos examinados",
+        missing,
+        start.elapsed(),
+        examined.load(Ordering::Relaxed)
+    );
+}
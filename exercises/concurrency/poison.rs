@@ -0,0 +1,36 @@
+//! 🦀 Poison - Seguir usando un lock después de un panic
+//!
+//! Si un thread hace panic con el guard de un `Mutex` o un `RwLock`
+//! tomado, el lock queda envenenado y cada `lock()` posterior devuelve
+//! `Err`. Es un aviso de que el dato pudo quedar a medias, no de que se
+//! haya perdido: `into_inner()` devuelve el guard igualmente.
+//!
+//! Las primitivas de este módulo (rate limiter, semáforo, latch,
+//! barrera...) solo modifican su estado en secciones críticas que no
+//! pueden quedarse a medio camino: un `+= 1`, una asignación, un `push`.
+//! Para ellas el aviso no aporta nada y un `unwrap()` convertiría el
+//! panic de un thread en el de todos los demás, así que lo ignoran con
+//! estas funciones. Quien las use se compromete a mantener esa propiedad
+//! en todas las secciones críticas del dato.
+
+use std::sync::{Condvar, Mutex, MutexGuard, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+/// `mutex.lock()`, devolviendo el guard aunque el lock esté envenenado
+pub fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// `rwlock.read()`, devolviendo el guard aunque el lock esté envenenado
+pub fn read<T>(rwlock: &RwLock<T>) -> RwLockReadGuard<'_, T> {
+    rwlock.read().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// `rwlock.write()`, devolviendo el guard aunque el lock esté envenenado
+pub fn write<T>(rwlock: &RwLock<T>) -> RwLockWriteGuard<'_, T> {
+    rwlock.write().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// `condvar.wait(guard)`, devolviendo el guard aunque el lock se envenene durante la espera
+pub fn wait<'a, T>(condvar: &Condvar, guard: MutexGuard<'a, T>) -> MutexGuard<'a, T> {
+    condvar.wait(guard).unwrap_or_else(|poisoned| poisoned.into_inner())
+}
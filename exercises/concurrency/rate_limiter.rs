@@ -0,0 +1,85 @@
+//! 🦀 Rate Limiter - Token bucket compartido entre threads
+//!
+//! El bucket empieza lleno con `capacity` tokens y se rellena a
+//! `refill_per_sec` tokens por segundo. Cada petición consume tokens;
+//! si no hay suficientes, se rechaza sin bloquear.
+
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use super::poison;
+
+#[derive(Debug)]
+struct Bucket {
+    // f64 para no perder el relleno parcial entre llamadas muy seguidas
+    tokens: f64,
+    last_refill: Instant,
+}
+
+/// Limitador de tasa clonable: los clones comparten el mismo bucket
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    capacity: u32,
+    refill_per_sec: u32,
+    bucket: Arc<Mutex<Bucket>>,
+}
+
+impl RateLimiter {
+    pub fn new(capacity: u32, refill_per_sec: u32) -> Self {
+        Self {
+            capacity,
+            refill_per_sec,
+            bucket: Arc::new(Mutex::new(Bucket {
+                tokens: capacity as f64,
+                last_refill: Instant::now(),
+            })),
+        }
+    }
+    
+    /// Consume `n` tokens si hay suficientes tras rellenar el bucket
+    pub fn try_acquire(&self, n: u32) -> bool {
+        let mut bucket = poison::lock(&self.bucket);
+        
+        let now = Instant::now();
+        let elapsed = now.duration_since(bucket.last_refill).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * self.refill_per_sec as f64).min(self.capacity as f64);
+        bucket.last_refill = now;
+        
+        if bucket.tokens >= n as f64 {
+            bucket.tokens -= n as f64;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// Función que demuestra limitar peticiones concurrentes con un token bucket
+pub fn demonstrate_rate_limiter() {
+    println!("\n✅ Demostrando rate limiting con token bucket...");
+    
+    let limiter = RateLimiter::new(5, 10);
+    
+    // CORREGIDO: Las peticiones que superan la capacidad se rechazan sin bloquear
+    let handles: Vec<_> = (0..4)
+        .map(|i| {
+            let limiter = limiter.clone();
+            thread::spawn(move || {
+                let accepted = (0..3).filter(|_| limiter.try_acquire(1)).count();
+                (i, accepted)
+            })
+        })
+        .collect();
+    
+    let mut total = 0;
+    for handle in handles {
+        let (i, accepted) = handle.join().unwrap();
+        println!("Thread {}: {} de 3 peticiones aceptadas", i, accepted);
+        total += accepted;
+    }
+    println!("Total aceptadas en la ráfaga: {} (capacidad 5)", total);
+    
+    thread::sleep(Duration::from_millis(150));
+    println!("Tras 150ms (1 token cada 100ms): {}", limiter.try_acquire(1));
+}
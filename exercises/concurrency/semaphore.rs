@@ -0,0 +1,114 @@
+//! 🦀 Semaphore - Limitar cuántos threads usan un recurso a la vez
+//!
+//! Un `Mutex` deja pasar a un solo thread; un semáforo con `n` permisos
+//! deja pasar a `n`. Sirve para acotar la concurrencia sobre recursos
+//! caros (conexiones, ficheros abiertos, memoria) sin serializarlo todo.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::thread;
+use std::time::Duration;
+
+use super::poison;
+
+/// Semáforo contador construido con `Mutex<usize>` + `Condvar`
+#[derive(Debug)]
+pub struct Semaphore {
+    permits: Mutex<usize>,
+    released: Condvar,
+}
+
+/// Permiso adquirido; se devuelve al semáforo en `Drop`
+#[derive(Debug)]
+pub struct SemaphoreGuard<'a> {
+    semaphore: &'a Semaphore,
+}
+
+impl Semaphore {
+    pub fn new(permits: usize) -> Self {
+        Self {
+            permits: Mutex::new(permits),
+            released: Condvar::new(),
+        }
+    }
+    
+    fn lock_permits(&self) -> MutexGuard<'_, usize> {
+        poison::lock(&self.permits)
+    }
+    
+    /// Bloquea hasta que haya un permiso libre
+    pub fn acquire(&self) -> SemaphoreGuard<'_> {
+        let mut permits = self.lock_permits();
+        // CORREGIDO: En bucle, porque wait puede despertar sin que haya permisos (spurious wakeup)
+        while *permits == 0 {
+            permits = poison::wait(&self.released, permits);
+        }
+        *permits -= 1;
+        SemaphoreGuard { semaphore: self }
+    }
+    
+    /// Toma un permiso solo si hay alguno libre, sin bloquear
+    pub fn try_acquire(&self) -> Option<SemaphoreGuard<'_>> {
+        let mut permits = self.lock_permits();
+        if *permits == 0 {
+            return None;
+        }
+        *permits -= 1;
+        Some(SemaphoreGuard { semaphore: self })
+    }
+    
+    pub fn available_permits(&self) -> usize {
+        *self.lock_permits()
+    }
+}
+
+impl Drop for SemaphoreGuard<'_> {
+    fn drop(&mut self) {
+        *self.semaphore.lock_permits() += 1;
+        // Solo se liberó un permiso: basta con despertar a un thread
+        self.semaphore.released.notify_one();
+    }
+}
+
+/// Función que demuestra acotar el acceso concurrente con un semáforo
+pub fn demonstrate_semaphore() {
+    println!("\n✅ Demostrando Semaphore para limitar la concurrencia...");
+    
+    let semaphore = Arc::new(Semaphore::new(2));
+    let active = Arc::new(AtomicUsize::new(0));
+    let max_active = Arc::new(AtomicUsize::new(0));
+    
+    let handles: Vec<_> = (0..5)
+        .map(|i| {
+            let semaphore = Arc::clone(&semaphore);
+            let active = Arc::clone(&active);
+            let max_active = Arc::clone(&max_active);
+            thread::spawn(move || {
+                let _permit = semaphore.acquire();
+                let now_active = active.fetch_add(1, Ordering::SeqCst) + 1;
+                max_active.fetch_max(now_active, Ordering::SeqCst);
+                println!("Thread {} usa el recurso ({} a la vez)", i, now_active);
+                
+                thread::sleep(Duration::from_millis(20));
+                active.fetch_sub(1, Ordering::SeqCst);
+                // _permit se libera aquí y despierta a un thread en espera
+            })
+        })
+        .collect();
+    
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    println!("Máximo de threads simultáneos: {} (permisos: 2)", max_active.load(Ordering::SeqCst));
+    
+    let guard = semaphore.try_acquire();
+    let second = semaphore.try_acquire();
+    println!(
+        "try_acquire con 2 permisos: {}, {}, tercero: {}",
+        guard.is_some(),
+        second.is_some(),
+        semaphore.try_acquire().is_some()
+    );
+    drop(guard);
+    println!("Permisos libres tras soltar uno: {}", semaphore.available_permits());
+}
@@ -0,0 +1,129 @@
+//! 🦀 Sharded Map - HashMap concurrente repartido en shards
+//!
+//! En lugar de un único `Mutex<HashMap>` que serializa todos los
+//! accesos, cada clave se asigna por hash a uno de `N` shards, cada uno
+//! con su propio `RwLock`. Dos threads solo compiten si sus claves caen
+//! en el mismo shard, y las lecturas de un mismo shard no se bloquean
+//! entre sí.
+
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap;
+use std::hash::{BuildHasher, Hash};
+use std::sync::{Arc, Mutex, RwLock};
+use std::thread;
+use std::time::Instant;
+
+use super::poison;
+
+/// Mapa concurrente con un `RwLock<HashMap>` independiente por shard
+#[derive(Debug)]
+pub struct ShardedMap<K, V> {
+    shards: Vec<RwLock<HashMap<K, V>>>,
+    hasher: RandomState,
+}
+
+impl<K: Hash + Eq, V> ShardedMap<K, V> {
+    /// Crea el mapa con `shard_count` shards (al menos uno)
+    ///
+    /// Elegir el número de shards: con pocos, varios threads acaban en
+    /// el mismo lock y se vuelve a la contención de un único `Mutex`;
+    /// con muchos, cada shard es un `HashMap` casi vacío que ocupa
+    /// memoria y `len` tiene que bloquearlos todos. Unas 4 veces el
+    /// número de threads que acceden a la vez es un buen punto de
+    /// partida: la probabilidad de que dos threads coincidan en un shard
+    /// es de 1/N por acceso.
+    pub fn new(shard_count: usize) -> Self {
+        let shard_count = shard_count.max(1);
+        Self {
+            shards: (0..shard_count).map(|_| RwLock::new(HashMap::new())).collect(),
+            hasher: RandomState::new(),
+        }
+    }
+    
+    pub fn shard_count(&self) -> usize {
+        self.shards.len()
+    }
+    
+    fn shard(&self, key: &K) -> &RwLock<HashMap<K, V>> {
+        let index = self.hasher.hash_one(key) as usize % self.shards.len();
+        &self.shards[index]
+    }
+    
+    /// Inserta el par y devuelve el valor anterior de la clave, si lo había
+    pub fn insert(&self, key: K, value: V) -> Option<V> {
+        poison::write(self.shard(&key)).insert(key, value)
+    }
+    
+    pub fn len(&self) -> usize {
+        self.shards.iter().map(|shard| poison::read(shard).len()).sum()
+    }
+}
+
+impl<K: Hash + Eq, V: Clone> ShardedMap<K, V> {
+    /// Devuelve una copia del valor: una referencia no puede sobrevivir al lock del shard
+    pub fn get(&self, key: &K) -> Option<V> {
+        poison::read(self.shard(key)).get(key).cloned()
+    }
+}
+
+/// Función que demuestra la menor contención de un mapa con shards
+pub fn demonstrate_sharded_map() {
+    println!("\n✅ Demostrando HashMap concurrente con shards...");
+    
+    const THREADS: u64 = 8;
+    const KEYS_PER_THREAD: u64 = 20_000;
+    
+    // Un único lock: todas las inserciones se serializan
+    let global = Arc::new(Mutex::new(HashMap::new()));
+    let start = Instant::now();
+    let handles: Vec<_> = (0..THREADS)
+        .map(|t| {
+            let global = Arc::clone(&global);
+            thread::spawn(move || {
+                for key in t * KEYS_PER_THREAD..(t + 1) * KEYS_PER_THREAD {
+                    global.lock().unwrap().insert(key, key * 2);
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    let global_time = start.elapsed();
+    
+    // CORREGIDO: Cada thread solo bloquea el shard de la clave que toca
+    let sharded = Arc::new(ShardedMap::new(THREADS as usize * 4));
+    let start = Instant::now();
+    let handles: Vec<_> = (0..THREADS)
+        .map(|t| {
+            let sharded = Arc::clone(&sharded);
+            thread::spawn(move || {
+                for key in t * KEYS_PER_THREAD..(t + 1) * KEYS_PER_THREAD {
+                    sharded.insert(key, key * 2);
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    let sharded_time = start.elapsed();
+    
+    println!(
+        "Mutex<HashMap>: {} claves en {:?}",
+        global.lock().unwrap().len(),
+        global_time
+    );
+    println!(
+        "ShardedMap ({} shards): {} claves en {:?}",
+        sharded.shard_count(),
+        sharded.len(),
+        sharded_time
+    );
+    // Con un solo núcleo no hay accesos simultáneos y los shards no aportan nada
+    println!(
+        "Núcleos disponibles: {}",
+        thread::available_parallelism().map(|n| n.get()).unwrap_or(1)
+    );
+    println!("get(42) = {:?}, get(999_999) = {:?}", sharded.get(&42), sharded.get(&999_999));
+}
@@ -0,0 +1,73 @@
+//! 🦀 Shared Counter - Contador compartido entre threads
+//!
+//! Encapsula `Arc<Mutex<i32>>` y ofrece dos formas de sumar:
+//! `add` toma el lock en cada llamada, `add_batch` lo toma una sola vez
+//! para todo el lote.
+
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// Errores de las operaciones concurrentes
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConcurrencyError {
+    /// Un thread hizo panic con el lock tomado
+    LockPoisoned(String),
+    /// La suma no cabe en el contador; el total se queda como estaba
+    Overflow { total: i32 },
+}
+
+impl std::fmt::Display for ConcurrencyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ConcurrencyError::LockPoisoned(msg) => write!(f, "Lock envenenado: {}", msg),
+            ConcurrencyError::Overflow { total } => {
+                write!(f, "La suma desborda el contador (total actual: {})", total)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConcurrencyError {}
+
+/// Contador que se puede clonar y compartir entre threads
+#[derive(Debug, Clone, Default)]
+pub struct SharedCounter {
+    total: Arc<Mutex<i32>>,
+}
+
+impl SharedCounter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    fn lock(&self) -> Result<MutexGuard<'_, i32>, ConcurrencyError> {
+        self.total
+            .lock()
+            .map_err(|e| ConcurrencyError::LockPoisoned(e.to_string()))
+    }
+    
+    /// Suma un valor: un lock por llamada
+    pub fn add(&self, value: i32) -> Result<i32, ConcurrencyError> {
+        let mut total = self.lock()?;
+        *total = total
+            .checked_add(value)
+            .ok_or(ConcurrencyError::Overflow { total: *total })?;
+        Ok(*total)
+    }
+    
+    /// CORREGIDO: Suma todo el lote con un único lock
+    ///
+    /// El lote se aplica entero o no se aplica: si alguna suma desborda,
+    /// el total no cambia.
+    pub fn add_batch(&self, values: &[i32]) -> Result<i32, ConcurrencyError> {
+        let mut total = self.lock()?;
+        *total = values
+            .iter()
+            .try_fold(*total, |acc, &value| acc.checked_add(value))
+            .ok_or(ConcurrencyError::Overflow { total: *total })?;
+        Ok(*total)
+    }
+    
+    pub fn get(&self) -> Result<i32, ConcurrencyError> {
+        Ok(*self.lock()?)
+    }
+}
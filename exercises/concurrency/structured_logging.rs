@@ -0,0 +1,178 @@
+//! 🦀 Structured Logging - Eventos tipados en lugar de println!
+//!
+//! Cada operación sobre el contador genera un `LogEvent` con marca de
+//! tiempo, thread y tipo de evento. El destino se elige con el trait
+//! `Logger`: la consola para el demo o un `Vec` compartido para los tests.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use super::poison;
+use super::shared_counter::ConcurrencyError;
+use super::Counter;
+
+/// Qué ocurrió en la operación
+#[derive(Debug, Clone, PartialEq)]
+pub enum EventKind {
+    LockAcquired,
+    /// Tiempo que el lock estuvo tomado
+    LockReleased(Duration),
+    /// Valor del contador tras el incremento
+    Incremented(i32),
+    Error(String),
+}
+
+/// Evento con el momento y el thread en que se produjo
+#[derive(Debug, Clone)]
+pub struct LogEvent {
+    pub timestamp: Instant,
+    pub thread_id: usize,
+    pub kind: EventKind,
+}
+
+impl LogEvent {
+    pub fn now(kind: EventKind) -> Self {
+        Self {
+            timestamp: Instant::now(),
+            thread_id: current_thread_id(),
+            kind,
+        }
+    }
+}
+
+static NEXT_THREAD_ID: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    // Se asigna en el primer uso dentro de cada thread
+    static THREAD_ID: usize = NEXT_THREAD_ID.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Id numérico y estable del thread actual (`ThreadId` no se puede
+/// convertir a número en Rust estable)
+pub fn current_thread_id() -> usize {
+    THREAD_ID.with(|id| *id)
+}
+
+/// Destino de los eventos
+pub trait Logger {
+    fn log(&self, event: &LogEvent);
+}
+
+/// Imprime cada evento relativo al momento de crear el logger
+pub struct ConsoleLogger {
+    start: Instant,
+}
+
+impl ConsoleLogger {
+    pub fn new() -> Self {
+        Self {
+            start: Instant::now(),
+        }
+    }
+}
+
+impl Logger for ConsoleLogger {
+    fn log(&self, event: &LogEvent) {
+        let offset = event.timestamp.saturating_duration_since(self.start);
+        println!("[{:>10?}] thread {}: {:?}", offset, event.thread_id, event.kind);
+    }
+}
+
+/// Guarda los eventos en memoria; los clones comparten el mismo `Vec`
+#[derive(Debug, Clone, Default)]
+pub struct VecLogger {
+    pub events: Arc<Mutex<Vec<LogEvent>>>,
+}
+
+impl Logger for VecLogger {
+    fn log(&self, event: &LogEvent) {
+        // Un logger no debe hacer panic: se recupera el Vec aunque esté envenenado
+        let mut events = poison::lock(&self.events);
+        events.push(event.clone());
+    }
+}
+
+/// `Counter` protegido por un `Mutex` que registra cada operación
+pub struct LoggedCounter<L: Logger> {
+    counter: Mutex<Counter>,
+    logger: L,
+}
+
+impl<L: Logger> LoggedCounter<L> {
+    pub fn new(logger: L) -> Self {
+        Self {
+            counter: Mutex::new(Counter::new()),
+            logger,
+        }
+    }
+    
+    fn emit(&self, kind: EventKind) {
+        self.logger.log(&LogEvent::now(kind));
+    }
+    
+    /// Incrementa el contador y devuelve el nuevo valor
+    ///
+    /// `LockAcquired` e `Incremented` se emiten con el lock tomado, así que
+    /// llegan al logger en el orden en que ocurrieron. `LockReleased` se
+    /// emite después de soltarlo: puede llegar tras el `LockAcquired` de
+    /// otro thread, pero nunca antes de que el lock esté libre.
+    pub fn increment(&self) -> Result<i32, ConcurrencyError> {
+        let mut guard = match self.counter.lock() {
+            Ok(guard) => guard,
+            Err(e) => {
+                let message = e.to_string();
+                self.emit(EventKind::Error(message.clone()));
+                return Err(ConcurrencyError::LockPoisoned(message));
+            }
+        };
+        let acquired_at = Instant::now();
+        self.emit(EventKind::LockAcquired);
+        
+        guard.increment();
+        let value = guard.get_value();
+        self.emit(EventKind::Incremented(value));
+        
+        // CORREGIDO: Se mide, se suelta el lock y solo entonces se registra la liberación
+        let held = acquired_at.elapsed();
+        drop(guard);
+        self.emit(EventKind::LockReleased(held));
+        Ok(value)
+    }
+}
+
+/// Función que demuestra logging estructurado de un contador compartido
+pub fn demonstrate_structured_logging() {
+    println!("\n✅ Demostrando logging estructurado...");
+    
+    let counter = Arc::new(LoggedCounter::new(ConsoleLogger::new()));
+    let handles: Vec<_> = (0..2)
+        .map(|_| {
+            let counter = Arc::clone(&counter);
+            thread::spawn(move || {
+                for _ in 0..2 {
+                    if let Err(e) = counter.increment() {
+                        println!("Error: {}", e);
+                    }
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    
+    // CORREGIDO: Con VecLogger los eventos se pueden inspeccionar en lugar de leerse en consola
+    let logger = VecLogger::default();
+    let counter = LoggedCounter::new(logger.clone());
+    for _ in 0..3 {
+        let _ = counter.increment();
+    }
+    let events = logger.events.lock().unwrap();
+    let increments = events
+        .iter()
+        .filter(|event| matches!(event.kind, EventKind::Incremented(_)))
+        .count();
+    println!("Eventos registrados: {} ({} incrementos)", events.len(), increments);
+}
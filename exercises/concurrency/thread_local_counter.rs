@@ -0,0 +1,171 @@
+//! 🦀 Thread Local Counter - Acumular por thread y sumar al final
+//!
+//! Un contador compartido (`Mutex<u64>` o incluso `AtomicU64`) obliga a
+//! todos los threads a pelear por la misma línea de caché en cada
+//! incremento. Si cada thread acumula en su propia cuenta y solo se suma
+//! al final, el camino caliente no tiene contención.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, Weak};
+use std::thread;
+use std::time::Instant;
+
+use super::poison;
+
+static NEXT_COUNTER_ID: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    // Cuenta de este thread para cada contador que ha usado, por id de contador.
+    // `Weak` porque el dueño es el contador: al destruirlo la entrada queda muerta
+    static LOCAL_TALLIES: RefCell<HashMap<usize, Weak<AtomicU64>>> = RefCell::new(HashMap::new());
+}
+
+/// Contador con una cuenta por thread; `merge` las suma todas
+///
+/// Cada thread registra su cuenta la primera vez que incrementa (único
+/// momento en que se toma el lock). Las cuentas siguen registradas
+/// cuando el thread termina, así que `merge` tras `join` ve todo.
+///
+/// El mapa de cada thread solo guarda `Weak`, así que un thread que vive
+/// mucho no acumula las cuentas de contadores ya destruidos: sus entradas
+/// muertas se quitan la próxima vez que ese thread registra una cuenta nueva.
+#[derive(Debug)]
+pub struct ThreadLocalCounter {
+    id: usize,
+    tallies: Mutex<Vec<Arc<AtomicU64>>>,
+}
+
+impl ThreadLocalCounter {
+    pub fn new() -> Self {
+        Self {
+            id: NEXT_COUNTER_ID.fetch_add(1, Ordering::Relaxed),
+            tallies: Mutex::new(Vec::new()),
+        }
+    }
+    
+    /// Cuenta del thread actual para este contador, registrándola en el primer uso
+    ///
+    /// En bucles calientes conviene guardarla y usar `LocalTally::add`
+    /// directamente, sin pasar por el `HashMap` del thread cada vez.
+    pub fn local(&self) -> LocalTally {
+        LOCAL_TALLIES.with(|tallies| {
+            let mut tallies = tallies.borrow_mut();
+            if let Some(tally) = tallies.get(&self.id).and_then(Weak::upgrade) {
+                return LocalTally { tally };
+            }
+            
+            // CORREGIDO: Sin esto el mapa crece con cada contador que el thread haya tocado
+            tallies.retain(|_, tally| tally.strong_count() > 0);
+            let tally = Arc::new(AtomicU64::new(0));
+            poison::lock(&self.tallies).push(Arc::clone(&tally));
+            tallies.insert(self.id, Arc::downgrade(&tally));
+            LocalTally { tally }
+        })
+    }
+    
+    pub fn increment(&self) {
+        self.add(1);
+    }
+    
+    pub fn add(&self, n: u64) {
+        self.local().add(n);
+    }
+    
+    /// Suma de todas las cuentas
+    ///
+    /// Exacta si los threads ya terminaron (tras `join`); con threads aún
+    /// activos es solo una instantánea aproximada.
+    pub fn merge(&self) -> u64 {
+        poison::lock(&self.tallies)
+            .iter()
+            .map(|tally| tally.load(Ordering::Relaxed))
+            .sum()
+    }
+    
+    /// Cuántos threads han incrementado este contador
+    pub fn thread_count(&self) -> usize {
+        poison::lock(&self.tallies).len()
+    }
+}
+
+/// Cuenta de un thread; solo ese thread escribe en ella
+#[derive(Debug, Clone)]
+pub struct LocalTally {
+    tally: Arc<AtomicU64>,
+}
+
+impl LocalTally {
+    pub fn add(&self, n: u64) {
+        // CORREGIDO: Sin otros escritores no hay contención y basta con Relaxed
+        self.tally.fetch_add(n, Ordering::Relaxed);
+    }
+}
+
+impl Default for ThreadLocalCounter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Función que demuestra acumular por thread frente a un contador compartido
+pub fn demonstrate_thread_local_counter() {
+    println!("\n✅ Demostrando ThreadLocalCounter (acumular por thread y unir)...");
+    
+    const THREADS: usize = 4;
+    const INCREMENTS: u64 = 1_000_000;
+    
+    let shared = Arc::new(Mutex::new(0u64));
+    let start = Instant::now();
+    let handles: Vec<_> = (0..THREADS)
+        .map(|_| {
+            let shared = Arc::clone(&shared);
+            thread::spawn(move || {
+                for _ in 0..INCREMENTS {
+                    *shared.lock().unwrap() += 1;
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    println!("Mutex<u64> compartido: {} en {:?}", *shared.lock().unwrap(), start.elapsed());
+    
+    let counter = Arc::new(ThreadLocalCounter::new());
+    let start = Instant::now();
+    let handles: Vec<_> = (0..THREADS)
+        .map(|_| {
+            let counter = Arc::clone(&counter);
+            thread::spawn(move || {
+                let tally = counter.local();
+                for _ in 0..INCREMENTS {
+                    tally.add(1);
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    println!(
+        "ThreadLocalCounter: {} en {:?} ({} cuentas unidas)",
+        counter.merge(),
+        start.elapsed(),
+        counter.thread_count()
+    );
+    
+    // increment/add buscan la cuenta del thread en cada llamada: cómodos fuera de bucles calientes
+    counter.increment();
+    counter.add(9);
+    println!(
+        "Tras sumar 10 desde el thread principal: {} ({} cuentas)",
+        counter.merge(),
+        counter.thread_count()
+    );
+    
+    // Con un solo core no hay contención real y la diferencia se reduce
+    let cores = thread::available_parallelism().map(|n| n.get()).unwrap_or(1);
+    println!("Cores disponibles: {}", cores);
+}
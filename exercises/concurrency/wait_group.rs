@@ -0,0 +1,91 @@
+//! 🦀 Wait Group - Esperar a un número variable de threads sin guardar sus handles
+//!
+//! Para esperar con `join` hace falta el `JoinHandle` de cada thread, así
+//! que quien los lanza tiene que reunirlos en un `Vec`. Con un `WaitGroup`
+//! (como `sync.WaitGroup` de Go) basta un contador compartido: `add(n)`
+//! antes de lanzar trabajo, `done()` al terminarlo y `wait()` bloquea
+//! hasta que llega a 0. Un worker puede lanzar más trabajo mientras
+//! tanto, siempre que haga `add` antes de su propio `done`.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::thread;
+use std::time::Duration;
+
+use super::poison;
+
+/// Contador de tareas pendientes; los clones comparten el mismo contador
+#[derive(Debug, Clone, Default)]
+pub struct WaitGroup {
+    inner: Arc<(Mutex<usize>, Condvar)>,
+}
+
+impl WaitGroup {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    fn lock_pending(&self) -> MutexGuard<'_, usize> {
+        poison::lock(&self.inner.0)
+    }
+    
+    /// Anota `n` tareas más; debe llamarse antes de lanzarlas, no dentro de ellas
+    pub fn add(&self, n: usize) {
+        *self.lock_pending() += n;
+    }
+    
+    /// Marca una tarea como terminada y despierta a los que esperan si era la última
+    ///
+    /// # Panics
+    ///
+    /// Si se llama más veces que las anotadas con `add`.
+    pub fn done(&self) {
+        let mut pending = self.lock_pending();
+        // CORREGIDO: Un done de más es un error del llamador; restar en silencio despertaría a wait antes de tiempo
+        *pending = pending.checked_sub(1).expect("WaitGroup::done sin un add pendiente");
+        if *pending == 0 {
+            self.inner.1.notify_all();
+        }
+    }
+    
+    /// Bloquea hasta que no quede ninguna tarea pendiente; vuelve enseguida si ya no hay
+    pub fn wait(&self) {
+        let mut pending = self.lock_pending();
+        while *pending > 0 {
+            pending = poison::wait(&self.inner.1, pending);
+        }
+    }
+}
+
+/// Procesa un "directorio" y lanza un thread por cada uno de sus 3 subdirectorios
+fn visit(depth: u32, wait_group: WaitGroup, processed: Arc<AtomicUsize>) {
+    processed.fetch_add(1, Ordering::SeqCst);
+    if depth > 0 {
+        for _ in 0..3 {
+            wait_group.add(1);
+            let (wait_group, processed) = (wait_group.clone(), Arc::clone(&processed));
+            thread::spawn(move || visit(depth - 1, wait_group, processed));
+        }
+    }
+    thread::sleep(Duration::from_millis(5));
+    wait_group.done();
+}
+
+/// Función que demuestra un reparto de trabajo que crece mientras se procesa
+pub fn demonstrate_wait_group() {
+    println!("\n✅ Demostrando WaitGroup con trabajo dinámico...");
+    
+    let wait_group = WaitGroup::new();
+    let processed = Arc::new(AtomicUsize::new(0));
+    
+    // El total de threads no se conoce de antemano: cada uno anota a sus hijos
+    wait_group.add(1);
+    {
+        let (wait_group, processed) = (wait_group.clone(), Arc::clone(&processed));
+        thread::spawn(move || visit(3, wait_group, processed));
+    }
+    
+    // Ningún JoinHandle guardado: wait vuelve cuando el último thread llama a done
+    wait_group.wait();
+    println!("Nodos procesados: {} (1 + 3 + 9 + 27)", processed.load(Ordering::SeqCst));
+}
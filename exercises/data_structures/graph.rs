@@ -0,0 +1,219 @@
+//! 🦀 Graph - Grafo dirigido con listas de adyacencia
+//!
+//! Los nodos y las aristas viven en dos `Vec` y se referencian por
+//! índice (`NodeId`, `EdgeId`) en lugar de con `Rc<RefCell<...>>`: no
+//! hay ciclos de referencias que romper con `Weak`, ni borrows en tiempo
+//! de ejecución, y un grafo con ciclos es tan fácil de construir como un
+//! árbol. Cada nodo guarda los `EdgeId` de sus aristas de salida en el
+//! orden en que se añadieron, que es el orden en que se visitan.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+use std::ops::Add;
+
+/// Índice de un nodo dentro de su `Graph`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NodeId(pub usize);
+
+/// Índice de una arista dentro de su `Graph`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EdgeId(pub usize);
+
+#[derive(Debug, Clone)]
+struct Edge<E> {
+    from: NodeId,
+    to: NodeId,
+    weight: E,
+}
+
+/// Grafo dirigido con valores `V` en los nodos y pesos `E` en las aristas
+#[derive(Debug, Clone)]
+pub struct Graph<V, E> {
+    nodes: Vec<V>,
+    edges: Vec<Edge<E>>,
+    /// Aristas de salida de cada nodo, en orden de inserción
+    adjacency: Vec<Vec<EdgeId>>,
+}
+
+impl<V, E> Default for Graph<V, E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V, E> Graph<V, E> {
+    pub fn new() -> Self {
+        Self {
+            nodes: Vec::new(),
+            edges: Vec::new(),
+            adjacency: Vec::new(),
+        }
+    }
+    
+    pub fn add_node(&mut self, value: V) -> NodeId {
+        self.nodes.push(value);
+        self.adjacency.push(Vec::new());
+        NodeId(self.nodes.len() - 1)
+    }
+    
+    /// Añade la arista `from -> to`; para un grafo no dirigido, añadir también `to -> from`
+    ///
+    /// # Panics
+    ///
+    /// Si `from` o `to` no son nodos de este grafo.
+    pub fn add_edge(&mut self, from: NodeId, to: NodeId, weight: E) -> EdgeId {
+        assert!(
+            from.0 < self.nodes.len() && to.0 < self.nodes.len(),
+            "arista {:?} -> {:?} en un grafo de {} nodos",
+            from,
+            to,
+            self.nodes.len()
+        );
+        let id = EdgeId(self.edges.len());
+        self.edges.push(Edge { from, to, weight });
+        self.adjacency[from.0].push(id);
+        id
+    }
+    
+    pub fn node(&self, id: NodeId) -> Option<&V> {
+        self.nodes.get(id.0)
+    }
+    
+    /// `(from, to, peso)` de una arista
+    pub fn edge(&self, id: EdgeId) -> Option<(NodeId, NodeId, &E)> {
+        self.edges.get(id.0).map(|edge| (edge.from, edge.to, &edge.weight))
+    }
+    
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+    
+    /// Destinos de las aristas de salida de `node`, con su peso
+    ///
+    /// # Panics
+    ///
+    /// Si `node` no es un nodo de este grafo.
+    pub fn neighbors(&self, node: NodeId) -> impl Iterator<Item = (NodeId, &E)> + '_ {
+        self.adjacency[node.0].iter().map(move |&edge| {
+            let edge = &self.edges[edge.0];
+            (edge.to, &edge.weight)
+        })
+    }
+    
+    /// Nodos alcanzables desde `start` por niveles: primero los vecinos, luego los vecinos de estos
+    pub fn bfs(&self, start: NodeId) -> Vec<NodeId> {
+        let mut visited = vec![false; self.nodes.len()];
+        let mut order = Vec::new();
+        let mut queue = VecDeque::from([start]);
+        visited[start.0] = true;
+        
+        while let Some(node) = queue.pop_front() {
+            order.push(node);
+            for (next, _) in self.neighbors(node) {
+                if !visited[next.0] {
+                    visited[next.0] = true;
+                    queue.push_back(next);
+                }
+            }
+        }
+        order
+    }
+    
+    /// Nodos alcanzables desde `start` en preorden: cada rama hasta el fondo antes de la siguiente
+    pub fn dfs(&self, start: NodeId) -> Vec<NodeId> {
+        let mut visited = vec![false; self.nodes.len()];
+        let mut order = Vec::new();
+        let mut stack = vec![start];
+        
+        // CORREGIDO: Pila explícita en lugar de recursión; un camino largo no desborda el stack
+        while let Some(node) = stack.pop() {
+            if visited[node.0] {
+                continue;
+            }
+            visited[node.0] = true;
+            order.push(node);
+            // Al revés, para que el primer vecino sea el primero en salir de la pila
+            let neighbors: Vec<NodeId> = self.neighbors(node).map(|(next, _)| next).collect();
+            stack.extend(neighbors.into_iter().rev().filter(|next| !visited[next.0]));
+        }
+        order
+    }
+    
+    /// Camino de menor peso total de `start` a `end` (Dijkstra), con su peso
+    ///
+    /// Los pesos no pueden ser menores que `E::default()` (el "cero"); con
+    /// pesos negativos el resultado no es necesariamente el mínimo.
+    /// `None` si `end` no es alcanzable desde `start`.
+    pub fn shortest_path(&self, start: NodeId, end: NodeId) -> Option<(Vec<NodeId>, E)>
+    where
+        E: Ord + Add<Output = E> + Default + Clone,
+    {
+        let mut best: Vec<Option<E>> = (0..self.nodes.len()).map(|_| None).collect();
+        let mut previous: Vec<Option<NodeId>> = vec![None; self.nodes.len()];
+        let mut heap = BinaryHeap::new();
+        best[start.0] = Some(E::default());
+        heap.push(Reverse((E::default(), start)));
+        
+        while let Some(Reverse((cost, node))) = heap.pop() {
+            if node == end {
+                let mut path = vec![end];
+                while let Some(prev) = previous[path[path.len() - 1].0] {
+                    path.push(prev);
+                }
+                path.reverse();
+                return Some((path, cost));
+            }
+            // Una entrada antigua del heap: ya se llegó a `node` por un camino más barato
+            if best[node.0].as_ref().is_some_and(|known| cost > *known) {
+                continue;
+            }
+            for (next, weight) in self.neighbors(node) {
+                let candidate = cost.clone() + weight.clone();
+                if best[next.0].as_ref().is_none_or(|known| candidate < *known) {
+                    best[next.0] = Some(candidate.clone());
+                    previous[next.0] = Some(node);
+                    heap.push(Reverse((candidate, next)));
+                }
+            }
+        }
+        None
+    }
+}
+
+/// Función que demuestra recorridos y caminos mínimos en un grafo con ciclos
+pub fn demonstrate_graph() {
+    println!("\n✅ Demostrando Graph con listas de adyacencia...");
+    
+    let mut graph: Graph<&str, u32> = Graph::new();
+    let madrid = graph.add_node("Madrid");
+    let zaragoza = graph.add_node("Zaragoza");
+    let barcelona = graph.add_node("Barcelona");
+    let valencia = graph.add_node("Valencia");
+    let sevilla = graph.add_node("Sevilla");
+    
+    // Carreteras en los dos sentidos, en km aproximados
+    for (a, b, km) in [
+        (madrid, zaragoza, 315),
+        (zaragoza, barcelona, 300),
+        (madrid, valencia, 355),
+        (valencia, barcelona, 350),
+        (madrid, sevilla, 530),
+    ] {
+        graph.add_edge(a, b, km);
+        graph.add_edge(b, a, km);
+    }
+    let ferry = graph.add_edge(sevilla, barcelona, 1_300);
+    
+    let names = |ids: &[NodeId]| ids.iter().filter_map(|&id| graph.node(id).copied()).collect::<Vec<_>>();
+    println!("{} nodos; arista {:?}: {:?}", graph.node_count(), ferry, graph.edge(ferry));
+    println!("BFS desde Madrid: {:?}", names(&graph.bfs(madrid)));
+    println!("DFS desde Madrid: {:?}", names(&graph.dfs(madrid)));
+    
+    match graph.shortest_path(sevilla, barcelona) {
+        Some((path, km)) => println!("Sevilla -> Barcelona: {:?}, {} km", names(&path), km),
+        None => println!("Barcelona no es alcanzable desde Sevilla"),
+    }
+    
+    let isolated = graph.add_node("Palma");
+    println!("Madrid -> Palma: {:?}", graph.shortest_path(madrid, isolated));
+}
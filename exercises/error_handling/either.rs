@@ -0,0 +1,89 @@
+//! 🦀 Either - Un valor que es de uno de dos tipos
+//!
+//! `Result<T, E>` también tiene dos variantes, pero una es el éxito y la
+//! otra el error: `?`, `unwrap` o `ok()` tratan `Err` como un fallo.
+//! `Either<L, R>` no da preferencia a ninguna, así que sirve cuando las
+//! dos alternativas son igual de válidas, como un token que puede ser un
+//! número o una palabra.
+
+/// Valor de tipo `L` o de tipo `R`, sin que ninguno sea un error
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Either<L, R> {
+    Left(L),
+    Right(R),
+}
+
+impl<L, R> Either<L, R> {
+    pub fn is_left(&self) -> bool {
+        matches!(self, Either::Left(_))
+    }
+    
+    pub fn is_right(&self) -> bool {
+        !self.is_left()
+    }
+    
+    /// Transforma el valor si es `Left`; un `Right` pasa sin cambios
+    pub fn map_left<T>(self, f: impl FnOnce(L) -> T) -> Either<T, R> {
+        match self {
+            Either::Left(left) => Either::Left(f(left)),
+            Either::Right(right) => Either::Right(right),
+        }
+    }
+    
+    /// Transforma el valor si es `Right`; un `Left` pasa sin cambios
+    pub fn map_right<T>(self, f: impl FnOnce(R) -> T) -> Either<L, T> {
+        match self {
+            Either::Left(left) => Either::Left(left),
+            Either::Right(right) => Either::Right(f(right)),
+        }
+    }
+    
+    /// `Left` pasa a `Ok` y `Right` a `Err`, para cuando un lado sí es el esperado
+    pub fn into_result(self) -> Result<L, R> {
+        match self {
+            Either::Left(left) => Ok(left),
+            Either::Right(right) => Err(right),
+        }
+    }
+}
+
+/// `Left` si `s` es un entero, `Right` con el texto (sin espacios alrededor) si no
+pub fn parse_number_or_word(s: &str) -> Either<i64, String> {
+    let trimmed = s.trim();
+    match trimmed.parse() {
+        Ok(number) => Either::Left(number),
+        Err(_) => Either::Right(trimmed.to_string()),
+    }
+}
+
+/// Función que demuestra clasificar tokens en números y palabras con Either
+pub fn demonstrate_either() {
+    println!("\n✅ Demostrando Either<L, R> para números o palabras...");
+    
+    let tokens: Vec<Either<i64, String>> = "42 hola -7 3.5 99999999999999999999 mundo"
+        .split_whitespace()
+        .map(parse_number_or_word)
+        .collect();
+    println!("Tokens: {:?}", tokens);
+    
+    let numbers = tokens.iter().filter(|token| token.is_left()).count();
+    let words = tokens.iter().filter(|token| token.is_right()).count();
+    println!("{} números y {} palabras", numbers, words);
+    
+    // Cada lado se transforma sin tocar el otro
+    let normalized: Vec<Either<i64, String>> = tokens
+        .into_iter()
+        .map(|token| token.map_left(|n| n * 2).map_right(|word| word.to_uppercase()))
+        .collect();
+    println!("Números doblados y palabras en mayúsculas: {:?}", normalized);
+    
+    // CORREGIDO: Cuando sí se espera un número, into_result permite usar `?` y los combinadores de Result
+    let sum: Result<i64, String> = ["10", "20", "treinta"]
+        .iter()
+        .map(|s| parse_number_or_word(s).into_result())
+        .sum();
+    match sum {
+        Ok(total) => println!("Suma: {}", total),
+        Err(word) => println!("La suma se detuvo en '{}', que no es un número", word),
+    }
+}
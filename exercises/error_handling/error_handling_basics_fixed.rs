@@ -3,19 +3,141 @@
 //! Esta es la versión corregida del ejercicio anterior,
 //! mostrando las mejores prácticas de manejo de errores en Rust.
 
+use std::env;
 use std::fs::File;
 use std::io::{self, Read};
 use std::num::ParseIntError;
+use std::process::ExitCode;
+
+use rust_lab_macros::Builder;
+
+mod either;
+mod file_watcher;
+mod http_parser;
+mod lazy_config;
+mod protocol_parser;
+mod reloadable_config;
+mod retry_macro;
+#[cfg(feature = "toml-config")]
+mod toml_config;
 
 /// Estructura que representa un archivo de configuración
-#[derive(Debug, Clone)]
-struct Config {
+///
+/// `Builder` genera `ConfigBuilder`; solo el puerto es obligatorio y el
+/// resto toma los mismos valores que `Config::default()`.
+///
+/// `pub(crate)` para que los fuzz targets, que incluyen este archivo
+/// como módulo, puedan usarla.
+#[derive(Debug, Clone, PartialEq, Builder)]
+pub(crate) struct Config {
+    #[builder(required)]
     port: u16,
+    #[builder(default = "localhost".to_string())]
     host: String,
+    #[builder(default = 30)]
     timeout: u64,
+    #[builder(default = "info".to_string())]
     debug_level: String,
 }
 
+/// Errores al cargar una configuración
+#[derive(Debug, PartialEq)]
+pub(crate) enum ConfigError {
+    InvalidPort(String),
+    InvalidHost(String),
+    FileNotFound(String),
+    ParseError(String),
+    /// Entrada mal formada en una línea concreta (1-based)
+    ParseAt { line: usize, message: String },
+    /// Variable obligatoria ausente
+    MissingField(String),
+    /// Puerto válido pero fuera de la banda que permite el despliegue
+    PortOutOfRange { port: u16, min: u16, max: u16 },
+    /// Argumento de línea de comandos que no es un flag conocido
+    UnknownFlag(String),
+    /// Flag al final de los argumentos o seguido de otro flag
+    MissingFlagValue(String),
+    /// TOML mal formado o con tipos incorrectos
+    #[cfg(feature = "toml-config")]
+    TomlParse(toml::de::Error),
+    /// Valor que TOML no puede representar (sus enteros son `i64`)
+    #[cfg(feature = "toml-config")]
+    TomlSerialize(toml::ser::Error),
+}
+
+impl std::fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ConfigError::InvalidPort(port) => write!(f, "Puerto inválido: {}", port),
+            ConfigError::InvalidHost(host) => write!(f, "Host inválido: {}", host),
+            ConfigError::FileNotFound(file) => write!(f, "Archivo no encontrado: {}", file),
+            ConfigError::ParseError(msg) => write!(f, "Error de parseo: {}", msg),
+            ConfigError::ParseAt { line, message } => write!(f, "Error de parseo en la línea {}: {}", line, message),
+            ConfigError::MissingField(field) => write!(f, "Falta el campo obligatorio: {}", field),
+            ConfigError::PortOutOfRange { port, min, max } => {
+                write!(f, "Puerto {} fuera del rango permitido {}..={}", port, min, max)
+            }
+            ConfigError::UnknownFlag(flag) => write!(f, "Flag desconocido: {}", flag),
+            ConfigError::MissingFlagValue(flag) => write!(f, "Falta el valor de {}", flag),
+            #[cfg(feature = "toml-config")]
+            ConfigError::TomlParse(e) => write!(f, "Error en TOML: {}", e),
+            #[cfg(feature = "toml-config")]
+            ConfigError::TomlSerialize(e) => write!(f, "No se puede guardar en TOML: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+/// Fallo real de un demo, que `main` convierte en un código de salida distinto de 0
+///
+/// Los errores que los demos provocan a propósito para mostrarlos se
+/// siguen imprimiendo; aquí solo llegan los que impiden terminar el demo.
+#[derive(Debug)]
+enum DemoError {
+    Config(ConfigError),
+    Io(io::Error),
+}
+
+impl std::fmt::Display for DemoError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            DemoError::Config(e) => write!(f, "Error de configuración: {}", e),
+            DemoError::Io(e) => write!(f, "Error de E/S: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for DemoError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            DemoError::Config(e) => Some(e),
+            DemoError::Io(e) => Some(e),
+        }
+    }
+}
+
+impl From<ConfigError> for DemoError {
+    fn from(e: ConfigError) -> Self {
+        DemoError::Config(e)
+    }
+}
+
+impl From<io::Error> for DemoError {
+    fn from(e: io::Error) -> Self {
+        DemoError::Io(e)
+    }
+}
+
+/// Resultado del parser de configuración
+type ParseResult<T> = Result<T, ConfigError>;
+
+impl Default for Config {
+    fn default() -> Self {
+        Config::new(8080, "localhost".to_string(), 30)
+    }
+}
+
 impl Config {
     /// Crea una nueva configuración
     fn new(port: u16, host: String, timeout: u64) -> Self {
@@ -29,19 +151,204 @@ impl Config {
     
     /// CORREGIDO: Método que valida el nivel de debug
     fn set_debug_level(&mut self, level: &str) -> Result<(), String> {
-        let valid_levels = ["trace", "debug", "info", "warn", "error"];
+        check_debug_level(level)?;
+        self.debug_level = level.to_string();
+        Ok(())
+    }
+    
+    /// CORREGIDO: Método que retorna el nivel de debug de forma segura
+    fn get_debug_level(&self) -> &str {
+        &self.debug_level
+    }
+    
+    /// Comprueba que el puerto esté en `min..=max`, para despliegues que solo admiten una banda
+    ///
+    /// # Panics
+    ///
+    /// Si `min > max`: el rango vacío es un error de quien llama, no de la configuración.
+    fn validate_port_range(&self, min: u16, max: u16) -> Result<(), ConfigError> {
+        assert!(min <= max, "rango de puertos vacío: {}..={}", min, max);
         
-        if valid_levels.contains(&level) {
-            self.debug_level = level.to_string();
+        if (min..=max).contains(&self.port) {
             Ok(())
         } else {
-            Err(format!("Nivel de debug inválido: {}. Niveles válidos: {:?}", level, valid_levels))
+            Err(ConfigError::PortOutOfRange { port: self.port, min, max })
         }
     }
     
-    /// CORREGIDO: Método que retorna el nivel de debug de forma segura
-    fn get_debug_level(&self) -> &str {
-        &self.debug_level
+    /// Lee `{PREFIX}_PORT`, `{PREFIX}_HOST`, `{PREFIX}_TIMEOUT` y `{PREFIX}_DEBUG_LEVEL`
+    ///
+    /// El puerto es obligatorio (`MissingField` si falta). El resto toma el
+    /// valor de `Config::default()` si no está definido, pero un valor presente
+    /// y mal formado siempre es un `ParseError`.
+    fn from_env(prefix: &str) -> Result<Config, ConfigError> {
+        let var = |field: &str| format!("{}_{}", prefix, field);
+        
+        let port_var = var("PORT");
+        let port = match env::var(&port_var) {
+            Ok(value) => parse_env_port(&port_var, &value)?,
+            Err(_) => return Err(ConfigError::MissingField(port_var)),
+        };
+        
+        let mut config = Config {
+            port,
+            ..Config::default()
+        };
+        
+        let host_var = var("HOST");
+        if let Ok(value) = env::var(&host_var) {
+            config.host = parse_env_host(&host_var, &value)?;
+        }
+        
+        let timeout_var = var("TIMEOUT");
+        if let Ok(value) = env::var(&timeout_var) {
+            config.timeout = parse_env_timeout(&timeout_var, &value)?;
+        }
+        
+        if let Ok(value) = env::var(var("DEBUG_LEVEL")) {
+            config.set_debug_level(value.trim()).map_err(ConfigError::ParseError)?;
+        }
+        
+        Ok(config)
+    }
+    
+    /// Como `from_env("APP")`, pero cada variable ausente o inválida toma
+    /// el valor de `Config::default()` de forma independiente
+    fn from_env_or_default() -> Config {
+        let mut config = Config::default();
+        
+        if let Some(port) = env::var("APP_PORT").ok().and_then(|v| parse_env_port("APP_PORT", &v).ok()) {
+            config.port = port;
+        }
+        if let Some(host) = env::var("APP_HOST").ok().and_then(|v| parse_env_host("APP_HOST", &v).ok()) {
+            config.host = host;
+        }
+        if let Some(timeout) = env::var("APP_TIMEOUT").ok().and_then(|v| parse_env_timeout("APP_TIMEOUT", &v).ok()) {
+            config.timeout = timeout;
+        }
+        if let Ok(level) = env::var("APP_DEBUG_LEVEL") {
+            // Si el nivel no es válido se conserva el de por defecto
+            let _ = config.set_debug_level(level.trim());
+        }
+        
+        config
+    }
+    
+    /// Carga la configuración desde un archivo `clave=valor`
+    fn from_file(path: &str) -> ParseResult<Config> {
+        let contents = read_config_file(path)
+            .map_err(|e| ConfigError::FileNotFound(format!("{} ({})", path, e)))?;
+        Config::parse(&contents)
+    }
+    
+    /// CORREGIDO: Parser que informa de la línea exacta del error
+    ///
+    /// Las líneas vacías y los comentarios (`#`) se ignoran pero
+    /// siguen contando para el número de línea. Cada línea se convierte
+    /// en un `ConfigEntry` y las entradas se aplican en orden sobre
+    /// `Config::default()`.
+    fn parse(contents: &str) -> ParseResult<Config> {
+        contents
+            .lines()
+            .enumerate()
+            .map(|(index, raw_line)| (index + 1, raw_line.trim()))
+            .filter(|(_, trimmed)| !trimmed.is_empty() && !trimmed.starts_with('#'))
+            .try_fold(Config::default(), |config, (line, trimmed)| {
+                let entry = ConfigEntry::try_from(trimmed).map_err(|e| match e {
+                    ConfigError::ParseError(message) => ConfigError::ParseAt { line, message },
+                    other => other,
+                })?;
+                Ok(config.with_entry(entry))
+            })
+    }
+    
+    /// Sustituye el campo de `entry`; el valor ya viene validado por `ConfigEntry::try_from`
+    fn with_entry(mut self, entry: ConfigEntry) -> Config {
+        match entry {
+            ConfigEntry::Port(port) => self.port = port,
+            ConfigEntry::Host(host) => self.host = host,
+            ConfigEntry::Timeout(timeout) => self.timeout = timeout,
+            ConfigEntry::DebugLevel(level) => self.debug_level = level,
+        }
+        self
+    }
+    
+    /// Aplica `--port`, `--host`, `--timeout` y `--debug-level` sobre la configuración
+    ///
+    /// Pensado como última capa, encima de `from_file` o `from_env`. Cada
+    /// flag va seguido de su valor (`--port 9090`) y se valida igual que
+    /// en `from_env`; si un flag se repite gana el último. Ante cualquier
+    /// error la configuración queda como estaba.
+    fn apply_cli_overrides(&mut self, args: &[String]) -> Result<(), ConfigError> {
+        let mut config = self.clone();
+        let mut args = args.iter();
+        
+        while let Some(flag) = args.next() {
+            if !matches!(flag.as_str(), "--port" | "--host" | "--timeout" | "--debug-level") {
+                return Err(ConfigError::UnknownFlag(flag.clone()));
+            }
+            // CORREGIDO: `--port --host x` es un valor ausente, no el puerto "--host"
+            let value = match args.next() {
+                Some(value) if !value.starts_with("--") => value,
+                _ => return Err(ConfigError::MissingFlagValue(flag.clone())),
+            };
+            
+            match flag.as_str() {
+                "--port" => config.port = parse_env_port(flag, value)?,
+                "--host" => config.host = parse_env_host(flag, value)?,
+                "--timeout" => config.timeout = parse_env_timeout(flag, value)?,
+                _ => config.set_debug_level(value.trim()).map_err(ConfigError::ParseError)?,
+            }
+        }
+        
+        *self = config;
+        Ok(())
+    }
+}
+
+/// Niveles aceptados por `Config::set_debug_level` y `ConfigEntry::DebugLevel`
+const DEBUG_LEVELS: [&str; 5] = ["trace", "debug", "info", "warn", "error"];
+
+fn check_debug_level(level: &str) -> Result<(), String> {
+    if DEBUG_LEVELS.contains(&level) {
+        Ok(())
+    } else {
+        Err(format!("Nivel de debug inválido: {}. Niveles válidos: {:?}", level, DEBUG_LEVELS))
+    }
+}
+
+/// Una línea `clave=valor` de un archivo de configuración, ya validada
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum ConfigEntry {
+    Port(u16),
+    Host(String),
+    Timeout(u64),
+    DebugLevel(String),
+}
+
+impl TryFrom<&str> for ConfigEntry {
+    type Error = ConfigError;
+    
+    /// Parsea una sola línea; los errores son `ParseError`, sin número de línea
+    fn try_from(line: &str) -> Result<Self, Self::Error> {
+        let (key, value) = line
+            .split_once('=')
+            .ok_or_else(|| ConfigError::ParseError(format!("se esperaba 'clave=valor', encontrado '{}'", line.trim())))?;
+        let (key, value) = (key.trim(), value.trim());
+        
+        match key {
+            "port" => validate_port_safe(value).map(ConfigEntry::Port).map_err(ConfigError::ParseError),
+            "host" if value.is_empty() => Err(ConfigError::ParseError("el host no puede estar vacío".to_string())),
+            "host" => Ok(ConfigEntry::Host(value.to_string())),
+            "timeout" => value
+                .parse()
+                .map(ConfigEntry::Timeout)
+                .map_err(|e: ParseIntError| ConfigError::ParseError(format!("timeout '{}' inválido: {}", value, e))),
+            "debug_level" => check_debug_level(value)
+                .map(|()| ConfigEntry::DebugLevel(value.to_string()))
+                .map_err(ConfigError::ParseError),
+            _ => Err(ConfigError::ParseError(format!("clave desconocida '{}'", key))),
+        }
     }
 }
 
@@ -50,7 +357,7 @@ fn demonstrate_result_correct() {
     println!("✅ Demostrando manejo correcto de Result...");
     
     // CORREGIDO: Usar match en lugar de unwrap()
-    let config = Config::new(8080, "localhost".to_string(), 30);
+    let mut config = Config::new(8080, "localhost".to_string(), 30);
     
     // Manejo seguro del nivel de debug
     match config.set_debug_level("debug") {
@@ -95,6 +402,25 @@ fn demonstrate_file_handling_correct() {
     }
 }
 
+fn parse_env_port(var: &str, value: &str) -> Result<u16, ConfigError> {
+    validate_port_safe(value.trim()).map_err(|e| ConfigError::ParseError(format!("{}: {}", var, e)))
+}
+
+fn parse_env_host(var: &str, value: &str) -> Result<String, ConfigError> {
+    let host = value.trim();
+    if host.is_empty() {
+        return Err(ConfigError::ParseError(format!("{} no puede estar vacío", var)));
+    }
+    Ok(host.to_string())
+}
+
+fn parse_env_timeout(var: &str, value: &str) -> Result<u64, ConfigError> {
+    value
+        .trim()
+        .parse()
+        .map_err(|e: ParseIntError| ConfigError::ParseError(format!("{} '{}' inválido: {}", var, value, e)))
+}
+
 /// Función que lee archivo de configuración con manejo de errores
 fn read_config_file(filename: &str) -> Result<String, io::Error> {
     let mut file = File::open(filename)?;
@@ -163,7 +489,7 @@ fn demonstrate_custom_error_correct() {
 }
 
 /// Función que valida un puerto de forma segura
-fn validate_port_safe(port_str: &str) -> Result<u16, String> {
+pub(crate) fn validate_port_safe(port_str: &str) -> Result<u16, String> {
     // CORREGIDO: Validación completa
     let port: u16 = port_str.parse()
         .map_err(|_| format!("'{}' no es un número válido", port_str))?;
@@ -179,6 +505,39 @@ fn validate_port_safe(port_str: &str) -> Result<u16, String> {
     Ok(port)
 }
 
+/// Consume el iterador separando los `Ok` de los `Err`, cada uno en su orden original
+///
+/// A diferencia de `collect::<Result<Vec<_>, _>>()`, no se detiene en el
+/// primer error: útil para informar de todos los fallos de una vez.
+fn collect_results<T, E>(iter: impl Iterator<Item = Result<T, E>>) -> (Vec<T>, Vec<E>) {
+    let mut oks = Vec::new();
+    let mut errs = Vec::new();
+    for result in iter {
+        match result {
+            Ok(value) => oks.push(value),
+            Err(e) => errs.push(e),
+        }
+    }
+    (oks, errs)
+}
+
+/// Función que demuestra separar éxitos y fallos de una secuencia de Results
+fn demonstrate_collect_results() {
+    println!("\n✅ Demostrando collect_results...");
+    
+    let inputs = ["8080", "abc", "443", "0", "3000"];
+    
+    let first_error: Result<Vec<u16>, String> = inputs.iter().map(|s| validate_port_safe(s)).collect();
+    println!("collect::<Result<Vec<_>, _>>(): {:?}", first_error);
+    
+    // CORREGIDO: Se procesan todas las entradas y se informa de todos los errores
+    let (ports, errors) = collect_results(inputs.iter().map(|s| validate_port_safe(s)));
+    println!("Puertos válidos: {:?}", ports);
+    for error in &errors {
+        println!("   - {}", error);
+    }
+}
+
 /// Función que demuestra manejo correcto de panic recovery
 fn demonstrate_panic_recovery_correct() {
     println!("\n✅ Demostrando manejo correcto de panic recovery...");
@@ -204,27 +563,6 @@ fn demonstrate_panic_recovery_correct() {
 fn demonstrate_custom_error_types() {
     println!("\n✅ Demostrando tipos de error personalizados...");
     
-    #[derive(Debug)]
-    enum ConfigError {
-        InvalidPort(String),
-        InvalidHost(String),
-        FileNotFound(String),
-        ParseError(String),
-    }
-    
-    impl std::fmt::Display for ConfigError {
-        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-            match self {
-                ConfigError::InvalidPort(port) => write!(f, "Puerto inválido: {}", port),
-                ConfigError::InvalidHost(host) => write!(f, "Host inválido: {}", host),
-                ConfigError::FileNotFound(file) => write!(f, "Archivo no encontrado: {}", file),
-                ConfigError::ParseError(msg) => write!(f, "Error de parseo: {}", msg),
-            }
-        }
-    }
-    
-    impl std::error::Error for ConfigError {}
-    
     // Función que puede fallar con nuestro tipo de error
     fn load_config(port_str: &str, host: &str) -> Result<Config, ConfigError> {
         let port: u16 = port_str.parse()
@@ -251,9 +589,108 @@ fn demonstrate_custom_error_types() {
         Ok(config) => println!("Configuración cargada: {:?}", config),
         Err(e) => println!("Error al cargar configuración: {}", e),
     }
+    
+    // Política del despliegue: solo puertos de usuario (1024..=49151)
+    for port in [8080, 80] {
+        let config = Config::new(port, "localhost".to_string(), 30);
+        match config.validate_port_range(1024, 49151) {
+            Ok(()) => println!("Puerto {} permitido por la política", port),
+            Err(e) => println!("{}", e),
+        }
+    }
+}
+
+/// Función que demuestra errores de parseo con número de línea
+fn demonstrate_config_parse_errors() {
+    println!("\n✅ Demostrando errores de parseo con número de línea...");
+    
+    let valid = "# servidor\nport=9090\nhost=example.com\n\ntimeout=60\ndebug_level=warn";
+    match Config::parse(valid) {
+        Ok(config) => println!("Configuración válida: {:?}", config),
+        Err(e) => println!("Error inesperado: {}", e),
+    }
+    
+    // Cada línea se puede parsear por separado; el número de línea lo añade `parse`
+    for line in ["port = 9090", "timeout=soon", "color=rojo"] {
+        println!("{:?} -> {:?}", line, ConfigEntry::try_from(line));
+    }
+    
+    // CORREGIDO: El error indica exactamente qué línea corregir
+    let invalid = "# servidor\nhost=example.com\nport=abc";
+    match Config::parse(invalid) {
+        Ok(config) => println!("Configuración cargada: {:?}", config),
+        Err(e) => println!("{}", e),
+    }
+    
+    match Config::from_file("config.txt") {
+        Ok(config) => println!("config.txt cargado: {:?}", config),
+        Err(e) => println!("{}", e),
+    }
+}
+
+/// Función que demuestra el builder generado con #[derive(Builder)]
+fn demonstrate_config_builder() {
+    println!("\n✅ Demostrando ConfigBuilder generado por macro...");
+    
+    let config = Config::builder()
+        .with_port(9090)
+        .with_host("example.com".to_string())
+        .with_timeout(60)
+        .with_debug_level("warn".to_string())
+        .build();
+    println!("Builder completo: {:?}", config);
+    
+    match Config::builder().with_port(3000).build() {
+        Ok(config) => println!("Solo el puerto, resto por defecto: {:?}", config),
+        Err(e) => println!("Error: {}", e),
+    }
+    
+    // CORREGIDO: Un campo obligatorio ausente es un error, no un valor inventado
+    if let Err(e) = Config::builder().with_host("localhost".to_string()).build() {
+        println!("Builder incompleto: {}", e);
+    }
 }
 
-fn main() {
+/// Función que demuestra configuración desde variables de entorno
+fn demonstrate_config_from_env() {
+    println!("\n✅ Demostrando configuración desde variables de entorno...");
+    
+    // CORREGIDO: Solo APP_PORT es obligatoria; su ausencia es un error explícito, no un panic
+    match Config::from_env("APP") {
+        Ok(config) => println!("Configuración del entorno: {:?}", config),
+        Err(e) => println!("Error al leer el entorno: {}", e),
+    }
+    
+    // Cada variable ausente cae individualmente a su valor por defecto
+    let config = Config::from_env_or_default();
+    println!("Configuración con valores por defecto: {:?}", config);
+}
+
+/// Función que demuestra sobrescribir la configuración con argumentos de línea de comandos
+fn demonstrate_config_cli_overrides() {
+    println!("\n✅ Demostrando overrides desde la línea de comandos...");
+    
+    let to_args = |args: &[&str]| args.iter().map(|arg| arg.to_string()).collect::<Vec<String>>();
+    let mut config = Config::default();
+    println!("Configuración base: {:?}", config);
+    
+    match config.apply_cli_overrides(&to_args(&["--port", "9090", "--debug-level", "debug"])) {
+        Ok(()) => println!("Tras --port 9090 --debug-level debug: {:?}", config),
+        Err(e) => println!("Error: {}", e),
+    }
+    
+    for args in [&["--verbose"][..], &["--timeout", "30", "--port"], &["--port", "0"]] {
+        match config.apply_cli_overrides(&to_args(args)) {
+            Ok(()) => println!("{:?} aceptado", args),
+            Err(e) => println!("{:?} rechazado: {}", args, e),
+        }
+    }
+    // Los intentos fallidos no dejaron cambios a medias
+    println!("Configuración final: {:?}", config);
+}
+
+/// Ejecuta todos los demos; se detiene en el primer fallo real
+fn run_error_handling_demo() -> Result<(), DemoError> {
     println!("🦀 Rust Lab - Error Handling SOLUCIÓN CORRECTA");
     println!("{}", "=".repeat(60));
     
@@ -262,14 +699,28 @@ fn main() {
     demonstrate_file_handling_correct();
     demonstrate_option_correct();
     
-    // Manejar errores de propagación
+    // Que falte config.txt es parte de la demostración, no un fallo del demo
     if let Err(e) = demonstrate_error_propagation_correct() {
         println!("Error en propagación: {}", e);
     }
     
     demonstrate_custom_error_correct();
+    demonstrate_collect_results();
     demonstrate_panic_recovery_correct();
     demonstrate_custom_error_types();
+    demonstrate_config_parse_errors();
+    demonstrate_config_from_env();
+    demonstrate_config_cli_overrides();
+    demonstrate_config_builder();
+    lazy_config::demonstrate_lazy_config();
+    reloadable_config::demonstrate_reloadable_config()?;
+    file_watcher::demonstrate_config_watcher()?;
+    either::demonstrate_either();
+    protocol_parser::demonstrate_protocol_parser();
+    http_parser::demonstrate_http_parser();
+    retry_macro::demonstrate_retry();
+    #[cfg(feature = "toml-config")]
+    toml_config::demonstrate_toml_config();
     
     println!("\n✅ Todas las demostraciones completadas sin errores!");
     println!("🎯 Conceptos clave demostrados:");
@@ -277,6 +728,35 @@ fn main() {
     println!("   - Option<T>: Valores opcionales");
     println!("   - Error propagation: Propagación de errores");
     println!("   - Custom errors: Tipos de error personalizados");
+    println!("   - Collect results: Separar Ok y Err sin parar en el primer error");
+    println!("   - Parse errors: Errores con número de línea");
+    println!("   - TryFrom: Cada línea clave=valor se convierte en un ConfigEntry por separado");
+    println!("   - Environment config: Variables de entorno con valores por defecto");
+    println!("   - CLI overrides: Flags --clave valor como última capa de configuración");
+    println!("   - Global config: Inicialización única con OnceLock");
+    println!("   - Hot reload: Recargar al avanzar el mtime del archivo");
+    println!("   - File watching: Un thread que recarga y avisa con un callback");
+    println!("   - Exit codes: Los fallos reales del demo terminan con ExitCode::FAILURE");
+    println!("   - Derive macros: Builder generado con #[derive(Builder)]");
+    println!("   - Sum types: Either<L, R> cuando ninguna de las dos variantes es un error");
+    println!("   - Protocol parsing: Un Result por línea de un flujo");
+    println!("   - HTTP parsing: Recorrer bytes a mano con un error por cada parte mal formada");
+    println!("   - Retry: Reintentos con función y con macro declarativa");
+    println!("   - Retriable errors: Los errores permanentes no se reintentan");
+    #[cfg(feature = "toml-config")]
+    println!("   - TOML config: Deserialización estructurada con serde");
     println!("   - Panic recovery: Recuperación de panics");
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    // CORREGIDO: Un fallo real termina con código distinto de 0 para que el runner lo detecte
+    match run_error_handling_demo() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("❌ El demo falló: {}", e);
+            ExitCode::FAILURE
+        }
+    }
 }
 
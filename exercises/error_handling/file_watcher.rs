@@ -0,0 +1,124 @@
+//! 🦀 File Watcher - Recargar `Config` en segundo plano al cambiar el archivo
+//!
+//! `ReloadableConfig` solo mira el archivo cuando alguien lo consulta.
+//! `ConfigWatcher` lanza un thread que compara el mtime cada
+//! `poll_interval` y, cuando cambia, vuelve a leer y parsear el archivo y
+//! entrega la nueva `Config` a un callback. Es polling, no notificaciones
+//! del sistema (inotify, FSEvents...): más simple y portable, a cambio de
+//! tardar hasta un intervalo en enterarse. Quien escribe el archivo
+//! debería hacerlo en uno temporal y renombrarlo, como `write_config`,
+//! para que el watcher nunca lea una escritura a medias.
+
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, SystemTime};
+
+use super::reloadable_config::{modified_time, read_config, write_config};
+use super::{Config, ConfigError, DemoError};
+
+/// Callback que recibe cada recarga: la configuración nueva o por qué se rechazó
+pub type OnChange = Arc<dyn Fn(Result<Config, ConfigError>) + Send + Sync>;
+
+/// Thread que vigila un archivo de configuración hasta `stop` (o hasta soltarlo)
+pub struct ConfigWatcher {
+    /// Al soltarlo, el `recv_timeout` del thread devuelve `Disconnected` y el bucle termina
+    stop: Option<Sender<()>>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl ConfigWatcher {
+    /// Empieza a vigilar `path`; el estado actual del archivo no dispara el callback
+    pub fn new<F>(path: PathBuf, poll_interval: Duration, on_change: F) -> Self
+    where
+        F: Fn(Result<Config, ConfigError>) + Send + Sync + 'static,
+    {
+        let on_change: OnChange = Arc::new(on_change);
+        // Se lee aquí y no en el thread: un cambio justo después de `new` no se pierde
+        let last_modified = modified_time(&path).ok();
+        let (stop, stopped) = mpsc::channel::<()>();
+        
+        let handle = thread::spawn(move || {
+            let mut last_modified = last_modified;
+            // CORREGIDO: Esperar en el canal en lugar de `sleep` hace que `stop` no tenga que esperar un intervalo entero
+            while let Err(RecvTimeoutError::Timeout) = stopped.recv_timeout(poll_interval) {
+                last_modified = check_for_change(&path, last_modified, &on_change);
+            }
+        });
+        
+        Self {
+            stop: Some(stop),
+            handle: Some(handle),
+        }
+    }
+    
+    /// Para el thread y espera a que termine
+    pub fn stop(mut self) {
+        self.shutdown();
+    }
+    
+    fn shutdown(&mut self) {
+        drop(self.stop.take());
+        if let Some(handle) = self.handle.take() {
+            // Un panic del callback ya terminó el thread; no hay nada más que parar
+            let _ = handle.join();
+        }
+    }
+}
+
+impl Drop for ConfigWatcher {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+/// Recarga si el mtime es distinto del último visto y devuelve el mtime actual
+///
+/// Se compara con `!=` y no con `>`: reemplazar el archivo por una copia
+/// antigua también es un cambio. Un archivo que no parsea llega al
+/// callback como `Err` una sola vez: su mtime se da por visto para no
+/// repetir el error en cada intervalo, y la siguiente escritura se vuelve
+/// a intentar.
+fn check_for_change(path: &Path, last_modified: Option<SystemTime>, on_change: &OnChange) -> Option<SystemTime> {
+    let modified = modified_time(path).ok();
+    if modified.is_none() || modified == last_modified {
+        return last_modified;
+    }
+    
+    on_change(read_config(path));
+    modified
+}
+
+/// Función que demuestra recibir la configuración nueva desde un thread vigilante
+pub fn demonstrate_config_watcher() -> Result<(), DemoError> {
+    println!("\n✅ Demostrando ConfigWatcher en segundo plano...");
+    
+    let path = std::env::temp_dir().join(format!("rust_lab_watched_config_{}.txt", std::process::id()));
+    let start = SystemTime::now();
+    write_config(&path, "port=8080\nhost=localhost\n", start)?;
+    
+    let poll_interval = Duration::from_millis(50);
+    let (tx, rx) = mpsc::channel();
+    let watcher = ConfigWatcher::new(path.clone(), poll_interval, move |reload| {
+        // El receptor puede haberse ido si el demo ya terminó
+        let _ = tx.send(reload);
+    });
+    let show_reload = |reload| match reload {
+        Ok(Ok(config)) => println!("Recargada en segundo plano: {:?}", config),
+        Ok(Err(e)) => println!("Recarga rechazada: {}", e),
+        Err(_) => println!("No llegó ninguna recarga en {:?}", poll_interval * 10),
+    };
+    
+    // El mtime se adelanta a mano: algunos sistemas de archivos solo guardan segundos
+    write_config(&path, "port=9090\nhost=localhost\n", start + Duration::from_secs(1))?;
+    show_reload(rx.recv_timeout(poll_interval * 10));
+    
+    // Un archivo roto llega como error y quien llama decide qué hacer con él
+    write_config(&path, "port=abc\n", start + Duration::from_secs(2))?;
+    show_reload(rx.recv_timeout(poll_interval * 10));
+    
+    watcher.stop();
+    let _ = std::fs::remove_file(&path);
+    Ok(())
+}
@@ -0,0 +1,244 @@
+//! 🦀 HTTP Parser - Línea de petición y cabeceras de HTTP/1.1
+//!
+//! ```text
+//! POST /users HTTP/1.1\r\n
+//! Host: example.com\r\n
+//! Content-Length: 5\r\n
+//! \r\n
+//! hello
+//! ```
+//!
+//! El parser recorre los bytes a mano, sin crates externos ni
+//! conversiones a `String` hasta que cada trozo está validado. Cualquier
+//! entrada mal formada produce un `HttpParseError` que dice qué parte
+//! falló, nunca un panic.
+
+use std::collections::HashMap;
+
+/// Tamaño máximo de cuerpo aceptado, para no reservar memoria a ciegas
+pub const MAX_BODY_SIZE: usize = 1024 * 1024;
+
+/// Método de la petición; los no estándar se guardan tal cual
+#[derive(Debug, Clone, PartialEq)]
+pub enum Method {
+    Get,
+    Post,
+    Put,
+    Delete,
+    Other(String),
+}
+
+impl Method {
+    fn from_token(token: &str) -> Self {
+        match token {
+            "GET" => Method::Get,
+            "POST" => Method::Post,
+            "PUT" => Method::Put,
+            "DELETE" => Method::Delete,
+            other => Method::Other(other.to_string()),
+        }
+    }
+}
+
+/// Petición ya parseada
+///
+/// Los nombres de las cabeceras se guardan en minúsculas, porque en HTTP
+/// no distinguen mayúsculas.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HttpRequest {
+    pub method: Method,
+    pub path: String,
+    pub version: (u8, u8),
+    pub headers: HashMap<String, String>,
+    pub body: Vec<u8>,
+}
+
+/// Errores al parsear una petición
+#[derive(Debug, Clone, PartialEq)]
+pub enum HttpParseError {
+    /// La primera línea no es `MÉTODO RUTA HTTP/x.y`
+    MalformedRequestLine(String),
+    /// Cabecera sin `:`, con nombre vacío o con un valor no válido
+    InvalidHeader(String),
+    BodyTooLarge { size: usize, max: usize },
+    /// Faltan la línea vacía tras las cabeceras o bytes del cuerpo
+    Incomplete,
+}
+
+impl std::fmt::Display for HttpParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            HttpParseError::MalformedRequestLine(line) => write!(f, "Línea de petición mal formada: '{}'", line),
+            HttpParseError::InvalidHeader(line) => write!(f, "Cabecera inválida: '{}'", line),
+            HttpParseError::BodyTooLarge { size, max } => {
+                write!(f, "Cuerpo de {} bytes, el máximo es {}", size, max)
+            }
+            HttpParseError::Incomplete => write!(f, "Petición incompleta"),
+        }
+    }
+}
+
+impl std::error::Error for HttpParseError {}
+
+/// Lectura de líneas terminadas en `\r\n` (o solo `\n`) sobre un buffer de bytes
+struct Cursor<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    /// Siguiente línea sin el terminador, o `None` si no queda ninguna completa
+    fn next_line(&mut self) -> Option<&'a [u8]> {
+        let start = self.pos;
+        let mut i = start;
+        while i < self.bytes.len() {
+            if self.bytes[i] == b'\n' {
+                self.pos = i + 1;
+                let line = &self.bytes[start..i];
+                return Some(line.strip_suffix(b"\r").unwrap_or(line));
+            }
+            i += 1;
+        }
+        None
+    }
+    
+    fn rest(&self) -> &'a [u8] {
+        &self.bytes[self.pos..]
+    }
+}
+
+fn lossy(bytes: &[u8]) -> String {
+    String::from_utf8_lossy(bytes).into_owned()
+}
+
+/// Parsea `MÉTODO RUTA HTTP/x.y`, separados por un único espacio
+fn parse_request_line(line: &[u8]) -> Result<(Method, String, (u8, u8)), HttpParseError> {
+    let malformed = || HttpParseError::MalformedRequestLine(lossy(line));
+    
+    let mut parts: Vec<&[u8]> = Vec::with_capacity(3);
+    let mut start = 0;
+    for (i, &byte) in line.iter().enumerate() {
+        if byte == b' ' {
+            parts.push(&line[start..i]);
+            start = i + 1;
+        }
+    }
+    parts.push(&line[start..]);
+    
+    let [method, path, version] = parts[..] else {
+        return Err(malformed());
+    };
+    if method.is_empty() || !method.iter().all(|b| b.is_ascii_uppercase()) {
+        return Err(malformed());
+    }
+    // Solo se aceptan rutas absolutas y el `*` de OPTIONS, en ASCII visible (lo demás va codificado con %)
+    if !(path.first() == Some(&b'/') || path == b"*") || !path.iter().all(|b| b.is_ascii_graphic()) {
+        return Err(malformed());
+    }
+    let version = match version {
+        [b'H', b'T', b'T', b'P', b'/', major, b'.', minor] if major.is_ascii_digit() && minor.is_ascii_digit() => {
+            (major - b'0', minor - b'0')
+        }
+        _ => return Err(malformed()),
+    };
+    
+    // Los tres trozos son ASCII ya validado, así que la conversión no pierde nada
+    Ok((Method::from_token(&lossy(method)), lossy(path), version))
+}
+
+/// Parsea `Nombre: valor`; devuelve el nombre en minúsculas y el valor sin espacios alrededor
+fn parse_header(line: &[u8]) -> Result<(String, String), HttpParseError> {
+    let invalid = || HttpParseError::InvalidHeader(lossy(line));
+    
+    let colon = line.iter().position(|&b| b == b':').ok_or_else(invalid)?;
+    let name = &line[..colon];
+    // El nombre no puede estar vacío ni llevar espacios (tampoco antes de los dos puntos)
+    if name.is_empty() || !name.iter().all(|b| b.is_ascii_graphic()) {
+        return Err(invalid());
+    }
+    
+    let value = line[colon + 1..].trim_ascii();
+    if value.iter().any(|&b| b.is_ascii_control() && b != b'\t') {
+        return Err(invalid());
+    }
+    let value = std::str::from_utf8(value).map_err(|_| invalid())?;
+    Ok((lossy(name).to_ascii_lowercase(), value.to_string()))
+}
+
+/// Parsea una petición completa: línea de petición, cabeceras y cuerpo
+///
+/// El cuerpo se lee solo si hay `Content-Length`; sin ella la petición no
+/// tiene cuerpo y los bytes sobrantes se ignoran.
+pub fn parse_request(raw: &[u8]) -> Result<HttpRequest, HttpParseError> {
+    let mut cursor = Cursor { bytes: raw, pos: 0 };
+    
+    let request_line = cursor.next_line().ok_or(HttpParseError::Incomplete)?;
+    let (method, path, version) = parse_request_line(request_line)?;
+    
+    let mut headers: HashMap<String, String> = HashMap::new();
+    loop {
+        let line = cursor.next_line().ok_or(HttpParseError::Incomplete)?;
+        if line.is_empty() {
+            break;
+        }
+        let (name, value) = parse_header(line)?;
+        // Una cabecera repetida equivale a sus valores separados por comas
+        headers
+            .entry(name)
+            .and_modify(|existing| {
+                existing.push_str(", ");
+                existing.push_str(&value);
+            })
+            .or_insert(value);
+    }
+    
+    let body = match headers.get("content-length") {
+        None => Vec::new(),
+        Some(length) => {
+            let size: usize = length
+                .parse()
+                .map_err(|_| HttpParseError::InvalidHeader(format!("content-length: {}", length)))?;
+            // CORREGIDO: Se comprueba el tamaño antes de reservar o copiar nada
+            if size > MAX_BODY_SIZE {
+                return Err(HttpParseError::BodyTooLarge { size, max: MAX_BODY_SIZE });
+            }
+            cursor.rest().get(..size).ok_or(HttpParseError::Incomplete)?.to_vec()
+        }
+    };
+    
+    Ok(HttpRequest {
+        method,
+        path,
+        version,
+        headers,
+        body,
+    })
+}
+
+/// Función que demuestra parsear peticiones HTTP válidas y erróneas
+pub fn demonstrate_http_parser() {
+    println!("\n✅ Demostrando parser de peticiones HTTP/1.1...");
+    
+    let requests: [&[u8]; 5] = [
+        b"GET /users?id=7 HTTP/1.1\r\nHost: example.com\r\nAccept: */*\r\n\r\n",
+        b"POST /users HTTP/1.1\r\nHost: example.com\r\nContent-Length: 5\r\n\r\nhello",
+        b"GET /users\r\n\r\n",
+        b"GET / HTTP/1.1\r\nHost example.com\r\n\r\n",
+        b"PUT /upload HTTP/1.1\r\nContent-Length: 99999999999\r\n\r\n",
+    ];
+    
+    for raw in requests {
+        match parse_request(raw) {
+            Ok(request) => println!(
+                "{:?} {} HTTP/{}.{} host={:?} body={:?}",
+                request.method,
+                request.path,
+                request.version.0,
+                request.version.1,
+                request.headers.get("host"),
+                String::from_utf8_lossy(&request.body)
+            ),
+            Err(e) => println!("Error: {}", e),
+        }
+    }
+}
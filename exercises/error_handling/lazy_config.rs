@@ -0,0 +1,62 @@
+//! 🦀 Lazy Config - Configuración global inicializada una sola vez
+//!
+//! En lugar de crear un `Config` nuevo en cada función, se guarda uno
+//! en un `static` con `OnceLock`: el primer acceso lo construye y todos
+//! los threads comparten después la misma instancia.
+
+use std::sync::OnceLock;
+use std::thread;
+
+use super::Config;
+
+/// `Config` compartido que se construye en el primer acceso
+pub struct LazyConfig {
+    cell: OnceLock<Config>,
+}
+
+impl LazyConfig {
+    /// `const` para poder usarlo en un `static`
+    pub const fn new() -> Self {
+        Self {
+            cell: OnceLock::new(),
+        }
+    }
+    
+    /// Devuelve la configuración, ejecutando `init` solo si aún no existe
+    ///
+    /// Si varios threads llegan a la vez, solo uno ejecuta `init`
+    /// y el resto espera a su resultado.
+    pub fn get_or_init(&self, init: impl FnOnce() -> Config) -> &Config {
+        self.cell.get_or_init(init)
+    }
+    
+    pub fn is_initialized(&self) -> bool {
+        self.cell.get().is_some()
+    }
+}
+
+static GLOBAL_CONFIG: LazyConfig = LazyConfig::new();
+
+/// Configuración global del proceso, creada con `Config::default()`
+pub fn global() -> &'static Config {
+    GLOBAL_CONFIG.get_or_init(Config::default)
+}
+
+/// Función que demuestra una configuración global compartida entre threads
+pub fn demonstrate_lazy_config() {
+    println!("\n✅ Demostrando configuración global con OnceLock...");
+    
+    println!("¿Inicializada antes del primer acceso? {}", GLOBAL_CONFIG.is_initialized());
+    
+    // CORREGIDO: Todos los threads reciben la misma instancia, sin recrearla
+    let handles: Vec<_> = (0..3)
+        .map(|i| thread::spawn(move || (i, global() as *const Config as usize)))
+        .collect();
+    
+    for handle in handles {
+        let (i, address) = handle.join().unwrap();
+        println!("Thread {}: Config en {:#x}", i, address);
+    }
+    
+    println!("Configuración global: {:?}", global());
+}
@@ -0,0 +1,164 @@
+//! 🦀 Protocol Parser - Protocolo clave-valor basado en líneas
+//!
+//! Cada mensaje ocupa una línea: `CMD key=value key2=value2`.
+//!
+//! ```text
+//! GET key=usuario
+//! SET key=usuario value=alice
+//! DELETE key=usuario
+//! ```
+//!
+//! Una línea mal formada produce un `ParseError` en lugar de un panic,
+//! y el resto del flujo se sigue procesando.
+
+use std::io::BufRead;
+
+/// Mensaje reconocido por el protocolo
+#[derive(Debug, Clone, PartialEq)]
+pub enum Command {
+    Get { key: String },
+    Set { key: String, value: String },
+    Delete { key: String },
+    /// Comando que el parser no conoce, con su nombre original
+    Unknown(String),
+}
+
+/// Errores al interpretar una línea
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseError {
+    EmptyLine,
+    /// Token sin la forma `clave=valor`
+    MalformedField(String),
+    MissingField { command: String, field: &'static str },
+    UnexpectedField { command: String, field: String },
+    DuplicateField { command: String, field: String },
+    /// Fallo de lectura del flujo
+    Io(String),
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ParseError::EmptyLine => write!(f, "Línea vacía"),
+            ParseError::MalformedField(token) => {
+                write!(f, "Campo mal formado '{}': se esperaba clave=valor", token)
+            }
+            ParseError::MissingField { command, field } => {
+                write!(f, "{}: falta el campo obligatorio '{}'", command, field)
+            }
+            ParseError::UnexpectedField { command, field } => {
+                write!(f, "{}: campo no permitido '{}'", command, field)
+            }
+            ParseError::DuplicateField { command, field } => {
+                write!(f, "{}: campo repetido '{}'", command, field)
+            }
+            ParseError::Io(msg) => write!(f, "Error de lectura: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parser sin estado del protocolo clave-valor
+pub struct ProtocolParser;
+
+impl ProtocolParser {
+    /// Interpreta una línea; el salto de línea final es opcional
+    pub fn parse_line(line: &str) -> Result<Command, ParseError> {
+        let mut tokens = line.split_whitespace();
+        let name = tokens.next().ok_or(ParseError::EmptyLine)?;
+        
+        let mut fields = Vec::new();
+        for token in tokens {
+            match token.split_once('=') {
+                Some((key, value)) if !key.is_empty() => fields.push((key, value)),
+                _ => return Err(ParseError::MalformedField(token.to_string())),
+            }
+        }
+        
+        match name {
+            "GET" => {
+                let [key] = expect_fields(name, &fields, ["key"])?;
+                Ok(Command::Get { key })
+            }
+            "SET" => {
+                let [key, value] = expect_fields(name, &fields, ["key", "value"])?;
+                Ok(Command::Set { key, value })
+            }
+            "DELETE" => {
+                let [key] = expect_fields(name, &fields, ["key"])?;
+                Ok(Command::Delete { key })
+            }
+            other => Ok(Command::Unknown(other.to_string())),
+        }
+    }
+    
+    /// Interpreta un flujo línea a línea, saltando las líneas en blanco
+    ///
+    /// Cada línea produce su propio `Result`: un error no detiene el
+    /// iterador, así que el llamador decide si continuar.
+    pub fn parse_stream(reader: impl BufRead) -> impl Iterator<Item = Result<Command, ParseError>> {
+        reader
+            .lines()
+            .filter(|line| !matches!(line, Ok(text) if text.trim().is_empty()))
+            .map(|line| {
+                let line = line.map_err(|e| ParseError::Io(e.to_string()))?;
+                ProtocolParser::parse_line(&line)
+            })
+    }
+}
+
+/// Comprueba que `fields` contiene exactamente los campos esperados
+/// y devuelve sus valores en el orden de `expected`
+fn expect_fields<const N: usize>(
+    command: &str,
+    fields: &[(&str, &str)],
+    expected: [&'static str; N],
+) -> Result<[String; N], ParseError> {
+    let mut values: [Option<String>; N] = std::array::from_fn(|_| None);
+    
+    for &(key, value) in fields {
+        let Some(index) = expected.iter().position(|&name| name == key) else {
+            return Err(ParseError::UnexpectedField {
+                command: command.to_string(),
+                field: key.to_string(),
+            });
+        };
+        if values[index].is_some() {
+            return Err(ParseError::DuplicateField {
+                command: command.to_string(),
+                field: key.to_string(),
+            });
+        }
+        values[index] = Some(value.to_string());
+    }
+    
+    let mut result: [String; N] = std::array::from_fn(|_| String::new());
+    for (i, value) in values.into_iter().enumerate() {
+        result[i] = value.ok_or_else(|| ParseError::MissingField {
+            command: command.to_string(),
+            field: expected[i],
+        })?;
+    }
+    Ok(result)
+}
+
+/// Función que demuestra el parseo de un flujo con líneas erróneas
+pub fn demonstrate_protocol_parser() {
+    println!("\n✅ Demostrando parser de protocolo clave-valor...");
+    
+    let transcript = "SET key=usuario value=alice\n\
+                      GET key=usuario\n\
+                      SET key=usuario\n\
+                      \n\
+                      DELETE key=usuario extra=1\n\
+                      PING\n";
+    
+    // CORREGIDO: Cada línea errónea se informa y el flujo continúa
+    for result in ProtocolParser::parse_stream(transcript.as_bytes()) {
+        match result {
+            Ok(command) => println!("Comando: {:?}", command),
+            Err(e) => println!("Error: {}", e),
+        }
+    }
+}
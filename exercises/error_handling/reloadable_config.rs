@@ -0,0 +1,121 @@
+//! 🦀 Reloadable Config - Recargar la configuración cuando cambia el archivo
+//!
+//! Sin depender de un watcher del sistema de archivos: basta con
+//! guardar la fecha de modificación (mtime) del último archivo leído y
+//! compararla al consultar. Si avanzó, se vuelve a leer y parsear.
+
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use super::{Config, ConfigError, DemoError};
+
+/// `Config` junto con el mtime del archivo del que salió
+#[derive(Debug)]
+pub struct ReloadableConfig {
+    path: PathBuf,
+    config: Config,
+    modified: SystemTime,
+}
+
+impl ReloadableConfig {
+    /// Lee y parsea el archivo por primera vez
+    pub fn new(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        let path = path.as_ref().to_path_buf();
+        let modified = modified_time(&path)?;
+        let config = read_config(&path)?;
+        Ok(Self { path, config, modified })
+    }
+    
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+    
+    /// Vuelve a cargar el archivo solo si su mtime es posterior al de la última carga
+    ///
+    /// Devuelve `Ok(true)` si recargó. Si el archivo nuevo no parsea, se
+    /// conserva la configuración anterior y el mtime no avanza, así que la
+    /// siguiente llamada lo vuelve a intentar.
+    ///
+    /// Algunos sistemas de archivos guardan el mtime con resolución de
+    /// segundos: dos escrituras en el mismo segundo no se distinguen.
+    pub fn reload_if_changed(&mut self) -> Result<bool, ConfigError> {
+        let modified = modified_time(&self.path)?;
+        if modified <= self.modified {
+            return Ok(false);
+        }
+        
+        // CORREGIDO: Se parsea antes de tocar el estado, para no quedarse a medias
+        self.config = read_config(&self.path)?;
+        self.modified = modified;
+        Ok(true)
+    }
+}
+
+pub fn modified_time(path: &Path) -> Result<SystemTime, ConfigError> {
+    fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .map_err(|e| ConfigError::FileNotFound(format!("{} ({})", path.display(), e)))
+}
+
+pub fn read_config(path: &Path) -> Result<Config, ConfigError> {
+    let contents = fs::read_to_string(path)
+        .map_err(|e| ConfigError::FileNotFound(format!("{} ({})", path.display(), e)))?;
+    Config::parse(&contents)
+}
+
+/// Escribe `contents` y adelanta el mtime para que el cambio se note aunque el reloj sea grueso
+///
+/// `File::create` vacía el archivo y actualiza el mtime antes de escribir
+/// nada: quien lo lea entre medias vería un archivo vacío, que parsea como
+/// `Config::default()`. Por eso se escribe un archivo hermano con su mtime
+/// ya puesto y se renombra encima de `path`, que cambia de golpe.
+pub fn write_config(path: &Path, contents: &str, modified: SystemTime) -> std::io::Result<()> {
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(".tmp");
+    let tmp = PathBuf::from(tmp);
+    
+    let written = File::create(&tmp).and_then(|mut file| {
+        file.write_all(contents.as_bytes())?;
+        file.set_modified(modified)
+    });
+    // CORREGIDO: Si algo falla, no se deja el temporal abandonado junto al original
+    written.and_then(|()| fs::rename(&tmp, path)).inspect_err(|_| {
+        let _ = fs::remove_file(&tmp);
+    })
+}
+
+/// Función que demuestra recargar la configuración al detectar cambios en el archivo
+pub fn demonstrate_reloadable_config() -> Result<(), DemoError> {
+    println!("\n✅ Demostrando recarga de configuración por mtime...");
+    
+    let path = std::env::temp_dir().join("rust_lab_reloadable_config.txt");
+    write_config(&path, "port=8080\nhost=localhost\n", SystemTime::now())?;
+    let result = run_reloadable_config_demo(&path);
+    let _ = fs::remove_file(&path);
+    result
+}
+
+/// Carga `path`, lo reescribe y recarga; `path` debe existir ya
+///
+/// Un archivo ausente o ilegible es un fallo del demo. El archivo roto
+/// del final se escribe a propósito y solo se muestra.
+pub fn run_reloadable_config_demo(path: &Path) -> Result<(), DemoError> {
+    let mut reloadable = ReloadableConfig::new(path)?;
+    let start = reloadable.modified;
+    println!("Configuración inicial: {:?}", reloadable.config());
+    println!("¿Recargada sin cambios? {}", reloadable.reload_if_changed()?);
+    
+    write_config(path, "port=9090\nhost=localhost\n", start + Duration::from_secs(1))?;
+    println!("¿Recargada tras reescribir? {}", reloadable.reload_if_changed()?);
+    println!("Puerto nuevo: {}", reloadable.config().port);
+    
+    // Un archivo roto no pisa la configuración que ya funcionaba
+    write_config(path, "port=abc\n", start + Duration::from_secs(2))?;
+    if let Err(e) = reloadable.reload_if_changed() {
+        println!("Recarga rechazada: {}", e);
+    }
+    println!("Se mantiene el puerto: {}", reloadable.config().port);
+    Ok(())
+}
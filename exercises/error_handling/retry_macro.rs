@@ -0,0 +1,158 @@
+//! 🦀 Retry - Reintentos como función y como macro
+//!
+//! `retry` recibe un closure; `retry!` recibe un bloque que se expande
+//! dentro de la función que lo usa. El valor final del bloque es el
+//! `Result` que se reintenta, y un `?` dentro del bloque sale
+//! directamente de la función que llama, sin reintentar: útil para
+//! errores que no son transitorios.
+//!
+//! `retry_smart` hace esa distinción con el tipo de error: solo reintenta
+//! los errores que implementan `Retriable` y dicen ser transitorios.
+
+use std::cell::Cell;
+use std::io;
+use std::thread;
+use std::time::Duration;
+
+/// Llama a `op` hasta que devuelva `Ok` o se agoten los intentos
+///
+/// Siempre hace al menos un intento y espera `delay` entre fallos.
+/// Devuelve el último error si ninguno tuvo éxito.
+pub fn retry<T, E>(attempts: u32, delay: Duration, mut op: impl FnMut() -> Result<T, E>) -> Result<T, E> {
+    let mut attempt = 1;
+    loop {
+        match op() {
+            Ok(value) => return Ok(value),
+            Err(e) if attempt >= attempts => return Err(e),
+            Err(_) => {
+                thread::sleep(delay);
+                attempt += 1;
+            }
+        }
+    }
+}
+
+/// Errores que saben si tiene sentido volver a intentar la operación
+pub trait Retriable {
+    /// `true` para fallos transitorios (timeouts, conexiones cortadas),
+    /// `false` para los que se repetirían igual (no existe, sin permiso)
+    fn is_retriable(&self) -> bool;
+}
+
+impl Retriable for io::Error {
+    fn is_retriable(&self) -> bool {
+        matches!(
+            self.kind(),
+            io::ErrorKind::TimedOut
+                | io::ErrorKind::Interrupted
+                | io::ErrorKind::WouldBlock
+                | io::ErrorKind::ConnectionReset
+                | io::ErrorKind::ConnectionAborted
+        )
+    }
+}
+
+/// Como `retry`, pero un error no reintentable se devuelve en el acto
+pub fn retry_smart<T, E: Retriable>(
+    attempts: u32,
+    delay: Duration,
+    mut op: impl FnMut() -> Result<T, E>,
+) -> Result<T, E> {
+    let mut attempt = 1;
+    loop {
+        match op() {
+            Ok(value) => return Ok(value),
+            // CORREGIDO: Esperar y repetir un error permanente solo retrasa el mismo fallo
+            Err(e) if !e.is_retriable() || attempt >= attempts => return Err(e),
+            Err(_) => {
+                thread::sleep(delay);
+                attempt += 1;
+            }
+        }
+    }
+}
+
+/// Igual que `retry`, pero con un bloque en lugar de un closure
+///
+/// ```ignore
+/// let data = retry!(3, Duration::from_millis(10), {
+///     let path = config.path()?; // error no transitorio: sale de la función
+///     read_remote(path)          // Result que se reintenta
+/// })?;
+/// ```
+macro_rules! retry {
+    ($attempts:expr, $delay:expr, $body:block) => {{
+        let attempts: u32 = $attempts;
+        let delay: ::std::time::Duration = $delay;
+        let mut attempt = 1;
+        loop {
+            match $body {
+                ::core::result::Result::Ok(value) => break ::core::result::Result::Ok(value),
+                ::core::result::Result::Err(e) if attempt >= attempts => break ::core::result::Result::Err(e),
+                ::core::result::Result::Err(_) => {
+                    ::std::thread::sleep(delay);
+                    attempt += 1;
+                }
+            }
+        }
+    }};
+}
+
+/// Simula una lectura que falla las primeras `failures` veces
+fn flaky_read(calls: &Cell<u32>, failures: u32) -> io::Result<String> {
+    calls.set(calls.get() + 1);
+    if calls.get() <= failures {
+        Err(io::Error::new(io::ErrorKind::TimedOut, format!("timeout en el intento {}", calls.get())))
+    } else {
+        Ok("8080".to_string())
+    }
+}
+
+/// Lee un puerto reintentando la lectura, pero no un límite mal configurado
+fn read_port(limit: &str, calls: &Cell<u32>) -> Result<u16, Box<dyn std::error::Error>> {
+    let text = retry!(3, Duration::from_millis(5), {
+        // CORREGIDO: `?` dentro del bloque devuelve el error al llamador sin reintentar
+        let failures: u32 = limit.parse()?;
+        flaky_read(calls, failures)
+    })?;
+    Ok(text.parse()?)
+}
+
+/// Función que demuestra reintentos con función y con macro
+pub fn demonstrate_retry() {
+    println!("\n✅ Demostrando reintentos con retry y retry!...");
+    
+    let calls = Cell::new(0);
+    let result = retry(3, Duration::from_millis(5), || flaky_read(&calls, 2));
+    println!("retry(): {:?} tras {} llamadas", result, calls.get());
+    
+    let calls = Cell::new(0);
+    let result = retry!(3, Duration::from_millis(5), { flaky_read(&calls, 5) });
+    println!("retry!() agotado: {:?} tras {} llamadas", result.map_err(|e| e.to_string()), calls.get());
+    
+    let calls = Cell::new(0);
+    let result = retry_smart(3, Duration::from_millis(5), || flaky_read(&calls, 1));
+    println!("retry_smart() con timeout: {:?} tras {} llamadas", result, calls.get());
+    
+    let calls = Cell::new(0);
+    let result: io::Result<String> = retry_smart(3, Duration::from_millis(5), || {
+        calls.set(calls.get() + 1);
+        Err(io::Error::new(io::ErrorKind::NotFound, "config.toml no existe"))
+    });
+    println!(
+        "retry_smart() con NotFound: {:?} tras {} llamada(s)",
+        result.map_err(|e| e.to_string()),
+        calls.get()
+    );
+    
+    let calls = Cell::new(0);
+    match read_port("1", &calls) {
+        Ok(port) => println!("Puerto leído: {} tras {} llamadas", port, calls.get()),
+        Err(e) => println!("Error: {}", e),
+    }
+    
+    let calls = Cell::new(0);
+    if let Err(e) = read_port("uno", &calls) {
+        println!("Error no transitorio: {} ({} llamadas)", e, calls.get());
+    }
+}
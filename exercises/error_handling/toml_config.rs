@@ -0,0 +1,164 @@
+//! 🦀 TOML Config - Configuración estructurada con `toml`
+//!
+//! En lugar de tratar el archivo como texto opaco, se deserializa a
+//! structs con serde. Solo se compila con la feature `toml-config`.
+//!
+//! ```toml
+//! [server]
+//! port = 8080
+//! host = "localhost"
+//!
+//! [app]
+//! timeout = 30
+//! debug_level = "info"   # opcional
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+use super::{Config, ConfigError};
+
+#[derive(Debug, Serialize, Deserialize)]
+struct TomlFile {
+    server: ServerSection,
+    app: AppSection,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ServerSection {
+    port: u16,
+    host: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct AppSection {
+    timeout: u64,
+    #[serde(default = "default_debug_level")]
+    debug_level: String,
+}
+
+fn default_debug_level() -> String {
+    "info".to_string()
+}
+
+/// Mismo esquema que `TomlFile`, pero una sección o clave de más es un error
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct StrictTomlFile {
+    server: StrictServerSection,
+    app: StrictAppSection,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct StrictServerSection {
+    port: u16,
+    host: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct StrictAppSection {
+    timeout: u64,
+    #[serde(default = "default_debug_level")]
+    debug_level: String,
+}
+
+impl From<StrictTomlFile> for TomlFile {
+    fn from(file: StrictTomlFile) -> Self {
+        TomlFile {
+            server: ServerSection {
+                port: file.server.port,
+                host: file.server.host,
+            },
+            app: AppSection {
+                timeout: file.app.timeout,
+                debug_level: file.app.debug_level,
+            },
+        }
+    }
+}
+
+impl TomlFile {
+    /// Comprueba lo que los tipos no garantizan y construye la configuración
+    fn into_config(self) -> Result<Config, ConfigError> {
+        // CORREGIDO: u16 descarta valores fuera de rango, pero el 0 tampoco es válido
+        if self.server.port == 0 {
+            return Err(ConfigError::InvalidPort("Puerto no puede ser 0".to_string()));
+        }
+        if self.server.host.trim().is_empty() {
+            return Err(ConfigError::InvalidHost("Host no puede estar vacío".to_string()));
+        }
+        
+        let mut config = Config::new(self.server.port, self.server.host, self.app.timeout);
+        config.set_debug_level(&self.app.debug_level).map_err(ConfigError::ParseError)?;
+        Ok(config)
+    }
+}
+
+impl Config {
+    /// Carga la configuración desde TOML, ignorando claves desconocidas
+    pub fn from_toml(toml_str: &str) -> Result<Config, ConfigError> {
+        let file: TomlFile = toml::from_str(toml_str).map_err(ConfigError::TomlParse)?;
+        file.into_config()
+    }
+    
+    /// Como `from_toml`, pero rechaza secciones o claves desconocidas
+    pub fn from_toml_strict(toml_str: &str) -> Result<Config, ConfigError> {
+        // CORREGIDO: Con deny_unknown_fields, toml señala la clave sobrante y su posición
+        let file: StrictTomlFile = toml::from_str(toml_str).map_err(ConfigError::TomlParse)?;
+        TomlFile::from(file).into_config()
+    }
+    
+    /// Serializa la configuración con el mismo esquema que lee `from_toml`
+    ///
+    /// Falla con un `timeout` mayor que `i64::MAX`: `from_env`, `parse` y
+    /// los flags lo aceptan como `u64`, pero un entero TOML no llega tan alto.
+    pub fn to_toml(&self) -> Result<String, ConfigError> {
+        let file = TomlFile {
+            server: ServerSection {
+                port: self.port,
+                host: self.host.clone(),
+            },
+            app: AppSection {
+                timeout: self.timeout,
+                debug_level: self.debug_level.clone(),
+            },
+        };
+        // CORREGIDO: Sin expect; un u64 fuera de rango es un error, no un panic
+        toml::to_string(&file).map_err(ConfigError::TomlSerialize)
+    }
+}
+
+/// Función que demuestra carga y guardado de configuración en TOML
+pub fn demonstrate_toml_config() {
+    println!("\n✅ Demostrando configuración TOML...");
+    
+    let toml_str = "[server]\nport = 9090\nhost = \"example.com\"\n\n[app]\ntimeout = 60\n";
+    match Config::from_toml(toml_str) {
+        Ok(config) => {
+            println!("Configuración desde TOML: {:?}", config);
+            match config.to_toml() {
+                Ok(toml_str) => println!("De vuelta a TOML:\n{}", toml_str),
+                Err(e) => println!("Error: {}", e),
+            }
+        }
+        Err(e) => println!("Error: {}", e),
+    }
+    
+    let huge_timeout = Config::new(8080, "localhost".to_string(), u64::MAX);
+    if let Err(e) = huge_timeout.to_toml() {
+        println!("Timeout de {}: {}", u64::MAX, e);
+    }
+    
+    // CORREGIDO: El error de serde/toml indica el campo y la línea
+    let bad = "[server]\nport = \"abc\"\nhost = \"localhost\"\n\n[app]\ntimeout = 30\n";
+    if let Err(e) = Config::from_toml(bad) {
+        println!("{}", e);
+    }
+    
+    let extra = "[server]\nport = 8080\nhost = \"localhost\"\nworkers = 4\n\n[app]\ntimeout = 30\n";
+    println!("Modo normal con clave extra: {}", Config::from_toml(extra).is_ok());
+    if let Err(e) = Config::from_toml_strict(extra) {
+        println!("Modo estricto: {}", e);
+    }
+}
@@ -0,0 +1,191 @@
+//! 🦀 FFI Callbacks - Pasar closures de Rust a C como callbacks
+//!
+//! Una API de C solo acepta un puntero a función `extern "C"`, que no
+//! puede capturar estado. Para usar un closure se registra una función
+//! fija (el trampolín) y el closure se guarda aparte, en un `Box`
+//! convertido a puntero crudo con `Box::into_raw`. El trampolín recupera
+//! ese puntero y llama al closure.
+//!
+//! `CallbackGuard` es dueña del closure: al soltarse (también durante un
+//! panic) vuelve a registrar el callback anterior y libera el `Box`.
+
+#![deny(unsafe_op_in_unsafe_fn, clippy::undocumented_unsafe_blocks)]
+
+use std::cell::{Cell, RefCell};
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+use std::rc::Rc;
+
+/// Callback con la firma que acepta la "biblioteca de C" simulada
+type Callback = extern "C" fn(i32) -> i32;
+
+/// Closure guardado detrás del trampolín
+///
+/// Se guarda como `*mut BoxedClosure`: un puntero delgado a un `Box<dyn ...>`,
+/// que cabría en el `void*` de una API de C real.
+type BoxedClosure = Box<dyn FnMut(i32) -> i32>;
+
+/// Callback por defecto: devuelve el argumento sin cambios
+extern "C" fn identity(arg: i32) -> i32 {
+    arg
+}
+
+thread_local! {
+    // Estado de la biblioteca de C simulada. Es por thread para que cada
+    // thread (y cada test) tenga su propio registro.
+    static REGISTERED: Cell<Callback> = const { Cell::new(identity) };
+    // Closure que ejecuta el trampolín; nulo si no hay ninguna guarda viva
+    static ACTIVE_CLOSURE: Cell<*mut BoxedClosure> = const { Cell::new(ptr::null_mut()) };
+}
+
+/// Registra `cb` y devuelve el callback que había antes, como `signal` en C
+fn register_callback(cb: Callback) -> Callback {
+    REGISTERED.with(|registered| registered.replace(cb))
+}
+
+/// Callback registrado en este momento
+fn current_callback() -> Callback {
+    REGISTERED.with(Cell::get)
+}
+
+/// Simula que el código de C llama al callback
+///
+/// # Safety
+///
+/// En C real, `cb` podría ser cualquier dirección: quien llama garantiza
+/// que es una función válida con esta firma y que no propaga un panic a
+/// través de la frontera.
+unsafe fn invoke_from_c_context(cb: Callback, arg: i32) -> i32 {
+    cb(arg)
+}
+
+/// Lo que haría la biblioteca de C al producirse un evento
+fn fire_event(arg: i32) -> i32 {
+    // SAFETY: solo se registran funciones de este archivo (`identity` y
+    // `trampoline`), que son válidas y atrapan cualquier panic.
+    unsafe { invoke_from_c_context(current_callback(), arg) }
+}
+
+/// Única función registrada para closures: busca el closure activo y lo llama
+extern "C" fn trampoline(arg: i32) -> i32 {
+    // Se saca del slot mientras se ejecuta: si el closure vuelve a disparar
+    // el evento, la llamada anidada no crea un segundo `&mut` al mismo closure
+    let raw = ACTIVE_CLOSURE.with(|active| active.replace(ptr::null_mut()));
+    if raw.is_null() {
+        return arg;
+    }
+    // SAFETY: el puntero salió de `Box::into_raw` en `CallbackGuard::new` y
+    // solo se libera en `Drop`, que antes lo quita de `ACTIVE_CLOSURE`. Es
+    // thread-local y acaba de sacarse del slot, así que esta es la única
+    // referencia mientras se ejecuta el closure.
+    let closure = unsafe { &mut *raw };
+    // CORREGIDO: Un panic no puede cruzar una función extern "C" (abortaría el proceso)
+    let result = panic::catch_unwind(AssertUnwindSafe(|| closure(arg))).unwrap_or_else(|_| {
+        eprintln!("El callback hizo panic; se devuelve el argumento sin cambios");
+        arg
+    });
+    ACTIVE_CLOSURE.with(|active| active.set(raw));
+    result
+}
+
+/// Dueña de un closure registrado como callback
+///
+/// Las guardas se anidan como una pila: cada una restaura lo que había al
+/// crearla, así que deben soltarse en orden inverso (lo normal con
+/// variables locales).
+struct CallbackGuard {
+    closure: *mut BoxedClosure,
+    previous_closure: *mut BoxedClosure,
+    previous_callback: Callback,
+}
+
+impl CallbackGuard {
+    fn new(closure: impl FnMut(i32) -> i32 + 'static) -> Self {
+        let boxed: BoxedClosure = Box::new(closure);
+        let closure = Box::into_raw(Box::new(boxed));
+        let previous_closure = ACTIVE_CLOSURE.with(|active| active.replace(closure));
+        let previous_callback = register_callback(trampoline);
+        Self {
+            closure,
+            previous_closure,
+            previous_callback,
+        }
+    }
+}
+
+impl Drop for CallbackGuard {
+    fn drop(&mut self) {
+        register_callback(self.previous_callback);
+        ACTIVE_CLOSURE.with(|active| active.set(self.previous_closure));
+        // SAFETY: `closure` salió de `Box::into_raw` en `new`, ya no está en
+        // `ACTIVE_CLOSURE` y `Drop` se ejecuta una sola vez.
+        drop(unsafe { Box::from_raw(self.closure) });
+    }
+}
+
+/// Función que demuestra registrar funciones `extern "C"` sin estado
+fn demonstrate_plain_callbacks() {
+    println!("\n✅ Demostrando register_callback con funciones extern \"C\"...");
+    
+    extern "C" fn double(arg: i32) -> i32 {
+        arg.saturating_mul(2)
+    }
+    
+    println!("Sin registrar nada: fire_event(21) = {}", fire_event(21));
+    let previous = register_callback(double);
+    println!("Con double: fire_event(21) = {}", fire_event(21));
+    register_callback(previous);
+    println!("Restaurado el anterior: fire_event(21) = {}", fire_event(21));
+}
+
+/// Función que demuestra closures con estado a través del trampolín
+fn demonstrate_closure_callbacks() {
+    println!("\n✅ Demostrando closures como callbacks con CallbackGuard...");
+    
+    let total = Rc::new(Cell::new(0));
+    let history = Rc::new(RefCell::new(Vec::new()));
+    {
+        let total = Rc::clone(&total);
+        let history = Rc::clone(&history);
+        let _guard = CallbackGuard::new(move |arg| {
+            total.set(total.get() + arg);
+            history.borrow_mut().push(arg);
+            total.get()
+        });
+        
+        for arg in [5, 10, 20] {
+            println!("fire_event({}) = {}", arg, fire_event(arg));
+        }
+    }
+    println!("Total acumulado: {}, eventos: {:?}", total.get(), history.borrow());
+    
+    // La guarda ya se soltó: el closure está liberado y vuelve el callback anterior
+    println!("Tras soltar la guarda: fire_event(5) = {}", fire_event(5));
+    println!("Referencias al total: {} (el closure ya no tiene la suya)", Rc::strong_count(&total));
+    
+    let result = panic::catch_unwind(|| {
+        let _guard = CallbackGuard::new(|arg| arg + 1000);
+        panic!("fallo mientras el callback está registrado");
+    });
+    println!(
+        "Panic con la guarda viva: {}, fire_event(5) = {}",
+        result.is_err(),
+        fire_event(5)
+    );
+}
+
+fn main() {
+    println!("🦀 Rust Lab - Callbacks entre C y Rust");
+    println!("{}", "=".repeat(60));
+    
+    demonstrate_plain_callbacks();
+    demonstrate_closure_callbacks();
+    
+    println!("\n✅ Todas las demostraciones completadas sin errores!");
+    println!("🎯 Conceptos clave demostrados:");
+    println!("   - extern \"C\" fn: Punteros a función sin estado para C");
+    println!("   - Trampolines: Una función fija que llama al closure guardado");
+    println!("   - Box::into_raw / from_raw: Ceder y recuperar la propiedad del closure");
+    println!("   - RAII: CallbackGuard desregistra y libera incluso durante un panic");
+    println!("   - catch_unwind: Un panic nunca cruza la frontera con C");
+}
@@ -0,0 +1,139 @@
+//! 🦀 Sort FFI - Llamar a `qsort` de la biblioteca de C desde Rust
+//!
+//! `qsort` no sabe nada de tipos: recibe un puntero `void*`, el número de
+//! elementos, el tamaño de cada uno y un comparador que recibe dos
+//! `void*`. Todo lo que Rust garantiza normalmente (tipos, tamaños,
+//! límites del slice) hay que comprobarlo a mano en la frontera.
+//!
+//! Cada bloque `unsafe` lleva un comentario `SAFETY:`; clippy lo exige
+//! con `undocumented_unsafe_blocks`.
+
+#![deny(unsafe_op_in_unsafe_fn, clippy::undocumented_unsafe_blocks)]
+
+use std::ffi::{c_int, c_void};
+use std::mem::size_of;
+
+/// `size_t` de C
+///
+/// En todas las plataformas que soporta Rust, `size_t` tiene el mismo
+/// ancho que `usize` (32 bits en targets de 32 bits, 64 en los de 64).
+/// `std` todavía no expone un `c_size_t` estable, así que se usa `usize`.
+#[allow(non_camel_case_types)]
+type size_t = usize;
+
+/// Comparador con la firma que espera `qsort`
+type Comparator = extern "C" fn(*const c_void, *const c_void) -> c_int;
+
+// SAFETY: la firma coincide con la de `qsort` en <stdlib.h>, que la libc
+// enlazada por `std` siempre exporta.
+unsafe extern "C" {
+    fn qsort(base: *mut c_void, nmemb: size_t, size: size_t, compar: Option<Comparator>);
+}
+
+/// Usuario mínimo para ordenar por `id`
+#[derive(Debug, Clone, PartialEq)]
+struct User {
+    id: u32,
+    name: String,
+}
+
+impl User {
+    fn new(id: u32, name: &str) -> Self {
+        Self {
+            id,
+            name: name.to_string(),
+        }
+    }
+}
+
+/// Ordena `data` con `qsort` usando `compar`
+///
+/// # Safety
+///
+/// `compar` debe interpretar sus argumentos como `*const T` y no hacer
+/// panic (un panic no puede cruzar una función `extern "C"`: aborta).
+unsafe fn qsort_slice<T>(data: &mut [T], compar: Comparator) {
+    // Con 0 o 1 elementos no hay nada que ordenar y el puntero de un
+    // slice vacío no apunta a memoria válida
+    if data.len() < 2 {
+        return;
+    }
+    
+    // SAFETY: `data` es un slice válido y exclusivo durante la llamada, así
+    // que `as_mut_ptr` apunta a `len` elementos contiguos de `size_of::<T>()`
+    // bytes. `qsort` solo los permuta con copias de bytes, que es como Rust
+    // mueve cualquier valor, y quien llama garantiza que `compar` es válido
+    // para `T`.
+    unsafe {
+        qsort(data.as_mut_ptr().cast(), data.len(), size_of::<T>(), Some(compar));
+    }
+}
+
+extern "C" fn compare_i32(a: *const c_void, b: *const c_void) -> c_int {
+    // SAFETY: `qsort_slice` solo usa este comparador con slices de `i32`,
+    // y `qsort` siempre pasa punteros a elementos de ese slice.
+    let (a, b) = unsafe { (*a.cast::<i32>(), *b.cast::<i32>()) };
+    // CORREGIDO: `a - b` puede desbordar (i32::MIN - 1); `cmp` no
+    a.cmp(&b) as c_int
+}
+
+extern "C" fn compare_users_by_id(a: *const c_void, b: *const c_void) -> c_int {
+    // SAFETY: solo se usa con slices de `User`; las referencias viven lo que
+    // dura la comparación y `qsort` no modifica los elementos mientras tanto.
+    let (a, b) = unsafe { (&*a.cast::<User>(), &*b.cast::<User>()) };
+    a.id.cmp(&b.id) as c_int
+}
+
+/// Ordena enteros con `qsort` de C
+fn sort_ints_via_ffi(data: &mut [i32]) {
+    // SAFETY: `compare_i32` trata los elementos como `i32` y no hace panic.
+    unsafe { qsort_slice(data, compare_i32) }
+}
+
+/// Ordena usuarios por `id`; `qsort` no es estable, así que los `id`
+/// repetidos pueden quedar en cualquier orden
+fn sort_users_via_ffi(users: &mut [User]) {
+    // SAFETY: `compare_users_by_id` trata los elementos como `User` y no hace panic.
+    unsafe { qsort_slice(users, compare_users_by_id) }
+}
+
+/// Función que demuestra ordenar enteros con `qsort`
+fn demonstrate_sort_ints() {
+    println!("\n✅ Demostrando qsort sobre un slice de i32...");
+    
+    let mut data = vec![42, -7, 0, i32::MAX, 13, i32::MIN, 5];
+    sort_ints_via_ffi(&mut data);
+    println!("Ordenado: {:?}", data);
+    
+    let mut empty: Vec<i32> = Vec::new();
+    sort_ints_via_ffi(&mut empty);
+    let mut single = vec![1];
+    sort_ints_via_ffi(&mut single);
+    println!("Vacío: {:?}, un elemento: {:?}", empty, single);
+}
+
+/// Función que demuestra ordenar structs con `String` a través de FFI
+fn demonstrate_sort_users() {
+    println!("\n✅ Demostrando qsort sobre un slice de User...");
+    
+    let mut users = vec![User::new(3, "Eva"), User::new(1, "Ana"), User::new(2, "Luis")];
+    sort_users_via_ffi(&mut users);
+    for user in &users {
+        println!("   {} - {}", user.id, user.name);
+    }
+}
+
+fn main() {
+    println!("🦀 Rust Lab - FFI con qsort");
+    println!("{}", "=".repeat(60));
+    
+    demonstrate_sort_ints();
+    demonstrate_sort_users();
+    
+    println!("\n✅ Todas las demostraciones completadas sin errores!");
+    println!("🎯 Conceptos clave demostrados:");
+    println!("   - extern \"C\": Declarar funciones de la biblioteca de C");
+    println!("   - c_void / c_int / size_t: Tipos de la frontera con C");
+    println!("   - Callbacks: Funciones extern \"C\" de Rust como comparador");
+    println!("   - SAFETY: Cada bloque unsafe documenta sus invariantes");
+}
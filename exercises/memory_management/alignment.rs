@@ -0,0 +1,105 @@
+//! 🦀 Alignment - Relleno, orden de campos y `#[repr(packed)]`
+//!
+//! Cada campo tiene que empezar en una dirección múltiplo de su
+//! alineación, así que entre campos puede quedar relleno. Con
+//! `#[repr(C)]` los campos se colocan en el orden declarado y el orden
+//! importa; con el `repr` por defecto, Rust los reordena él mismo para
+//! minimizar el relleno. `#[repr(packed)]` elimina el relleno a costa de
+//! campos desalineados que no se pueden referenciar.
+
+use std::hint::black_box;
+use std::mem::{align_of, offset_of, size_of};
+use std::time::Instant;
+
+/// Orden declarado con el campo grande en medio: 1 + 3 de relleno + 4 + 1 + 3 de relleno
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct Padded {
+    pub a: u8,
+    pub b: u32,
+    pub c: u8,
+}
+
+/// Los mismos campos con los pequeños juntos: 1 + 1 + 2 de relleno + 4
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct Packed {
+    pub a: u8,
+    pub c: u8,
+    pub b: u32,
+}
+
+/// Sin relleno: `b` queda en el offset 1, desalineado
+///
+/// Crear `&self.b` no compila (sería una referencia desalineada); hay que
+/// copiar el campo con `{ x.b }` o leerlo con `ptr::read_unaligned`.
+#[repr(C, packed)]
+#[derive(Debug, Clone, Copy)]
+pub struct Unaligned {
+    pub a: u8,
+    pub b: u32,
+    pub c: u8,
+}
+
+/// Orden declarado como `Padded`, pero con el `repr` por defecto
+#[derive(Debug, Clone, Copy)]
+pub struct DefaultLayout {
+    pub a: u8,
+    pub b: u32,
+    pub c: u8,
+}
+
+/// `offsets` son los de `a`, `b` y `c`, en ese orden
+fn print_layout<T>(name: &str, offsets: [usize; 3]) {
+    println!(
+        "   {:<14} size_of = {:>2}, align_of = {}, offsets a/b/c = {:?}",
+        name,
+        size_of::<T>(),
+        align_of::<T>(),
+        offsets
+    );
+}
+
+/// Función que demuestra el coste en memoria del relleno y el de leer campos desalineados
+pub fn demonstrate_packed_struct_alignment() {
+    println!("\n✅ Demostrando alineación y relleno de structs...");
+    
+    print_layout::<Padded>("Padded", [offset_of!(Padded, a), offset_of!(Padded, b), offset_of!(Padded, c)]);
+    print_layout::<Packed>("Packed", [offset_of!(Packed, a), offset_of!(Packed, b), offset_of!(Packed, c)]);
+    print_layout::<Unaligned>(
+        "Unaligned",
+        [offset_of!(Unaligned, a), offset_of!(Unaligned, b), offset_of!(Unaligned, c)],
+    );
+    // Rust reordena los campos: el resultado no depende del orden declarado
+    print_layout::<DefaultLayout>(
+        "DefaultLayout",
+        [offset_of!(DefaultLayout, a), offset_of!(DefaultLayout, b), offset_of!(DefaultLayout, c)],
+    );
+    
+    const COUNT: usize = 1_000_000;
+    let packed: Vec<Packed> = (0..COUNT as u32).map(|i| Packed { a: 1, c: 2, b: i }).collect();
+    let unaligned: Vec<Unaligned> = (0..COUNT as u32).map(|i| Unaligned { a: 1, b: i, c: 2 }).collect();
+    println!(
+        "{} elementos: {} KB con Padded, {} KB con Packed, {} KB con Unaligned",
+        COUNT,
+        COUNT * size_of::<Padded>() / 1024,
+        COUNT * size_of::<Packed>() / 1024,
+        COUNT * size_of::<Unaligned>() / 1024
+    );
+    
+    let start = Instant::now();
+    let aligned_sum: u64 = packed.iter().map(|x| black_box(x.b) as u64).sum();
+    let aligned_time = start.elapsed();
+    
+    let start = Instant::now();
+    // CORREGIDO: Pasar `x.b` por valor lo copia; `&x.b` sería una referencia desalineada
+    let unaligned_sum: u64 = unaligned.iter().map(|x| black_box(x.b) as u64).sum();
+    let unaligned_time = start.elapsed();
+    
+    println!("Leer b alineado (Packed): {:?}", aligned_time);
+    println!("Leer b desalineado (Unaligned): {:?}", unaligned_time);
+    println!("Mismas sumas: {}", aligned_sum == unaligned_sum);
+    // En x86_64 la penalización es pequeña; en otras arquitecturas el compilador
+    // tiene que leer byte a byte y la diferencia crece
+    println!("Arquitectura: {}", std::env::consts::ARCH);
+}
@@ -0,0 +1,99 @@
+//! 🦀 CowVec - Vec con copy-on-write
+//!
+//! `Vec::clone()` siempre copia todos los elementos. `CowVec` comparte
+//! el buffer mediante `Rc` y solo lo copia cuando alguien escribe
+//! mientras otro clon todavía lo está usando.
+
+use std::rc::Rc;
+
+/// Vec compartido que se copia de forma perezosa al escribir
+#[derive(Debug)]
+pub struct CowVec<T: Clone> {
+    data: Rc<Vec<T>>,
+}
+
+impl<T: Clone> CowVec<T> {
+    pub fn new() -> Self {
+        Self {
+            data: Rc::new(Vec::new()),
+        }
+    }
+    
+    /// Añade un elemento (copia el buffer si está compartido)
+    pub fn push(&mut self, item: T) {
+        self.make_unique().push(item);
+    }
+    
+    /// Lectura: nunca copia
+    pub fn get(&self, i: usize) -> Option<&T> {
+        self.data.get(i)
+    }
+    
+    /// Escritura: copia el buffer si está compartido
+    pub fn get_mut(&mut self, i: usize) -> Option<&mut T> {
+        self.make_unique().get_mut(i)
+    }
+    
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+    
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+    
+    /// Número de `CowVec` que comparten el mismo buffer
+    pub fn share_count(&self) -> usize {
+        Rc::strong_count(&self.data)
+    }
+    
+    /// Disparador del copy-on-write: si hay otros dueños, clonamos el Vec
+    fn make_unique(&mut self) -> &mut Vec<T> {
+        if Rc::strong_count(&self.data) > 1 {
+            self.data = Rc::new(self.data.as_ref().clone());
+        }
+        // Ahora somos el único dueño, get_mut no puede fallar
+        Rc::get_mut(&mut self.data).expect("CowVec debería ser único tras copiar")
+    }
+}
+
+impl<T: Clone> Clone for CowVec<T> {
+    /// Clonar solo incrementa el contador del Rc, no copia los datos
+    fn clone(&self) -> Self {
+        Self {
+            data: Rc::clone(&self.data),
+        }
+    }
+}
+
+impl<T: Clone> Default for CowVec<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Función que demuestra copy-on-write con CowVec
+pub fn demonstrate_cow_vec() {
+    println!("\n✅ Demostrando CowVec (copy-on-write)...");
+    
+    let mut original = CowVec::new();
+    for i in 0..5 {
+        original.push(i * 10);
+    }
+    
+    // CORREGIDO: 5 clones baratos que comparten el mismo buffer
+    let mut clones: Vec<CowVec<i32>> = (0..5).map(|_| original.clone()).collect();
+    println!("Buffer compartido por {} CowVec", original.share_count());
+    
+    // Escribir en un clon dispara la copia solo para ese clon
+    if let Some(value) = clones[0].get_mut(0) {
+        *value = 999;
+    }
+    
+    println!("Clon modificado: {:?}, compartido por {}", clones[0].get(0), clones[0].share_count());
+    println!("Original intacto: {:?}, compartido por {}", original.get(0), original.share_count());
+    
+    let untouched = clones.iter().skip(1).all(|c| c.get(0) == Some(&0));
+    println!("Resto de clones sin cambios: {}", untouched);
+    println!("Longitud: {}, vacío: {}", original.len(), original.is_empty());
+}
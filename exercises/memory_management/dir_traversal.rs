@@ -0,0 +1,254 @@
+//! 🦀 Dir Traversal - Estadísticas de usuarios repartidos en un árbol de directorios
+//!
+//! Cada subdirectorio puede tener un archivo `.db` con registros de
+//! tamaño fijo (el formato de `mmap`). Recorrer el árbol con recursión
+//! usa un frame de pila por nivel; con una cola de directorios
+//! pendientes (`VecDeque<PathBuf>`) la profundidad solo gasta heap.
+//!
+//! El recorrido es un `Iterator` de archivos, así que la versión
+//! paralela lo pasa a rayon con `par_bridge` sin listar antes el árbol.
+
+use std::collections::VecDeque;
+use std::fs::{self, File, ReadDir};
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use rayon::iter::{ParallelBridge, ParallelIterator};
+
+use super::mmap::{parse_record, write_record, UserRef, RECORD_SIZE};
+
+/// Resumen de todos los usuarios encontrados
+#[derive(Debug, Clone, PartialEq)]
+pub struct UserStats {
+    pub total_users: usize,
+    pub total_posts: u64,
+    /// 0.0 si no hay usuarios
+    pub avg_posts_per_user: f64,
+    /// Con empate, el id menor; `None` si no hay usuarios
+    pub max_posts_user_id: Option<u32>,
+}
+
+/// Acumulador parcial; dos se combinan con `merge` en cualquier orden
+#[derive(Debug, Clone, Copy, Default)]
+struct Totals {
+    users: usize,
+    posts: u64,
+    /// `(posts, id)` del usuario con más posts
+    max: Option<(u32, u32)>,
+}
+
+impl Totals {
+    fn add(&mut self, user: &UserRef) {
+        self.users += 1;
+        self.posts += user.posts as u64;
+        self.max = Self::better(self.max, Some((user.posts, user.id)));
+    }
+    
+    fn merge(mut self, other: Totals) -> Totals {
+        self.users += other.users;
+        self.posts += other.posts;
+        self.max = Self::better(self.max, other.max);
+        self
+    }
+    
+    // CORREGIDO: El empate se decide por id, no por el orden de llegada, para que
+    // el recorrido paralelo dé el mismo resultado que el secuencial
+    fn better(a: Option<(u32, u32)>, b: Option<(u32, u32)>) -> Option<(u32, u32)> {
+        match (a, b) {
+            (Some((posts_a, id_a)), Some((posts_b, id_b))) => {
+                if posts_b > posts_a || (posts_b == posts_a && id_b < id_a) {
+                    b
+                } else {
+                    a
+                }
+            }
+            (a, None) => a,
+            (None, b) => b,
+        }
+    }
+    
+    fn into_stats(self) -> UserStats {
+        UserStats {
+            total_users: self.users,
+            total_posts: self.posts,
+            avg_posts_per_user: if self.users == 0 { 0.0 } else { self.posts as f64 / self.users as f64 },
+            max_posts_user_id: self.max.map(|(_, id)| id),
+        }
+    }
+}
+
+/// Archivos `.db` bajo un directorio, en anchura y sin recursión
+struct DbFiles {
+    pending: VecDeque<PathBuf>,
+    current: Option<ReadDir>,
+}
+
+impl DbFiles {
+    fn new(root: &Path) -> Self {
+        Self {
+            pending: VecDeque::from([root.to_path_buf()]),
+            current: None,
+        }
+    }
+}
+
+impl Iterator for DbFiles {
+    type Item = io::Result<PathBuf>;
+    
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let Some(entries) = self.current.as_mut() else {
+                let dir = self.pending.pop_front()?;
+                match fs::read_dir(&dir) {
+                    Ok(entries) => self.current = Some(entries),
+                    Err(e) => return Some(Err(e)),
+                }
+                continue;
+            };
+            
+            let entry = match entries.next() {
+                Some(Ok(entry)) => entry,
+                Some(Err(e)) => return Some(Err(e)),
+                None => {
+                    self.current = None;
+                    continue;
+                }
+            };
+            // file_type no sigue enlaces simbólicos, así que un enlace a un padre no crea un ciclo
+            let file_type = match entry.file_type() {
+                Ok(file_type) => file_type,
+                Err(e) => return Some(Err(e)),
+            };
+            let path = entry.path();
+            if file_type.is_dir() {
+                self.pending.push_back(path);
+            } else if file_type.is_file() && path.extension().is_some_and(|ext| ext == "db") {
+                return Some(Ok(path));
+            }
+        }
+    }
+}
+
+/// Totales de un archivo de registros
+fn file_totals(path: &Path) -> io::Result<Totals> {
+    let bytes = fs::read(path)?;
+    if bytes.len() % RECORD_SIZE != 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{}: {} bytes no es múltiplo de {}", path.display(), bytes.len(), RECORD_SIZE),
+        ));
+    }
+    
+    let mut totals = Totals::default();
+    for (index, record) in bytes.chunks_exact(RECORD_SIZE).enumerate() {
+        let record = record.try_into().expect("chunks_exact da trozos de RECORD_SIZE");
+        let user = parse_record(record).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}: el registro {} no es UTF-8", path.display(), index),
+            )
+        })?;
+        totals.add(&user);
+    }
+    Ok(totals)
+}
+
+/// Recorre `root` y agrega todos los usuarios de sus archivos `.db`
+pub fn walk_user_db_dir(root: &Path) -> Result<UserStats, io::Error> {
+    let mut totals = Totals::default();
+    for path in DbFiles::new(root) {
+        totals = totals.merge(file_totals(&path?)?);
+    }
+    Ok(totals.into_stats())
+}
+
+/// Como `walk_user_db_dir`, pero lee y agrega los archivos en paralelo
+///
+/// El recorrido de directorios sigue siendo de un solo thread; `par_bridge`
+/// reparte los archivos a medida que aparecen.
+pub fn parallel_walk(root: &Path) -> Result<UserStats, io::Error> {
+    DbFiles::new(root)
+        .par_bridge()
+        .map(|path| file_totals(&path?))
+        .try_reduce(Totals::default, |a, b| Ok(a.merge(b)))
+        .map(Totals::into_stats)
+}
+
+/// Crea `root/d0/d1/...` con `depth` niveles y `fanout` hijos por directorio, un `users.db` en cada uno
+fn build_user_tree(root: &Path, depth: usize, fanout: usize, users_per_file: u32) -> io::Result<usize> {
+    let mut pending = VecDeque::from([(root.to_path_buf(), 0)]);
+    let mut next_id = 0u32;
+    let mut files = 0;
+    
+    while let Some((dir, level)) = pending.pop_front() {
+        fs::create_dir_all(&dir)?;
+        let mut writer = BufWriter::new(File::create(dir.join("users.db"))?);
+        for _ in 0..users_per_file {
+            let name = format!("User{}", next_id);
+            let user = UserRef {
+                id: next_id,
+                posts: next_id.wrapping_mul(2_654_435_761) % 100,
+                name: &name,
+                email: "user@example.com",
+            };
+            write_record(&mut writer, &user)?;
+            next_id += 1;
+        }
+        writer.flush()?;
+        files += 1;
+        
+        if level < depth {
+            for child in 0..fanout {
+                pending.push_back((dir.join(format!("d{}", child)), level + 1));
+            }
+        }
+    }
+    Ok(files)
+}
+
+/// Función que demuestra agregar estadísticas de un árbol de directorios en serie y en paralelo
+pub fn demonstrate_dir_traversal() {
+    println!("\n✅ Demostrando recorrido iterativo de directorios...");
+    
+    let root = std::env::temp_dir().join(format!("rust_lab_user_tree_{}", std::process::id()));
+    match build_user_tree(&root, 3, 4, 2_000) {
+        Ok(files) => println!("Árbol de 4 niveles con {} archivos en {}", files, root.display()),
+        Err(e) => {
+            println!("No se pudo crear el árbol: {}", e);
+            let _ = fs::remove_dir_all(&root);
+            return;
+        }
+    }
+    
+    let start = Instant::now();
+    let sequential = walk_user_db_dir(&root);
+    let sequential_time = start.elapsed();
+    let start = Instant::now();
+    let parallel = parallel_walk(&root);
+    let parallel_time = start.elapsed();
+    
+    match (&sequential, &parallel) {
+        (Ok(stats), Ok(parallel_stats)) => {
+            println!("Secuencial en {:?}: {:?}", sequential_time, stats);
+            println!("Paralelo en {:?}, mismo resultado: {}", parallel_time, stats == parallel_stats);
+        }
+        (Err(e), _) | (_, Err(e)) => println!("Error al recorrer: {}", e),
+    }
+    
+    // Una cadena de 300 directorios: la cola crece en el heap, no en la pila
+    let deep: PathBuf = (0..300).fold(root.join("deep"), |path, _| path.join("d"));
+    let deep_result = fs::create_dir_all(&deep).and_then(|()| {
+        let mut file = File::create(deep.join("users.db"))?;
+        write_record(&mut file, &UserRef { id: 999_999, posts: 1_000, name: "Deep", email: "" })?;
+        walk_user_db_dir(&root)
+    });
+    match deep_result {
+        Ok(stats) => println!("Con una rama de 300 niveles, más posts: {:?}", stats.max_posts_user_id),
+        Err(e) => println!("Error con la rama profunda: {}", e),
+    }
+    
+    if let Err(e) = fs::remove_dir_all(&root) {
+        println!("No se pudo borrar {}: {}", root.display(), e);
+    }
+}
@@ -0,0 +1,242 @@
+//! 🦀 Linked List - Lista doblemente enlazada con punteros crudos
+//!
+//! `TreeNode` usa `Rc<RefCell<T>>` y `Weak` para el padre. En una lista
+//! doble cada nodo tiene dos dueños (el anterior y el siguiente), y con
+//! `Rc` eso significa contadores, `borrow_mut` y `Weak` en cada enlace.
+//! Con `*mut Node<T>` la lista es la única dueña de todos los nodos y
+//! los `unsafe` quedan encerrados tras una API segura.
+//!
+//! Invariantes que mantiene `DoublyLinkedList` (y de los que dependen
+//! todos los bloques `unsafe`):
+//!
+//! 1. `head` y `tail` son nodos centinela sin valor, reservados con
+//!    `Box::into_raw` en `new` y liberados solo en `Drop`.
+//! 2. Siguiendo `next` desde `head` se llega a `tail` pasando por
+//!    exactamente `len` nodos con valor, y `prev` recorre el mismo
+//!    camino al revés.
+//! 3. Cada nodo con valor se reservó con `Box::into_raw` y solo la lista
+//!    tiene punteros a él.
+//!
+//! Los tests se pueden ejecutar con Miri para detectar comportamiento
+//! indefinido: `cargo +nightly miri test --test memory_management_tests linked_list`.
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::ptr;
+
+struct Node<T> {
+    /// `None` solo en los centinelas
+    value: Option<T>,
+    prev: *mut Node<T>,
+    next: *mut Node<T>,
+}
+
+impl<T> Node<T> {
+    fn alloc(value: Option<T>) -> *mut Node<T> {
+        Box::into_raw(Box::new(Node {
+            value,
+            prev: ptr::null_mut(),
+            next: ptr::null_mut(),
+        }))
+    }
+}
+
+/// Lista doblemente enlazada con centinelas en ambos extremos
+pub struct DoublyLinkedList<T> {
+    head: *mut Node<T>,
+    tail: *mut Node<T>,
+    len: usize,
+    /// Indica al compilador que la lista es dueña de valores `T` (drop check)
+    _owns: PhantomData<T>,
+}
+
+// SAFETY: la lista es dueña exclusiva de sus nodos; moverla a otro thread
+// equivale a mover los `T`, así que basta con `T: Send`.
+unsafe impl<T: Send> Send for DoublyLinkedList<T> {}
+
+// SAFETY: con `&DoublyLinkedList` solo se obtienen `&T` (vía `iter`), que
+// pueden compartirse entre threads únicamente si `T: Sync`.
+unsafe impl<T: Sync> Sync for DoublyLinkedList<T> {}
+
+impl<T> DoublyLinkedList<T> {
+    pub fn new() -> Self {
+        let head = Node::alloc(None);
+        let tail = Node::alloc(None);
+        // SAFETY: `head` y `tail` acaban de salir de `Box::into_raw`, así que
+        // no son nulos, están alineados y nadie más los referencia.
+        unsafe {
+            (*head).next = tail;
+            (*tail).prev = head;
+        }
+        Self {
+            head,
+            tail,
+            len: 0,
+            _owns: PhantomData,
+        }
+    }
+    
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    
+    /// Enlaza un nodo nuevo entre `prev` y `next`, que deben ser adyacentes
+    fn insert_between(&mut self, value: T, prev: *mut Node<T>, next: *mut Node<T>) {
+        let node = Node::alloc(Some(value));
+        // SAFETY: `node` es una reserva nueva. `prev` y `next` son nodos vivos
+        // y consecutivos de esta lista (invariante 2), y `&mut self` garantiza
+        // que nadie más los está leyendo mientras se re-enlazan.
+        unsafe {
+            (*node).prev = prev;
+            (*node).next = next;
+            (*prev).next = node;
+            (*next).prev = node;
+        }
+        self.len += 1;
+    }
+    
+    /// Desenlaza `node` y devuelve su valor, liberando el nodo
+    fn unlink(&mut self, node: *mut Node<T>) -> T {
+        // SAFETY: quien llama garantiza que `node` es un nodo con valor de esta
+        // lista (nunca un centinela), así que `prev` y `next` no son nulos.
+        // Tras re-enlazar a sus vecinos nadie apunta a `node`, y como salió de
+        // `Box::into_raw` se puede recuperar con `Box::from_raw` una sola vez.
+        let boxed = unsafe {
+            (*(*node).prev).next = (*node).next;
+            (*(*node).next).prev = (*node).prev;
+            Box::from_raw(node)
+        };
+        self.len -= 1;
+        boxed.value.expect("un nodo con valor nunca es un centinela")
+    }
+    
+    pub fn push_front(&mut self, value: T) {
+        // SAFETY: `head` es válido durante toda la vida de la lista (invariante 1).
+        let first = unsafe { (*self.head).next };
+        self.insert_between(value, self.head, first);
+    }
+    
+    pub fn push_back(&mut self, value: T) {
+        // SAFETY: `tail` es válido durante toda la vida de la lista (invariante 1).
+        let last = unsafe { (*self.tail).prev };
+        self.insert_between(value, last, self.tail);
+    }
+    
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        // SAFETY: `head` es válido y, con `len > 0`, su `next` es un nodo con valor.
+        let first = unsafe { (*self.head).next };
+        Some(self.unlink(first))
+    }
+    
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        // SAFETY: `tail` es válido y, con `len > 0`, su `prev` es un nodo con valor.
+        let last = unsafe { (*self.tail).prev };
+        Some(self.unlink(last))
+    }
+    
+    /// Recorre los valores de delante hacia atrás
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            // SAFETY: `head` es válido durante toda la vida de la lista (invariante 1).
+            current: unsafe { (*self.head).next },
+            end: self.tail,
+            remaining: self.len,
+            _list: PhantomData,
+        }
+    }
+}
+
+impl<T> Default for DoublyLinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for DoublyLinkedList<T> {
+    fn drop(&mut self) {
+        while self.pop_front().is_some() {}
+        // SAFETY: la lista ya está vacía, así que solo quedan los centinelas,
+        // reservados con `Box::into_raw` en `new` y sin ningún otro puntero vivo.
+        unsafe {
+            drop(Box::from_raw(self.head));
+            drop(Box::from_raw(self.tail));
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for DoublyLinkedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// Iterador de referencias; el lifetime lo ata al préstamo de la lista
+pub struct Iter<'a, T> {
+    current: *mut Node<T>,
+    end: *mut Node<T>,
+    remaining: usize,
+    _list: PhantomData<&'a DoublyLinkedList<T>>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+    
+    fn next(&mut self) -> Option<&'a T> {
+        if self.current == self.end {
+            return None;
+        }
+        // SAFETY: `current` no es el centinela final, así que es un nodo con
+        // valor. La lista está prestada de forma compartida durante `'a`, de
+        // modo que ningún `push`/`pop` puede liberarlo ni modificarlo mientras
+        // exista la referencia devuelta.
+        let node = unsafe { &*self.current };
+        self.current = node.next;
+        self.remaining -= 1;
+        node.value.as_ref()
+    }
+    
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+/// Función que demuestra una lista doblemente enlazada segura por fuera y `unsafe` por dentro
+pub fn demonstrate_doubly_linked_list() {
+    println!("\n✅ Demostrando lista doblemente enlazada con punteros crudos...");
+    
+    let mut list = DoublyLinkedList::new();
+    list.push_back(2);
+    list.push_back(3);
+    list.push_front(1);
+    println!("Lista: {:?} (len {})", list, list.len());
+    
+    println!("pop_front: {:?}, pop_back: {:?}", list.pop_front(), list.pop_back());
+    println!("Lista tras los pop: {:?}", list);
+    
+    // CORREGIDO: Drop libera cada nodo y su String, sin fugas ni doble free
+    let mut names = DoublyLinkedList::new();
+    for name in ["Ana", "Luis", "Eva"] {
+        names.push_back(name.to_string());
+    }
+    let total: usize = names.iter().map(String::len).sum();
+    println!("Nombres: {:?}, {} caracteres en total", names, total);
+    
+    // Send: la lista se mueve entera a otro thread
+    let handle = std::thread::spawn(move || {
+        names.push_front("Sara".to_string());
+        names.len()
+    });
+    println!("Elementos tras moverla a otro thread: {}", handle.join().unwrap());
+}
@@ -3,12 +3,35 @@
 //! Esta es la versión corregida del ejercicio anterior,
 //! mostrando las mejores prácticas de gestión de memoria en Rust.
 
+#[path = "../performance/allocator.rs"]
+mod allocator;
+#[path = "../data_structures/graph.rs"]
+mod graph;
+mod alignment;
+mod cow_vec;
+mod dir_traversal;
+mod linked_list;
+mod memory_stats;
+mod mmap;
+mod pinned;
+mod raw_pointers;
+mod resource;
+mod tracked;
+mod transmute_safety;
+mod try_cell;
+mod union_type;
+
 use std::rc::{Rc, Weak};
 use std::sync::Arc;
 use std::cell::RefCell;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::sync::Mutex;
 
+use allocator::{allocation_count, current_heap_bytes};
+use graph::{Graph, NodeId};
+use memory_stats::{map_stats, vec_stats};
+use tracked::{DropLog, Tracked};
+
 /// Estructura que representa un nodo en un árbol con referencias seguras
 #[derive(Debug)]
 struct TreeNode {
@@ -49,6 +72,111 @@ impl TreeNode {
         }
         None
     }
+    
+    /// Enlaza `child` bajo `parent` con una Weak reference real hacia el padre
+    fn link_child(parent: &Rc<RefCell<TreeNode>>, child: Rc<RefCell<TreeNode>>) {
+        child.borrow_mut().parent = Some(Rc::downgrade(parent));
+        parent.borrow_mut().children.push(child);
+    }
+    
+    /// Construye un árbol nuevo con la misma forma y cada valor transformado
+    ///
+    /// Los `parent` del resultado apuntan a los nodos nuevos, no a los
+    /// originales, y el árbol de entrada no se modifica.
+    fn map(root: &Rc<RefCell<TreeNode>>, f: impl Fn(i32) -> i32 + Copy) -> Rc<RefCell<TreeNode>> {
+        let node = root.borrow();
+        let mapped = Rc::new(RefCell::new(TreeNode::new(f(node.value))));
+        for child in &node.children {
+            // CORREGIDO: link_child enlaza con Weak al padre recién creado
+            TreeNode::link_child(&mapped, TreeNode::map(child, f));
+        }
+        mapped
+    }
+    
+    /// Valores desde la raíz hasta el primer nodo (en preorden) con `value`
+    ///
+    /// Usa una pila explícita con el camino parcial de cada nodo en lugar
+    /// de recursión, así la profundidad del árbol no está limitada por el
+    /// tamaño del stack.
+    fn path_to(root: &Rc<RefCell<TreeNode>>, value: i32) -> Option<Vec<i32>> {
+        let mut stack = vec![(Rc::clone(root), Vec::new())];
+        
+        while let Some((node, mut path)) = stack.pop() {
+            let node = node.borrow();
+            path.push(node.value);
+            if node.value == value {
+                return Some(path);
+            }
+            // En orden inverso para visitar primero el hijo de la izquierda,
+            // que se lleva el camino sin copiarlo
+            if let Some((first, rest)) = node.children.split_first() {
+                for child in rest.iter().rev() {
+                    stack.push((Rc::clone(child), path.clone()));
+                }
+                stack.push((Rc::clone(first), path));
+            }
+        }
+        
+        None
+    }
+    
+    /// Árbol en formato DOT de Graphviz, para pegarlo en un visor
+    ///
+    /// El id de cada nodo sale de su dirección en memoria, así que dos
+    /// nodos con el mismo valor siguen siendo nodos distintos. Un nodo
+    /// alcanzable por varios caminos se declara una sola vez.
+    fn to_dot(root: &Rc<RefCell<TreeNode>>) -> String {
+        let id = |node: &Rc<RefCell<TreeNode>>| format!("n{:p}", Rc::as_ptr(node));
+        let mut dot = String::from("digraph tree {\n");
+        let mut visited = HashSet::new();
+        let mut stack = vec![Rc::clone(root)];
+        
+        // CORREGIDO: Pila explícita y nodos visitados: ni desborda el stack ni entra en bucle con un ciclo
+        while let Some(node) = stack.pop() {
+            if !visited.insert(Rc::as_ptr(&node)) {
+                continue;
+            }
+            let node_ref = node.borrow();
+            dot.push_str(&format!("    {} [label=\"{}\"];\n", id(&node), node_ref.value));
+            for child in &node_ref.children {
+                dot.push_str(&format!("    {} -> {};\n", id(&node), id(child)));
+                stack.push(Rc::clone(child));
+            }
+        }
+        
+        dot.push_str("}\n");
+        dot
+    }
+    
+    /// Copia el árbol a un `Graph` con una arista padre -> hijo de peso 1 por enlace
+    ///
+    /// Los `NodeId` se asignan en anchura desde la raíz, que es `NodeId(0)`.
+    /// Como en `to_dot`, un nodo compartido por varios padres aparece una
+    /// sola vez, con una arista desde cada uno.
+    fn to_graph(root: &Rc<RefCell<TreeNode>>) -> (Graph<i32, u32>, NodeId) {
+        let mut graph = Graph::new();
+        let mut ids = HashMap::new();
+        let root_id = graph.add_node(root.borrow().value);
+        ids.insert(Rc::as_ptr(root), root_id);
+        let mut queue = VecDeque::from([(Rc::clone(root), root_id)]);
+        
+        while let Some((node, id)) = queue.pop_front() {
+            for child in &node.borrow().children {
+                let child_id = match ids.get(&Rc::as_ptr(child)) {
+                    Some(&child_id) => child_id,
+                    None => {
+                        let child_id = graph.add_node(child.borrow().value);
+                        ids.insert(Rc::as_ptr(child), child_id);
+                        queue.push_back((Rc::clone(child), child_id));
+                        child_id
+                    }
+                };
+                graph.add_edge(id, child_id, 1);
+            }
+        }
+        
+        (graph, root_id)
+    }
 }
 
 /// Función que demuestra Rc sin ciclos
@@ -71,6 +199,148 @@ fn demonstrate_rc_without_cycles() {
     println!("Número de referencias a node1: {}", Rc::strong_count(&node1));
 }
 
+/// Devuelve los índices de nodos sospechosos de formar parte de un ciclo
+///
+/// Heurística: un nodo con hijos, más de una referencia fuerte y ninguna
+/// referencia débil indica que sus hijos no usaron Weak para apuntar hacia
+/// atrás. Las hojas se ignoran porque no tienen back-edges que revisar.
+fn find_potential_leaks(nodes: &[Rc<RefCell<TreeNode>>]) -> Vec<usize> {
+    nodes.iter()
+        .enumerate()
+        .filter(|(_, node)| {
+            Rc::strong_count(node) > 1
+                && Rc::weak_count(node) == 0
+                && !node.borrow().children.is_empty()
+        })
+        .map(|(i, _)| i)
+        .collect()
+}
+
+/// Función que demuestra la detección de ciclos con strong_count/weak_count
+fn demonstrate_rc_cycle_detection() {
+    println!("\n✅ Demostrando detección de ciclos con Rc...");
+    
+    // Árbol correcto: los hijos apuntan al padre con Weak
+    let root = Rc::new(RefCell::new(TreeNode::new(1)));
+    let child = Rc::new(RefCell::new(TreeNode::new(2)));
+    TreeNode::link_child(&root, child.clone());
+    
+    let tree = vec![root, child];
+    println!("Árbol sin ciclos, nodos sospechosos: {:?}", find_potential_leaks(&tree));
+    
+    // Ciclo intencional: a -> b -> a usando Rc en ambos sentidos
+    let a = Rc::new(RefCell::new(TreeNode::new(10)));
+    let b = Rc::new(RefCell::new(TreeNode::new(20)));
+    a.borrow_mut().children.push(b.clone());
+    b.borrow_mut().children.push(a.clone());
+    
+    let cycle = vec![a, b];
+    for (i, node) in cycle.iter().enumerate() {
+        println!(
+            "Nodo {}: strong = {}, weak = {}",
+            i,
+            Rc::strong_count(node),
+            Rc::weak_count(node)
+        );
+    }
+    println!("Ciclo, nodos sospechosos: {:?}", find_potential_leaks(&cycle));
+    
+    // CORREGIDO: Romper el ciclo para que la memoria se libere
+    cycle[1].borrow_mut().children.clear();
+    println!("Ciclo roto, nodos sospechosos: {:?}", find_potential_leaks(&cycle));
+}
+
+/// Función que demuestra transformar un árbol sin modificar el original
+fn demonstrate_tree_map() {
+    println!("\n✅ Demostrando TreeNode::map...");
+    
+    let root = Rc::new(RefCell::new(TreeNode::new(1)));
+    let child = Rc::new(RefCell::new(TreeNode::new(2)));
+    TreeNode::link_child(&root, child.clone());
+    
+    let doubled = TreeNode::map(&root, |v| v * 10);
+    let new_child = doubled.borrow().children[0].clone();
+    
+    println!("Original: raíz {} -> hijo {}", root.borrow().value, child.borrow().value);
+    println!("Transformado: raíz {} -> hijo {}", doubled.borrow().value, new_child.borrow().value);
+    println!("Padre del hijo transformado: {:?}", new_child.borrow().get_parent_value());
+}
+
+/// Función que demuestra recorrer un árbol de TreeNode como grafo
+fn demonstrate_tree_as_graph() {
+    println!("\n✅ Demostrando TreeNode::to_graph...");
+    
+    // 1 -> [2, 3], 2 -> [4, 5], 3 -> [6]
+    let nodes: Vec<_> = (1..=6).map(|value| Rc::new(RefCell::new(TreeNode::new(value)))).collect();
+    for (parent, child) in [(0, 1), (0, 2), (1, 3), (1, 4), (2, 5)] {
+        TreeNode::link_child(&nodes[parent], Rc::clone(&nodes[child]));
+    }
+    
+    let (mut graph, root) = TreeNode::to_graph(&nodes[0]);
+    let values = |graph: &Graph<i32, u32>, ids: &[NodeId]| {
+        ids.iter().filter_map(|&id| graph.node(id).copied()).collect::<Vec<i32>>()
+    };
+    println!("BFS: {:?}", values(&graph, &graph.bfs(root)));
+    println!("DFS: {:?}", values(&graph, &graph.dfs(root)));
+    
+    // Los ids van en anchura, así que coinciden con el valor - 1
+    let (four, five) = (NodeId(3), NodeId(4));
+    if let Some((path, hops)) = graph.shortest_path(root, five) {
+        println!("Camino de 1 a 5: {:?} ({} saltos)", values(&graph, &path), hops);
+    }
+    // En el grafo se pueden añadir aristas que el árbol no permite, como un atajo 1 -> 5
+    graph.add_edge(root, five, 1);
+    if let Some((path, hops)) = graph.shortest_path(root, five) {
+        println!("Con el atajo: {:?} ({} saltos)", values(&graph, &path), hops);
+    }
+    println!("De 4 a 1 (las aristas solo bajan): {:?}", graph.shortest_path(four, root));
+    
+    graph::demonstrate_graph();
+}
+
+/// Función que demuestra buscar el camino hasta un nodo sin recursión
+fn demonstrate_tree_path() {
+    println!("\n✅ Demostrando TreeNode::path_to...");
+    
+    // 1 -> 2 -> 4 -> 5 (una rama larga) y 1 -> 3
+    let root = Rc::new(RefCell::new(TreeNode::new(1)));
+    let mut parent = Rc::clone(&root);
+    for value in [2, 4, 5] {
+        let child = Rc::new(RefCell::new(TreeNode::new(value)));
+        TreeNode::link_child(&parent, Rc::clone(&child));
+        parent = child;
+    }
+    TreeNode::link_child(&root, Rc::new(RefCell::new(TreeNode::new(3))));
+    
+    for target in [5, 3, 1, 42] {
+        match TreeNode::path_to(&root, target) {
+            Some(path) => println!("Camino hasta {}: {:?}", target, path),
+            None => println!("{} no está en el árbol", target),
+        }
+    }
+    
+    // CORREGIDO: Un árbol muy profundo no desborda el stack
+    let deep_root = Rc::new(RefCell::new(TreeNode::new(0)));
+    let mut parent = Rc::clone(&deep_root);
+    for value in 1..=100_000 {
+        let child = Rc::new(RefCell::new(TreeNode::new(value)));
+        TreeNode::link_child(&parent, Rc::clone(&child));
+        parent = child;
+    }
+    let depth = TreeNode::path_to(&deep_root, 100_000).map(|path| path.len());
+    println!("Camino en un árbol de 100_001 niveles: {:?} nodos", depth);
+    
+    // Dos nodos con valor 2 en ramas distintas siguen siendo nodos distintos en el DOT
+    TreeNode::link_child(&root, Rc::new(RefCell::new(TreeNode::new(2))));
+    println!("Árbol en DOT (pegar en un visor de Graphviz):\n{}", TreeNode::to_dot(&root));
+    
+    // Liberar la cadena de forma iterativa: el Drop recursivo sí desbordaría el stack
+    let mut next = deep_root.borrow_mut().children.pop();
+    while let Some(node) = next {
+        next = node.borrow_mut().children.pop();
+    }
+}
+
 /// Función que demuestra RefCell correcto
 fn demonstrate_refcell_correct() {
     println!("\n✅ Demostrando RefCell correcto...");
@@ -148,8 +418,9 @@ fn demonstrate_memory_management_correct() {
     
     // CORREGIDO: Limpiar memoria cuando sea necesario
     data.clear();
+    println!("Vec tras clear: {}", vec_stats(&data));
     data.shrink_to_fit();
-    println!("Vec limpiado y optimizado");
+    println!("Vec tras shrink_to_fit: {}", vec_stats(&data));
     
     // CORREGIDO: HashMap con gestión de memoria
     let mut map = HashMap::new();
@@ -162,6 +433,9 @@ fn demonstrate_memory_management_correct() {
     // CORREGIDO: Limpiar entradas antiguas
     map.retain(|k, _| k % 2 == 0);
     println!("HashMap limpiado, {} elementos restantes", map.len());
+    println!("HashMap tras retain: {}", map_stats(&map));
+    map.shrink_to_fit();
+    println!("HashMap tras shrink_to_fit: {}", map_stats(&map));
 }
 
 /// Función que demuestra recursión optimizada
@@ -191,21 +465,32 @@ fn demonstrate_buffer_safety() {
     
     let mut buffer = [0; 10];
     
-    // CORREGIDO: Acceso seguro con bounds checking
-    for i in 0..buffer.len() {
-        buffer[i] = i as u8;
+    // CORREGIDO: Los índices fuera de rango dan None en lugar de un `if i < len` manual
+    let mut skipped = Vec::new();
+    for i in 0..15 {
+        match safe_index_mut(&mut buffer, i) {
+            Some(slot) => *slot = i as u8,
+            None => skipped.push(i),
+        }
     }
+    println!("Índices fuera de rango ignorados: {:?}", skipped);
     
-    // CORREGIDO: Iteración segura
-    for (i, &value) in buffer.iter().enumerate() {
-        println!("buffer[{}] = {}", i, value);
+    // CORREGIDO: Lectura con el mismo hábito
+    for i in 0..buffer.len() {
+        if let Some(value) = safe_index(&buffer, i) {
+            println!("buffer[{}] = {}", i, value);
+        }
     }
+    println!("buffer[10] = {:?}", safe_index(&buffer, 10));
 }
 
 /// Función que demuestra optimización de memoria
 fn demonstrate_memory_optimization() {
     println!("\n✅ Demostrando optimización de memoria...");
     
+    let heap_before = current_heap_bytes();
+    let allocs_before = allocation_count();
+    
     // CORREGIDO: Allocations de tamaño uniforme para reducir fragmentación
     let mut data = Vec::new();
     for i in 0..1000 {
@@ -214,6 +499,21 @@ fn demonstrate_memory_optimization() {
     }
     
     println!("Fragmentation minimizada con {} allocations uniformes", data.len());
+    println!(
+        "Heap: {} bytes antes, {} bytes después ({} allocations)",
+        heap_before,
+        current_heap_bytes(),
+        allocation_count() - allocs_before
+    );
+    
+    // CORREGIDO: Liberar explícitamente y comprobar que el heap vuelve a su nivel
+    let heap_before = current_heap_bytes();
+    drop(data);
+    println!(
+        "Heap tras liberar: {} bytes antes, {} bytes después",
+        heap_before,
+        current_heap_bytes()
+    );
 }
 
 /// Función que demuestra gestión de recursos
@@ -221,8 +521,32 @@ fn demonstrate_resource_management() {
     println!("\n✅ Demostrando gestión de recursos...");
     
     // CORREGIDO: RAII (Resource Acquisition Is Initialization)
-    let data = String::from("Datos importantes");
-    println!("Datos: {}", data);
+    let data = Tracked::new("datos", String::from("Datos importantes"));
+    println!("Datos: {}", *data);
+    
+    {
+        let _a = Tracked::new("scope interno A", 1);
+        let _b = Tracked::new("scope interno B", 2);
+        println!("Saliendo del scope interno...");
+        // CORREGIDO: Se liberan en orden inverso (B y luego A)
+    }
+    
+    // Los campos de un struct se liberan en el orden en que se declaran
+    struct Connection {
+        _socket: Tracked<u16>,
+        _buffer: Tracked<Vec<u8>>,
+    }
+    
+    let log = DropLog::default();
+    let connection = Connection {
+        _socket: Tracked::with_log("socket", 8080, &log),
+        _buffer: Tracked::with_log("buffer", vec![0; 64], &log),
+    };
+    drop(connection);
+    println!("Orden de liberación de los campos: {:?}", log.borrow());
+    
+    println!("Liberando {} explícitamente...", data.name());
+    drop(data);
     
     // CORREGIDO: Los recursos se liberan automáticamente
     // No hay necesidad de liberar manualmente
@@ -255,6 +579,16 @@ fn safe_recursion(n: u32) -> u32 {
     }
 }
 
+/// Acceso con comprobación de límites: `None` si `i` está fuera del slice
+fn safe_index<T>(slice: &[T], i: usize) -> Option<&T> {
+    slice.get(i)
+}
+
+/// Versión mutable de `safe_index`
+fn safe_index_mut<T>(slice: &mut [T], i: usize) -> Option<&mut T> {
+    slice.get_mut(i)
+}
+
 /// Función auxiliar para allocation grande
 fn try_large_allocation() -> Result<Vec<u8>, String> {
     // CORREGIDO: Intentar allocation grande con manejo de errores
@@ -301,6 +635,10 @@ fn main() {
     println!("{}", "=".repeat(70));
     
     demonstrate_rc_without_cycles();
+    demonstrate_rc_cycle_detection();
+    demonstrate_tree_map();
+    demonstrate_tree_path();
+    demonstrate_tree_as_graph();
     demonstrate_refcell_correct();
     demonstrate_arc_threads_correct();
     demonstrate_memory_management_correct();
@@ -309,16 +647,44 @@ fn main() {
     demonstrate_buffer_safety();
     demonstrate_memory_optimization();
     demonstrate_resource_management();
+    resource::demonstrate_resource_close();
     demonstrate_memory_error_handling();
     demonstrate_weak_references();
+    cow_vec::demonstrate_cow_vec();
+    pinned::demonstrate_pinned_self_referential();
+    try_cell::demonstrate_try_cell();
+    linked_list::demonstrate_doubly_linked_list();
+    union_type::demonstrate_union_type();
+    alignment::demonstrate_packed_struct_alignment();
+    transmute_safety::demonstrate_transmute_safety();
+    raw_pointers::demonstrate_raw_pointer_arithmetic();
+    mmap::demonstrate_memory_mapped_file();
+    dir_traversal::demonstrate_dir_traversal();
     
     println!("\n✅ Todas las demostraciones completadas sin errores!");
     println!("🎯 Conceptos clave demostrados:");
     println!("   - Rc<T>: Referencias compartidas sin ciclos");
     println!("   - Weak<T>: Referencias débiles para evitar ciclos");
+    println!("   - Cycle detection: strong_count vs weak_count");
+    println!("   - Tree map: Copiar un árbol re-enlazando los Weak al padre");
+    println!("   - Tree search: path_to con una pila explícita en lugar de recursión");
+    println!("   - Tree export: to_dot con un id por dirección de memoria");
+    println!("   - Graphs: Nodos y aristas por índice, con BFS, DFS y Dijkstra");
     println!("   - RefCell<T>: Mutabilidad interior con borrow checking");
+    println!("   - TryCell<T>: Borrows que devuelven Result en lugar de panic");
     println!("   - Arc<T>: Referencias atómicas para threads");
-    println!("   - RAII: Liberación automática de recursos");
+    println!("   - Copy-on-write: Compartir datos hasta la primera escritura");
+    println!("   - Pin<Box<T>>: Structs auto-referenciales que no se mueven");
+    println!("   - Raw pointers: Lista doblemente enlazada con unsafe tras una API segura");
+    println!("   - Unions: Reinterpretar bits y uniones etiquetadas con acceso seguro");
+    println!("   - Alignment: Relleno entre campos, orden de campos y repr(packed)");
+    println!("   - Transmute: Comprobar tamaños y preferir to_le_bytes, as_bytes o from_utf8");
+    println!("   - Raw pointers: Recorrer un buffer con add(1) y un puntero al final");
+    println!("   - Memory-mapped files: Registros de tamaño fijo leídos sin copiar el fichero");
+    println!("   - Iterative traversal: Una cola de directorios en lugar de recursión");
+    println!("   - Memory stats: Bytes reservados sin usar (capacity - len)");
+    println!("   - RAII: Liberación automática de recursos en orden LIFO");
+    println!("   - Explicit close: close() devuelve el error y Drop cierra lo que siga abierto");
     println!("   - Memory safety: Prevención de errores de memoria");
     println!("   - Buffer safety: Prevención de buffer overflow");
     println!("   - Resource management: Gestión automática de recursos");
@@ -0,0 +1,49 @@
+//! 🦀 Memory Stats - Cuánta capacidad reservada no se usa
+//!
+//! `len` es lo que hay guardado; `capacity` es lo que ya está reservado
+//! en el heap. La diferencia, multiplicada por el tamaño de cada
+//! elemento, es memoria pagada pero sin usar: lo que recupera
+//! `shrink_to_fit`.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::mem::size_of;
+
+/// Ocupación de una colección
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryStats {
+    pub len: usize,
+    pub capacity: usize,
+    pub wasted_bytes: usize,
+}
+
+impl fmt::Display for MemoryStats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "len {}, capacity {}, {} bytes sin usar",
+            self.len, self.capacity, self.wasted_bytes
+        )
+    }
+}
+
+/// Estadísticas de un `Vec`; solo cuenta el buffer, no lo que apunten los elementos
+pub fn vec_stats<T>(v: &Vec<T>) -> MemoryStats {
+    MemoryStats {
+        len: v.len(),
+        capacity: v.capacity(),
+        wasted_bytes: (v.capacity() - v.len()) * size_of::<T>(),
+    }
+}
+
+/// Estadísticas de un `HashMap`
+///
+/// Aproximado: `capacity` es cuántos elementos caben sin realocar, no el
+/// número real de buckets, y se ignoran los bytes de control de la tabla.
+pub fn map_stats<K, V>(m: &HashMap<K, V>) -> MemoryStats {
+    MemoryStats {
+        len: m.len(),
+        capacity: m.capacity(),
+        wasted_bytes: (m.capacity() - m.len()) * size_of::<(K, V)>(),
+    }
+}
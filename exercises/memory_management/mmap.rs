@@ -0,0 +1,220 @@
+//! 🦀 Memory-Mapped Files - Leer una base de datos de usuarios sin copiarla
+//!
+//! `File::read_to_string` o un `BufReader` copian cada byte del fichero a
+//! memoria del proceso. Con `mmap` el sistema operativo proyecta el
+//! fichero en el espacio de direcciones: las páginas se cargan bajo
+//! demanda al tocarlas y se leen como un `&[u8]`, sin copias.
+//!
+//! El fichero es una secuencia de registros de tamaño fijo, así que el
+//! registro `i` empieza en `i * RECORD_SIZE` y el acceso aleatorio es O(1):
+//!
+//! ```text
+//! id: u32 | posts: u32 | name: [u8; 24] | email: [u8; 32]   (64 bytes, texto relleno con 0)
+//! ```
+//!
+//! `MmapUserDb` necesita la feature `mmap` (crate `memmap2`); la lectura
+//! con `BufReader` funciona siempre y sirve de comparación.
+
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+use std::time::Instant;
+
+pub const NAME_SIZE: usize = 24;
+pub const EMAIL_SIZE: usize = 32;
+pub const RECORD_SIZE: usize = 4 + 4 + NAME_SIZE + EMAIL_SIZE;
+
+/// Usuario que apunta a los bytes del registro; con `mmap`, al propio fichero
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UserRef<'a> {
+    pub id: u32,
+    pub posts: u32,
+    pub name: &'a str,
+    pub email: &'a str,
+}
+
+/// Copia `text` en `field` rellenando con ceros; falla si no cabe o contiene un 0
+fn write_text(field: &mut [u8], text: &str) -> io::Result<()> {
+    if text.len() > field.len() || text.as_bytes().contains(&0) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("'{}' no cabe en {} bytes sin ceros", text, field.len()),
+        ));
+    }
+    field[..text.len()].copy_from_slice(text.as_bytes());
+    Ok(())
+}
+
+/// Escribe `user` como un registro de `RECORD_SIZE` bytes
+pub fn write_record(writer: &mut impl Write, user: &UserRef) -> io::Result<()> {
+    let mut record = [0u8; RECORD_SIZE];
+    record[0..4].copy_from_slice(&user.id.to_le_bytes());
+    record[4..8].copy_from_slice(&user.posts.to_le_bytes());
+    write_text(&mut record[8..8 + NAME_SIZE], user.name)?;
+    write_text(&mut record[8 + NAME_SIZE..], user.email)?;
+    writer.write_all(&record)
+}
+
+/// Texto de un campo sin el relleno de ceros del final
+fn read_text(field: &[u8]) -> Option<&str> {
+    let len = field.iter().position(|&b| b == 0).unwrap_or(field.len());
+    std::str::from_utf8(&field[..len]).ok()
+}
+
+/// Interpreta un registro completo; `None` si el texto no es UTF-8
+pub fn parse_record(record: &[u8; RECORD_SIZE]) -> Option<UserRef<'_>> {
+    let (id, rest) = record.split_first_chunk::<4>()?;
+    let (posts, rest) = rest.split_first_chunk::<4>()?;
+    let (name, email) = rest.split_at(NAME_SIZE);
+    Some(UserRef {
+        id: u32::from_le_bytes(*id),
+        posts: u32::from_le_bytes(*posts),
+        name: read_text(name)?,
+        email: read_text(email)?,
+    })
+}
+
+/// Índices de los registros que cumplen `predicate`, leyendo con `BufReader`
+///
+/// Cada registro se copia a un buffer de la pila antes de interpretarlo.
+pub fn scan_users_buffered<F>(path: &Path, predicate: F) -> io::Result<Vec<usize>>
+where
+    F: Fn(&UserRef) -> bool,
+{
+    let mut reader = BufReader::new(File::open(path)?);
+    let mut record = [0u8; RECORD_SIZE];
+    let mut matches = Vec::new();
+    let mut index = 0;
+    
+    loop {
+        match reader.read_exact(&mut record) {
+            Ok(()) => {}
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(matches),
+            Err(e) => return Err(e),
+        }
+        if parse_record(&record).is_some_and(|user| predicate(&user)) {
+            matches.push(index);
+        }
+        index += 1;
+    }
+}
+
+#[cfg(feature = "mmap")]
+pub use mapped::MmapUserDb;
+
+#[cfg(feature = "mmap")]
+mod mapped {
+    use super::{parse_record, UserRef, RECORD_SIZE};
+    use memmap2::Mmap;
+    use std::fs::File;
+    use std::io;
+    use std::path::Path;
+    
+    /// Base de datos de usuarios proyectada en memoria
+    pub struct MmapUserDb {
+        mmap: Mmap,
+    }
+    
+    impl MmapUserDb {
+        /// Proyecta `path`; su tamaño debe ser múltiplo de `RECORD_SIZE`
+        pub fn open(path: &Path) -> io::Result<Self> {
+            let file = File::open(path)?;
+            // SAFETY: el mapeo es de solo lectura, pero si otro proceso trunca o
+            // modifica el fichero mientras está proyectado, leerlo es UB o SIGBUS.
+            // Los ficheros de este ejercicio solo los escribe el propio proceso,
+            // antes de abrirlos.
+            let mmap = unsafe { Mmap::map(&file)? };
+            // CORREGIDO: Un fichero truncado no deja un registro a medias al final
+            if mmap.len() % RECORD_SIZE != 0 {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{} bytes no es múltiplo de {}", mmap.len(), RECORD_SIZE),
+                ));
+            }
+            Ok(Self { mmap })
+        }
+        
+        pub fn len(&self) -> usize {
+            self.mmap.len() / RECORD_SIZE
+        }
+        
+        /// Registro `index`; `None` si no existe o su texto no es UTF-8
+        pub fn get_user(&self, index: usize) -> Option<UserRef<'_>> {
+            let start = index.checked_mul(RECORD_SIZE)?;
+            let record = self.mmap.get(start..start.checked_add(RECORD_SIZE)?)?;
+            parse_record(record.try_into().ok()?)
+        }
+        
+        /// Índices de los registros que cumplen `predicate`, sin copiar ninguno
+        pub fn scan_users<F>(&self, predicate: F) -> Vec<usize>
+        where
+            F: Fn(&UserRef) -> bool,
+        {
+            self.mmap
+                .chunks_exact(RECORD_SIZE)
+                .enumerate()
+                .filter_map(|(index, record)| {
+                    let user = parse_record(record.try_into().ok()?)?;
+                    predicate(&user).then_some(index)
+                })
+                .collect()
+        }
+    }
+}
+
+/// Escribe `count` usuarios sintéticos en `path`
+fn write_user_db(path: &Path, count: u32) -> io::Result<()> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    for id in 0..count {
+        let name = format!("User{}", id);
+        let email = format!("user{}@example.com", id);
+        let user = UserRef {
+            id,
+            posts: id % 50,
+            name: &name,
+            email: &email,
+        };
+        write_record(&mut writer, &user)?;
+    }
+    writer.flush()
+}
+
+/// Función que demuestra leer una base de datos de 1M de usuarios con mmap y con BufReader
+pub fn demonstrate_memory_mapped_file() {
+    println!("\n✅ Demostrando ficheros proyectados en memoria...");
+    
+    let path = std::env::temp_dir().join(format!("rust_lab_users_{}.db", std::process::id()));
+    const USERS: u32 = 1_000_000;
+    if let Err(e) = write_user_db(&path, USERS) {
+        println!("No se pudo crear {}: {}", path.display(), e);
+        return;
+    }
+    println!("{} usuarios en {} ({} MB)", USERS, path.display(), USERS as usize * RECORD_SIZE / 1_000_000);
+    
+    let active = |user: &UserRef| user.posts >= 45;
+    
+    let start = Instant::now();
+    match scan_users_buffered(&path, active) {
+        Ok(matches) => println!("BufReader: {} coincidencias en {:?}", matches.len(), start.elapsed()),
+        Err(e) => println!("Error leyendo con BufReader: {}", e),
+    }
+    
+    #[cfg(feature = "mmap")]
+    match MmapUserDb::open(&path) {
+        Ok(db) => {
+            let start = Instant::now();
+            let matches = db.scan_users(active);
+            println!("mmap: {} coincidencias en {:?}", matches.len(), start.elapsed());
+            // Acceso aleatorio: solo se carga la página del registro pedido
+            println!("Registro 765432: {:?}", db.get_user(765_432));
+            println!("Registro {} (fuera de rango): {:?}", db.len(), db.get_user(db.len()));
+        }
+        Err(e) => println!("Error al proyectar: {}", e),
+    }
+    #[cfg(not(feature = "mmap"))]
+    println!("Compilar con --features mmap para comparar con memmap2");
+    
+    if let Err(e) = std::fs::remove_file(&path) {
+        println!("No se pudo borrar {}: {}", path.display(), e);
+    }
+}
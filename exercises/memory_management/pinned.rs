@@ -0,0 +1,79 @@
+//! 🦀 Pinned - Structs auto-referenciales con Pin<Box<T>>
+//!
+//! Un struct que guarda un puntero a uno de sus propios campos se rompe
+//! si se mueve: el puntero seguiría apuntando a la dirección antigua.
+//! `Pin` garantiza que el valor ya no cambiará de dirección.
+
+use std::marker::PhantomPinned;
+use std::pin::Pin;
+use std::ptr;
+
+/// Struct cuyo campo `ptr` apunta a su propio campo `data`
+#[derive(Debug)]
+pub struct SelfRef {
+    data: String,
+    ptr: *const String,
+    // Sin esto el struct sería `Unpin` y Pin no impediría moverlo
+    _pin: PhantomPinned,
+}
+
+impl SelfRef {
+    /// Crea el struct ya fijado en el heap y después enlaza `ptr`
+    pub fn new(s: String) -> Pin<Box<Self>> {
+        let mut boxed = Box::pin(SelfRef {
+            data: s,
+            ptr: ptr::null(),
+            _pin: PhantomPinned,
+        });
+        
+        // CORREGIDO: tomar la dirección solo cuando el valor ya no se moverá
+        let data_ptr: *const String = &boxed.data;
+        // SAFETY: solo modificamos un campo, el valor sigue en el mismo sitio
+        unsafe {
+            boxed.as_mut().get_unchecked_mut().ptr = data_ptr;
+        }
+        boxed
+    }
+    
+    /// Lee `data` a través del puntero crudo
+    pub fn read_via_ptr(&self) -> &str {
+        // SAFETY: `ptr` apunta a `self.data` y el Pin garantiza que no se ha movido
+        unsafe { &*self.ptr }
+    }
+    
+    /// Lee `data` directamente
+    pub fn data(&self) -> &str {
+        &self.data
+    }
+    
+    /// Comprueba que `ptr` sigue apuntando a `data`
+    pub fn is_consistent(&self) -> bool {
+        ptr::eq(self.ptr, &self.data)
+    }
+}
+
+/// Función que mueve el Box (no el contenido) a otra función
+fn take_ownership(pinned: Pin<Box<SelfRef>>) -> Pin<Box<SelfRef>> {
+    pinned
+}
+
+/// Función que demuestra un struct auto-referencial con Pin<Box<T>>
+pub fn demonstrate_pinned_self_referential() {
+    println!("\n✅ Demostrando structs auto-referenciales con Pin...");
+    
+    let pinned = SelfRef::new(String::from("Datos fijados"));
+    println!("Leído directamente: {}", pinned.data());
+    println!("Leído vía puntero: {}", pinned.read_via_ptr());
+    
+    // CORREGIDO: mover el Pin<Box> solo mueve el puntero, no el SelfRef
+    let address_before = &*pinned as *const SelfRef;
+    let pinned = take_ownership(pinned);
+    let address_after = &*pinned as *const SelfRef;
+    println!("Misma dirección tras mover el Box: {}", address_before == address_after);
+    println!("Puntero consistente: {}", pinned.is_consistent());
+    
+    // Moverlo fuera del Pin no compila porque SelfRef no es Unpin:
+    // let moved: SelfRef = *Pin::into_inner(pinned);
+    // std::mem::swap(&mut *a, &mut *b);
+    println!("Sacar el valor del Pin no compila: SelfRef es !Unpin");
+}
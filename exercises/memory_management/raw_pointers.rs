@@ -0,0 +1,84 @@
+//! 🦀 Raw Pointers - Recorrer buffers con aritmética de punteros
+//!
+//! El ejercicio de buffer safety indexa con comprobación de límites. Aquí
+//! se recorre el mismo tipo de buffer con punteros crudos, como en C: un
+//! puntero al principio, otro una posición después del final, y `add(1)`
+//! para avanzar. El compilador ya no comprueba nada; cada bloque `unsafe`
+//! explica qué invariante lo hace correcto.
+//!
+//! Miri comprueba que ningún puntero se sale del buffer:
+//! `cargo +nightly miri test --test memory_management_tests via_ptr`.
+
+use std::hint::black_box;
+use std::time::Instant;
+
+/// Suma de todos los elementos recorriendo el slice con un puntero
+pub fn sum_slice_via_ptr(data: &[i32]) -> i64 {
+    let mut ptr = data.as_ptr();
+    // SAFETY: un puntero una posición después del final del mismo slice es
+    // válido para calcularlo (no para leerlo); con len 0, end == ptr.
+    let end = unsafe { ptr.add(data.len()) };
+    let mut sum = 0i64;
+    
+    while ptr != end {
+        // SAFETY: `ptr` está en `[as_ptr, end)`, así que apunta a un elemento
+        // inicializado del slice, que sigue prestado durante todo el bucle.
+        sum += unsafe { *ptr } as i64;
+        // SAFETY: `ptr < end`, así que `ptr + 1` es como mucho `end`.
+        ptr = unsafe { ptr.add(1) };
+    }
+    sum
+}
+
+/// Invierte el slice intercambiando desde los dos extremos hacia el centro
+pub fn reverse_in_place_via_ptr(data: &mut [i32]) {
+    if data.len() < 2 {
+        return;
+    }
+    let mut front = data.as_mut_ptr();
+    // SAFETY: `len >= 2`, así que `len - 1` es el índice del último elemento.
+    let mut back = unsafe { front.add(data.len() - 1) };
+    
+    while front < back {
+        // SAFETY: `front` y `back` apuntan a elementos distintos del slice
+        // (`front < back`) y ambos vienen del mismo `&mut`, así que pueden
+        // leerse y escribirse sin solaparse.
+        unsafe {
+            std::ptr::swap(front, back);
+            front = front.add(1);
+            // `back > front` antes de avanzar `front`, así que `back - 1` sigue dentro del slice
+            back = back.sub(1);
+        }
+    }
+}
+
+/// Versión segura de referencia para comparar resultados y tiempos
+pub fn sum_slice_safe(data: &[i32]) -> i64 {
+    data.iter().map(|&x| x as i64).sum()
+}
+
+/// Función que demuestra recorrer buffers con punteros crudos frente a iteradores
+pub fn demonstrate_raw_pointer_arithmetic() {
+    println!("\n✅ Demostrando aritmética de punteros crudos...");
+    
+    let mut small = [1, 2, 3, 4, 5];
+    println!("sum_slice_via_ptr({:?}) = {}", small, sum_slice_via_ptr(&small));
+    reverse_in_place_via_ptr(&mut small);
+    println!("reverse_in_place_via_ptr -> {:?}", small);
+    println!("Slice vacío: suma = {}", sum_slice_via_ptr(&[]));
+    
+    let data: Vec<i32> = (0..2_000_000).map(|i| (i % 1000) - 500).collect();
+    
+    let start = Instant::now();
+    let ptr_sum = sum_slice_via_ptr(black_box(&data));
+    let ptr_time = start.elapsed();
+    
+    let start = Instant::now();
+    let iter_sum = sum_slice_safe(black_box(&data));
+    let iter_time = start.elapsed();
+    
+    // El iterador tampoco comprueba límites en cada paso, así que el puntero crudo no suele ganar
+    println!("Puntero crudo: {:?}", ptr_time);
+    println!("Iterador seguro: {:?}", iter_time);
+    println!("Mismas sumas: {}", ptr_sum == iter_sum);
+}
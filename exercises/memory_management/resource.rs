@@ -0,0 +1,146 @@
+//! 🦀 Resource - Cerrar en Drop, pero permitir un close explícito antes
+//!
+//! `Drop::drop` no puede devolver un `Result`: si cerrar un fichero falla
+//! al salir del scope, el error solo se puede registrar. `Resource`
+//! ofrece las dos cosas: `close()` para quien quiere enterarse del error
+//! y un `Drop` que cierra lo que siga abierto. El handle vive en un
+//! `Option` y `close` lo saca con `take()`, así que nunca se cierra dos veces.
+
+use std::fs::File;
+use std::io::{self, Write};
+
+use super::tracked::DropLog;
+
+/// Handle que se puede cerrar informando del error
+pub trait Close {
+    fn close(self) -> io::Result<()>;
+}
+
+impl Close for File {
+    /// Vuelca a disco antes de soltar el descriptor: es ahí donde aparecen los errores de escritura
+    fn close(self) -> io::Result<()> {
+        self.sync_all()
+    }
+}
+
+/// Handle de prueba que anota cada cierre en un `DropLog` y puede fallar al cerrar
+#[derive(Debug)]
+pub struct LoggedHandle {
+    name: String,
+    log: DropLog,
+    fail: bool,
+}
+
+impl LoggedHandle {
+    pub fn new(name: &str, log: &DropLog) -> Self {
+        Self {
+            name: name.to_string(),
+            log: DropLog::clone(log),
+            fail: false,
+        }
+    }
+    
+    /// Como `new`, pero su `close` devuelve un error después de anotarse
+    pub fn failing(name: &str, log: &DropLog) -> Self {
+        Self {
+            fail: true,
+            ..Self::new(name, log)
+        }
+    }
+}
+
+impl Close for LoggedHandle {
+    fn close(self) -> io::Result<()> {
+        self.log.borrow_mut().push(format!("close {}", self.name));
+        if self.fail {
+            Err(io::Error::other(format!("no se pudo cerrar {}", self.name)))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Recurso con nombre que se cierra una sola vez: con `close` o, si no, al liberarse
+#[derive(Debug)]
+pub struct Resource<H: Close> {
+    name: String,
+    handle: Option<H>,
+}
+
+impl<H: Close> Resource<H> {
+    pub fn new(name: &str, handle: H) -> Self {
+        Self {
+            name: name.to_string(),
+            handle: Some(handle),
+        }
+    }
+    
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    
+    pub fn is_closed(&self) -> bool {
+        self.handle.is_none()
+    }
+    
+    /// El handle abierto, o `None` si ya se cerró
+    pub fn handle_mut(&mut self) -> Option<&mut H> {
+        self.handle.as_mut()
+    }
+    
+    /// Cierra el handle y devuelve el error, si lo hay; sobre un recurso ya cerrado no hace nada
+    ///
+    /// Aunque el cierre falle, el handle se da por cerrado: reintentarlo
+    /// no es seguro (con un `File`, el descriptor ya se liberó).
+    pub fn close(&mut self) -> io::Result<()> {
+        match self.handle.take() {
+            Some(handle) => handle.close(),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<H: Close> Drop for Resource<H> {
+    fn drop(&mut self) {
+        // CORREGIDO: Drop no puede propagar el error; al menos queda registrado
+        if let Err(e) = self.close() {
+            eprintln!("⚠️  Error al cerrar {} en Drop: {}", self.name, e);
+        }
+    }
+}
+
+/// Función que demuestra el cierre explícito frente al cierre en Drop
+pub fn demonstrate_resource_close() {
+    println!("\n✅ Demostrando Resource con close explícito y cierre en Drop...");
+    
+    let path = std::env::temp_dir().join(format!("rust_lab_resource_{}.log", std::process::id()));
+    match File::create(&path) {
+        Ok(file) => {
+            let mut resource = Resource::new("resource.log", file);
+            if let Some(Err(e)) = resource.handle_mut().map(|file| writeln!(file, "inicio de sesión")) {
+                println!("Error al escribir: {}", e);
+            }
+            // El error de sync_all llega a quien llama en lugar de perderse en Drop
+            let closed = resource.close();
+            println!("close({}) = {:?}, cerrado: {}", resource.name(), closed, resource.is_closed());
+            println!("Segundo close = {:?}", resource.close());
+        }
+        Err(e) => println!("No se pudo crear {}: {}", path.display(), e),
+    }
+    if let Err(e) = std::fs::remove_file(&path) {
+        println!("No se pudo borrar {}: {}", path.display(), e);
+    }
+    
+    let log = DropLog::default();
+    {
+        let mut explicit = Resource::new("explícito", LoggedHandle::new("explícito", &log));
+        let _implicit = Resource::new("implícito", LoggedHandle::new("implícito", &log));
+        let _failing = Resource::new("defectuoso", LoggedHandle::failing("defectuoso", &log));
+        if let Err(e) = explicit.close() {
+            println!("Error al cerrar: {}", e);
+        }
+        println!("Saliendo del scope con dos recursos abiertos...");
+    }
+    // Un cierre por recurso: el explícito primero, el resto en orden LIFO
+    println!("Cierres: {:?}", log.borrow());
+}
@@ -0,0 +1,68 @@
+//! 🦀 Tracked - Hace visible el orden de Drop
+//!
+//! `Tracked<T>` envuelve un valor con un nombre y avisa cuando se libera,
+//! imprimiéndolo o anotándolo en un registro compartido. Sirve para ver
+//! las reglas de RAII: las variables locales se liberan en orden inverso
+//! a su declaración (LIFO) y los campos de un struct en orden de declaración.
+
+use std::cell::RefCell;
+use std::ops::{Deref, DerefMut};
+use std::rc::Rc;
+
+/// Registro compartido con los nombres en el orden en que se liberaron
+pub type DropLog = Rc<RefCell<Vec<String>>>;
+
+/// Valor con nombre que registra su propia liberación
+#[derive(Debug)]
+pub struct Tracked<T> {
+    name: String,
+    value: T,
+    log: Option<DropLog>,
+}
+
+impl<T> Tracked<T> {
+    /// Imprime un mensaje al liberarse
+    pub fn new(name: &str, value: T) -> Self {
+        Self {
+            name: name.to_string(),
+            value,
+            log: None,
+        }
+    }
+    
+    /// Anota su nombre en `log` al liberarse, sin imprimir nada
+    pub fn with_log(name: &str, value: T, log: &DropLog) -> Self {
+        Self {
+            name: name.to_string(),
+            value,
+            log: Some(Rc::clone(log)),
+        }
+    }
+    
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl<T> Deref for Tracked<T> {
+    type Target = T;
+    
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T> DerefMut for Tracked<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+impl<T> Drop for Tracked<T> {
+    fn drop(&mut self) {
+        match &self.log {
+            Some(log) => log.borrow_mut().push(self.name.clone()),
+            None => println!("🗑️  Liberando: {}", self.name),
+        }
+    }
+}
@@ -0,0 +1,107 @@
+//! 🦀 Transmute Safety - Cuándo `transmute` es correcto y qué usar en su lugar
+//!
+//! `std::mem::transmute` reinterpreta los bits de un valor como otro
+//! tipo del mismo tamaño. El compilador solo comprueba el tamaño; todo
+//! lo demás (validez de los bits, orden de bytes, layout de punteros
+//! anchos) es responsabilidad de quien lo llama. Casi siempre existe
+//! una alternativa segura que expresa mejor la intención.
+
+use std::mem::{size_of, transmute};
+use std::str::Utf8Error;
+
+// `transmute` ya lo exige al compilar, pero así la suposición queda escrita junto al código
+const _: () = assert!(size_of::<u32>() == size_of::<[u8; 4]>());
+
+/// Caso 1: `u32` → `[u8; 4]` con `transmute`, en el orden de bytes de la máquina
+// El compilador ya sugiere `to_ne_bytes`; el transmute se deja a propósito para compararlos
+#[allow(unnecessary_transmutes)]
+pub fn u32_to_bytes_transmute(value: u32) -> [u8; 4] {
+    // SAFETY: mismo tamaño (comprobado arriba) y cualquier patrón de bits
+    // es un `[u8; 4]` válido.
+    unsafe { transmute::<u32, [u8; 4]>(value) }
+}
+
+/// Caso 2: la alternativa segura; `to_ne_bytes` es exactamente lo mismo
+/// y `to_le_bytes` fija el orden para que no dependa de la máquina
+pub fn u32_to_bytes_safe(value: u32) -> [u8; 4] {
+    value.to_le_bytes()
+}
+
+/// Caso 3a: `&str` → `&[u8]` con `transmute`
+///
+/// Funciona porque `str` tiene el mismo layout que `[u8]`, pero no aporta
+/// nada frente a `as_bytes()`.
+pub fn str_to_bytes_transmute(s: &str) -> &[u8] {
+    // SAFETY: `str` y `[u8]` tienen el mismo layout y todo `str` es un
+    // `[u8]` válido; el lifetime se conserva.
+    unsafe { transmute::<&str, &[u8]>(s) }
+}
+
+/// Caso 3b: la dirección peligrosa, `&[u8]` → `&str`, validando el UTF-8
+///
+/// `transmute::<&[u8], &str>` con bytes que no son UTF-8 es
+/// comportamiento indefinido: cualquier método de `str` puede leer fuera
+/// de los límites. `from_utf8` lo comprueba y devuelve un error.
+pub fn bytes_to_str_checked(bytes: &[u8]) -> Result<&str, Utf8Error> {
+    std::str::from_utf8(bytes)
+}
+
+/// Caso 4: `bytemuck` comprueba en tiempo de compilación que el tipo
+/// admite cualquier patrón de bits (`Pod`) y ofrece casts sin `unsafe`
+#[cfg(feature = "pod-casts")]
+mod pod {
+    use bytemuck::{Pod, Zeroable};
+    
+    #[repr(C)]
+    #[derive(Debug, Clone, Copy, PartialEq, Pod, Zeroable)]
+    pub struct Pixel {
+        pub r: u8,
+        pub g: u8,
+        pub b: u8,
+        pub a: u8,
+    }
+    
+    /// Bytes de una imagen sin copiarla
+    pub fn pixels_as_bytes(pixels: &[Pixel]) -> &[u8] {
+        bytemuck::cast_slice(pixels)
+    }
+    
+    /// Como el caso 1, pero el compilador verifica que el cast es válido
+    pub fn u32_to_bytes_pod(value: u32) -> [u8; 4] {
+        bytemuck::cast(value)
+    }
+}
+
+/// Función que demuestra `transmute` frente a sus alternativas seguras
+pub fn demonstrate_transmute_safety() {
+    println!("\n✅ Demostrando transmute y alternativas seguras...");
+    
+    let value = 0x0102_0304u32;
+    println!("transmute::<u32, [u8; 4]>({:#010x}) = {:?}", value, u32_to_bytes_transmute(value));
+    println!("to_ne_bytes = {:?}, to_le_bytes = {:?}", value.to_ne_bytes(), u32_to_bytes_safe(value));
+    println!("to_be_bytes = {:?} (orden de red)", value.to_be_bytes());
+    
+    let text = "hola";
+    println!(
+        "&str -> &[u8]: transmute {:?}, as_bytes {:?}",
+        str_to_bytes_transmute(text),
+        text.as_bytes()
+    );
+    
+    // CORREGIDO: Los bytes de entrada se validan en lugar de transmutarlos a &str
+    for bytes in [&b"rust"[..], &[0xFF, 0xFE, 0x41][..]] {
+        match bytes_to_str_checked(bytes) {
+            Ok(s) => println!("{:?} es UTF-8 válido: {:?}", bytes, s),
+            Err(e) => println!("{:?} no es UTF-8: {}", bytes, e),
+        }
+    }
+    
+    #[cfg(feature = "pod-casts")]
+    {
+        let pixels = [pod::Pixel { r: 255, g: 0, b: 0, a: 255 }, pod::Pixel { r: 0, g: 128, b: 255, a: 64 }];
+        println!("Pixels como bytes (bytemuck): {:?}", pod::pixels_as_bytes(&pixels));
+        println!("bytemuck::cast::<u32, [u8; 4]> = {:?}", pod::u32_to_bytes_pod(value));
+    }
+    #[cfg(not(feature = "pod-casts"))]
+    println!("Compilar con --features pod-casts para ver los casts de bytemuck");
+}
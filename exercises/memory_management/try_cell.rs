@@ -0,0 +1,107 @@
+//! 🦀 TryCell - Mutabilidad interior sin panics
+//!
+//! `RefCell::borrow_mut` hace panic si ya hay un borrow activo.
+//! `TryCell` envuelve un `RefCell` y ofrece `try_get`/`try_get_mut`,
+//! que devuelven un `BorrowError` en lugar de abortar el programa.
+
+use std::cell::{Ref, RefCell, RefMut};
+
+/// Motivo por el que no se pudo obtener el borrow
+#[derive(Debug, Clone, PartialEq)]
+pub enum BorrowError {
+    /// Se pidió lectura pero hay una escritura activa
+    AlreadyMutablyBorrowed,
+    /// Se pidió escritura pero hay lecturas o una escritura activas
+    AlreadyBorrowed,
+}
+
+impl std::fmt::Display for BorrowError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            BorrowError::AlreadyMutablyBorrowed => write!(f, "El valor ya está prestado como mutable"),
+            BorrowError::AlreadyBorrowed => write!(f, "El valor ya está prestado"),
+        }
+    }
+}
+
+impl std::error::Error for BorrowError {}
+
+/// `RefCell` con una API que no hace panic
+#[derive(Debug)]
+pub struct TryCell<T> {
+    inner: RefCell<T>,
+}
+
+impl<T> TryCell<T> {
+    pub fn new(value: T) -> Self {
+        Self {
+            inner: RefCell::new(value),
+        }
+    }
+    
+    /// Préstamo de lectura, o error si hay una escritura activa
+    pub fn try_get(&self) -> Result<Ref<'_, T>, BorrowError> {
+        self.inner
+            .try_borrow()
+            .map_err(|_| BorrowError::AlreadyMutablyBorrowed)
+    }
+    
+    /// Préstamo de escritura, o error si hay cualquier otro borrow activo
+    pub fn try_get_mut(&self) -> Result<RefMut<'_, T>, BorrowError> {
+        self.inner
+            .try_borrow_mut()
+            .map_err(|_| BorrowError::AlreadyBorrowed)
+    }
+    
+    /// Préstamo de lectura
+    ///
+    /// # Panics
+    ///
+    /// Hace panic si el valor está prestado como mutable. Usa
+    /// `try_get` para manejar ese caso sin panic.
+    pub fn get(&self) -> Ref<'_, T> {
+        self.inner.borrow()
+    }
+    
+    /// Préstamo de escritura
+    ///
+    /// # Panics
+    ///
+    /// Hace panic si el valor ya está prestado. Usa `try_get_mut`
+    /// para manejar ese caso sin panic.
+    pub fn get_mut(&self) -> RefMut<'_, T> {
+        self.inner.borrow_mut()
+    }
+}
+
+/// Función que demuestra borrows que fallan sin panic
+pub fn demonstrate_try_cell() {
+    println!("\n✅ Demostrando TryCell (borrows sin panic)...");
+    
+    let cell = TryCell::new(vec![1, 2, 3]);
+    
+    {
+        let mut writer = cell.get_mut();
+        writer.push(4);
+        
+        // CORREGIDO: Un segundo borrow solapado devuelve Err en lugar de panic
+        match cell.try_get_mut() {
+            Ok(_) => println!("Segundo borrow mutable obtenido"),
+            Err(e) => println!("Segundo borrow mutable rechazado: {}", e),
+        }
+        match cell.try_get() {
+            Ok(_) => println!("Lectura obtenida"),
+            Err(e) => println!("Lectura rechazada: {}", e),
+        }
+    }
+    
+    // Sin borrows activos, los préstamos secuenciales funcionan
+    if let Ok(mut writer) = cell.try_get_mut() {
+        writer.push(5);
+    }
+    match cell.try_get() {
+        Ok(reader) => println!("Contenido final: {:?}", *reader),
+        Err(e) => println!("Error: {}", e),
+    }
+    println!("Longitud vía get(): {}", cell.get().len());
+}
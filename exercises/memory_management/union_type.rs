@@ -0,0 +1,158 @@
+//! 🦀 Union Type - Reinterpretar bits con `union` y dónde no hace falta
+//!
+//! Todos los campos de una `union` comparten la misma memoria: escribir
+//! uno y leer otro reinterpreta los bytes sin conversión. Leer un campo
+//! es `unsafe` porque el compilador no sabe cuál se escribió ni si los
+//! bytes son válidos para el tipo leído.
+//!
+//! Para `f32` ↔ `u32` no hace falta: `f32::to_bits` y `f32::from_bits`
+//! están en `core` (también en `no_std`) y son `const`. Una `union` sí
+//! es necesaria para declarar las `union` de una API de C en FFI, o para
+//! guardar valores de tipos distintos en el mismo espacio, como hace
+//! `MaybeUninit` por dentro. `Variant` muestra ese segundo caso con la
+//! etiqueta que la `union` no lleva.
+
+use std::fmt;
+
+/// Los mismos 4 bytes vistos como `f32` o como `u32`
+#[derive(Clone, Copy)]
+#[repr(C)]
+union FloatBits {
+    f: f32,
+    i: u32,
+}
+
+/// Bits de `f` a través de la union; equivale a `f.to_bits()`
+pub fn f32_to_bits_safe(f: f32) -> u32 {
+    let bits = FloatBits { f };
+    // SAFETY: `f32` y `u32` tienen el mismo tamaño y cualquier patrón de
+    // 32 bits es un `u32` válido.
+    unsafe { bits.i }
+}
+
+/// `f32` con los bits `b`; equivale a `f32::from_bits(b)`
+pub fn bits_to_f32_safe(b: u32) -> f32 {
+    let bits = FloatBits { i: b };
+    // SAFETY: cualquier patrón de 32 bits es un `f32` válido (algunos son NaN).
+    unsafe { bits.f }
+}
+
+/// Espacio compartido para un entero, un flotante o un booleano
+///
+/// Leer `flag` cuando se escribió `int` es comportamiento indefinido si
+/// el primer byte no es 0 ni 1: por eso la union nunca sale de `Variant`.
+#[derive(Clone, Copy)]
+#[repr(C)]
+union TaggedValue {
+    int: i64,
+    float: f64,
+    flag: bool,
+}
+
+const TAG_INT: u8 = 0;
+const TAG_FLOAT: u8 = 1;
+const TAG_FLAG: u8 = 2;
+
+/// Union etiquetada: `tag` dice qué campo de `value` es válido
+///
+/// Los campos son privados y solo los constructores fijan la etiqueta,
+/// así que nunca se desincroniza del valor. Es lo que un `enum` de Rust
+/// hace automáticamente.
+#[derive(Clone, Copy)]
+pub struct Variant {
+    tag: u8,
+    value: TaggedValue,
+}
+
+impl Variant {
+    pub fn from_int(int: i64) -> Self {
+        Self {
+            tag: TAG_INT,
+            value: TaggedValue { int },
+        }
+    }
+    
+    pub fn from_float(float: f64) -> Self {
+        Self {
+            tag: TAG_FLOAT,
+            value: TaggedValue { float },
+        }
+    }
+    
+    pub fn from_flag(flag: bool) -> Self {
+        Self {
+            tag: TAG_FLAG,
+            value: TaggedValue { flag },
+        }
+    }
+    
+    pub fn as_int(&self) -> Option<i64> {
+        // Con `then_some` el campo se leería antes de comprobar la etiqueta
+        if self.tag == TAG_INT {
+            // SAFETY: con `TAG_INT` el campo escrito en el constructor fue `int`.
+            Some(unsafe { self.value.int })
+        } else {
+            None
+        }
+    }
+    
+    pub fn as_float(&self) -> Option<f64> {
+        if self.tag == TAG_FLOAT {
+            // SAFETY: con `TAG_FLOAT` el campo escrito en el constructor fue `float`.
+            Some(unsafe { self.value.float })
+        } else {
+            None
+        }
+    }
+    
+    pub fn as_flag(&self) -> Option<bool> {
+        if self.tag == TAG_FLAG {
+            // SAFETY: con `TAG_FLAG` el campo escrito fue `flag`, así que el byte es 0 o 1.
+            Some(unsafe { self.value.flag })
+        } else {
+            None
+        }
+    }
+}
+
+impl fmt::Debug for Variant {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(int) = self.as_int() {
+            write!(f, "Variant::Int({})", int)
+        } else if let Some(float) = self.as_float() {
+            write!(f, "Variant::Float({})", float)
+        } else if let Some(flag) = self.as_flag() {
+            write!(f, "Variant::Flag({})", flag)
+        } else {
+            write!(f, "Variant::<tag {}>", self.tag)
+        }
+    }
+}
+
+/// Función que demuestra type punning con `union` frente a `to_bits`
+pub fn demonstrate_union_type() {
+    println!("\n✅ Demostrando union para reinterpretar bits...");
+    
+    for value in [1.0f32, -0.0, f32::INFINITY, f32::NAN] {
+        let bits = f32_to_bits_safe(value);
+        println!(
+            "{:>5} -> {:#010x} (to_bits: {:#010x}), de vuelta: {}",
+            value,
+            bits,
+            value.to_bits(),
+            bits_to_f32_safe(bits)
+        );
+    }
+    
+    // CORREGIDO: La etiqueta decide qué campo se lee; nunca se adivina
+    let values = [Variant::from_int(42), Variant::from_float(2.5), Variant::from_flag(true)];
+    for variant in &values {
+        println!("{:?}: as_int = {:?}, as_float = {:?}", variant, variant.as_int(), variant.as_float());
+    }
+    // Un enum con los mismos tres casos también ocupa 16 bytes: 8 de valor y la etiqueta con relleno
+    println!(
+        "Tamaño: union {} bytes, Variant {} bytes",
+        std::mem::size_of::<TaggedValue>(),
+        std::mem::size_of::<Variant>()
+    );
+}
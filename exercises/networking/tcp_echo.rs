@@ -0,0 +1,149 @@
+//! 🦀 TCP Echo - Servidor de eco con `std::net::TcpListener`
+//!
+//! Cada conexión se atiende en su propio thread, que lee líneas con
+//! `BufReader` y devuelve cada una tal cual. El listener es no bloqueante
+//! y el bucle de `accept` consulta un `AtomicBool`, así que el servidor
+//! se puede parar limpiamente desde otro thread sin matar el proceso.
+//!
+//! Uso: `cargo run --bin tcp_echo -- 127.0.0.1:7878` y en otra terminal
+//! `nc 127.0.0.1 7878`. Sin argumentos se ejecuta una demo con un
+//! cliente en el mismo proceso.
+
+use std::io::{self, BufRead, BufReader, ErrorKind, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+/// Cada cuánto se vuelve a mirar el flag de parada mientras no pasa nada
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Escucha en `addr` y hace eco hasta que `stop` pase a `true`
+pub fn run_echo_server(addr: &str, stop: Arc<AtomicBool>) -> io::Result<()> {
+    let listener = TcpListener::bind(addr)?;
+    println!("Servidor de eco escuchando en {}", listener.local_addr()?);
+    serve_echo(listener, stop)
+}
+
+/// Acepta conexiones en `listener` hasta que `stop` pase a `true`
+///
+/// Separado de `run_echo_server` para poder enlazar antes a `127.0.0.1:0`
+/// y saber qué puerto asignó el sistema.
+pub fn serve_echo(listener: TcpListener, stop: Arc<AtomicBool>) -> io::Result<()> {
+    // CORREGIDO: Un accept bloqueante nunca volvería a mirar el flag de parada
+    listener.set_nonblocking(true)?;
+    let mut connections = Vec::new();
+    
+    while !stop.load(Ordering::Acquire) {
+        match listener.accept() {
+            Ok((stream, peer)) => {
+                let stop = Arc::clone(&stop);
+                connections.push(thread::spawn(move || {
+                    if let Err(e) = handle_connection(stream, &stop) {
+                        eprintln!("Conexión con {} terminada con error: {}", peer, e);
+                    }
+                }));
+            }
+            Err(e) if e.kind() == ErrorKind::WouldBlock => thread::sleep(POLL_INTERVAL),
+            Err(e) => return Err(e),
+        }
+        connections.retain(|handle| !handle.is_finished());
+    }
+    
+    // Las conexiones también miran el flag, así que terminan en como mucho un POLL_INTERVAL
+    for handle in connections {
+        let _ = handle.join();
+    }
+    Ok(())
+}
+
+/// Devuelve cada línea recibida hasta que el cliente cierra o se pide parar
+fn handle_connection(stream: TcpStream, stop: &AtomicBool) -> io::Result<()> {
+    // En algunas plataformas el socket aceptado hereda el modo no bloqueante del listener
+    stream.set_nonblocking(false)?;
+    // Un timeout en la lectura deja mirar el flag aunque el cliente no envíe nada
+    stream.set_read_timeout(Some(POLL_INTERVAL))?;
+    let mut writer = stream.try_clone()?;
+    let mut reader = BufReader::new(stream);
+    let mut line = Vec::new();
+    
+    loop {
+        // read_until conserva en `line` lo leído antes de un timeout, así que no se pierden bytes
+        match reader.read_until(b'\n', &mut line) {
+            Ok(0) => return Ok(()),
+            Ok(_) => {
+                writer.write_all(&line)?;
+                line.clear();
+            }
+            Err(e) if matches!(e.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) => {
+                if stop.load(Ordering::Acquire) {
+                    return Ok(());
+                }
+            }
+            Err(e) if e.kind() == ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+/// Cliente de prueba: envía cada mensaje como una línea y devuelve las respuestas
+pub fn test_echo_client(addr: &str, messages: &[&str]) -> io::Result<Vec<String>> {
+    let mut stream = TcpStream::connect(addr)?;
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let mut replies = Vec::with_capacity(messages.len());
+    
+    for message in messages {
+        writeln!(stream, "{}", message)?;
+        let mut reply = String::new();
+        if reader.read_line(&mut reply)? == 0 {
+            return Err(io::Error::new(ErrorKind::UnexpectedEof, "el servidor cerró la conexión"));
+        }
+        replies.push(reply.trim_end_matches('\n').to_string());
+    }
+    Ok(replies)
+}
+
+/// Función que demuestra un servidor de eco con parada limpia
+fn demonstrate_tcp_echo() -> io::Result<()> {
+    println!("\n✅ Demostrando servidor TCP de eco...");
+    
+    // Puerto 0: el sistema elige uno libre
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+    let addr = listener.local_addr()?.to_string();
+    let stop = Arc::new(AtomicBool::new(false));
+    
+    let server = {
+        let stop = Arc::clone(&stop);
+        thread::spawn(move || serve_echo(listener, stop))
+    };
+    
+    let replies = test_echo_client(&addr, &["hola", "mundo", "🦀 ferris"])?;
+    println!("Respuestas de {}: {:?}", addr, replies);
+    
+    stop.store(true, Ordering::Release);
+    server.join().expect("el thread del servidor no debería hacer panic")?;
+    println!("Servidor parado limpiamente");
+    Ok(())
+}
+
+fn main() -> io::Result<()> {
+    println!("🦀 Rust Lab - Servidor TCP de eco");
+    println!("{}", "=".repeat(60));
+    
+    if let Some(addr) = std::env::args().nth(1) {
+        // Sin nadie que active el flag, sirve hasta que se interrumpa el proceso
+        return run_echo_server(&addr, Arc::new(AtomicBool::new(false)));
+    }
+    
+    demonstrate_tcp_echo()?;
+    
+    println!("\n✅ Todas las demostraciones completadas sin errores!");
+    println!("🎯 Conceptos clave demostrados:");
+    println!("   - TcpListener / TcpStream: Sockets TCP de la biblioteca estándar");
+    println!("   - Un thread por conexión: Cada cliente se atiende por separado");
+    println!("   - BufReader: Leer el stream línea a línea");
+    println!("   - set_nonblocking + AtomicBool: Parar el bucle de accept sin matar el proceso");
+    println!("   - Puerto 0: El sistema asigna un puerto libre");
+    Ok(())
+}
@@ -0,0 +1,122 @@
+//! 🦀 UDP Multicast - Un emisor, todos los miembros del grupo reciben
+//!
+//! Con TCP cada cliente tiene su propia conexión. Con UDP multicast el
+//! emisor envía un solo datagrama a una dirección de grupo (224.0.0.0/4)
+//! y el sistema lo entrega a cada socket que se haya unido al grupo con
+//! `join_multicast_v4`. El TTL limita cuántos routers puede atravesar:
+//! con 1 no sale de la red local.
+//!
+//! Uso: `cargo run --bin udp_multicast`. En redes o contenedores sin
+//! multicast la demo lo detecta y lo indica en lugar de fallar.
+
+use std::io::{self, ErrorKind};
+use std::net::{Ipv4Addr, UdpSocket};
+use std::thread;
+use std::time::Duration;
+
+/// Cuánto espera el receptor antes de rendirse
+const RECEIVE_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Saltos máximos de los datagramas enviados; 1 = solo la red local
+const MULTICAST_TTL: u32 = 1;
+
+fn parse_group(group: &str) -> io::Result<Ipv4Addr> {
+    let addr: Ipv4Addr = group
+        .parse()
+        .map_err(|e| io::Error::new(ErrorKind::InvalidInput, format!("'{}': {}", group, e)))?;
+    if !addr.is_multicast() {
+        return Err(io::Error::new(
+            ErrorKind::InvalidInput,
+            format!("{} no es una dirección multicast (224.0.0.0/4)", addr),
+        ));
+    }
+    Ok(addr)
+}
+
+/// Envía `message` una vez al grupo `group:port`
+pub fn run_multicast_sender(group: &str, port: u16, message: &str) -> io::Result<()> {
+    let group = parse_group(group)?;
+    let socket = UdpSocket::bind("0.0.0.0:0")?;
+    // IP_MULTICAST_TTL: std elige la opción de socket adecuada para cada plataforma
+    socket.set_multicast_ttl_v4(MULTICAST_TTL)?;
+    // IP_MULTICAST_LOOP: los receptores de esta misma máquina también lo reciben
+    socket.set_multicast_loop_v4(true)?;
+    socket.send_to(message.as_bytes(), (group, port))?;
+    Ok(())
+}
+
+/// Socket unido a `group` en `port` (0 = puerto libre), listo para recibir
+pub fn join_multicast_group(group: &str, port: u16) -> io::Result<UdpSocket> {
+    let group = parse_group(group)?;
+    let socket = UdpSocket::bind((Ipv4Addr::UNSPECIFIED, port))?;
+    // UNSPECIFIED: el sistema elige la interfaz según su tabla de rutas
+    socket.join_multicast_v4(&group, &Ipv4Addr::UNSPECIFIED)?;
+    socket.set_read_timeout(Some(RECEIVE_TIMEOUT))?;
+    Ok(socket)
+}
+
+/// Espera un datagrama en un socket ya unido al grupo
+pub fn receive_message(socket: &UdpSocket) -> io::Result<String> {
+    let mut buf = [0u8; 1500];
+    // CORREGIDO: Con el timeout del socket, un grupo mudo da un error en lugar de colgar el thread
+    let (len, _from) = socket.recv_from(&mut buf)?;
+    String::from_utf8(buf[..len].to_vec()).map_err(|e| io::Error::new(ErrorKind::InvalidData, e))
+}
+
+/// Se une a `group:port` y devuelve el primer mensaje recibido
+pub fn run_multicast_receiver(group: &str, port: u16) -> io::Result<String> {
+    let socket = join_multicast_group(group, port)?;
+    receive_message(&socket)
+}
+
+/// Función que demuestra enviar un datagrama a un grupo multicast
+fn demonstrate_udp_multicast() -> io::Result<()> {
+    println!("\n✅ Demostrando UDP multicast...");
+    
+    const GROUP: &str = "224.0.0.1";
+    // Unirse antes de enviar: un datagrama enviado antes de unirse se pierde
+    let socket = match join_multicast_group(GROUP, 0) {
+        Ok(socket) => socket,
+        Err(e) => {
+            println!("Multicast no disponible en esta máquina: {}", e);
+            return Ok(());
+        }
+    };
+    let port = socket.local_addr()?.port();
+    println!("Receptor unido a {}:{}", GROUP, port);
+    
+    let receiver = thread::spawn(move || receive_message(&socket));
+    if let Err(e) = run_multicast_sender(GROUP, port, "hola, grupo") {
+        println!("No se pudo enviar al grupo (¿sin ruta multicast?): {}", e);
+        return Ok(());
+    }
+    
+    match receiver.join().expect("el receptor no debería hacer panic") {
+        Ok(message) => println!("Recibido: {:?}", message),
+        Err(e) if matches!(e.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) => {
+            println!("Nada recibido en {:?}: la red descarta el multicast", RECEIVE_TIMEOUT)
+        }
+        Err(e) => return Err(e),
+    }
+    
+    // Una dirección unicast no se puede usar como grupo
+    if let Err(e) = run_multicast_receiver("127.0.0.1", 0) {
+        println!("Grupo inválido rechazado: {}", e);
+    }
+    Ok(())
+}
+
+fn main() -> io::Result<()> {
+    println!("🦀 Rust Lab - UDP multicast");
+    println!("{}", "=".repeat(60));
+    
+    demonstrate_udp_multicast()?;
+    
+    println!("\n✅ Todas las demostraciones completadas sin errores!");
+    println!("🎯 Conceptos clave demostrados:");
+    println!("   - UdpSocket: Datagramas sin conexión");
+    println!("   - join_multicast_v4: Suscribirse a una dirección de grupo");
+    println!("   - set_multicast_ttl_v4: Limitar el alcance de los datagramas");
+    println!("   - set_read_timeout: No esperar para siempre a un datagrama");
+    Ok(())
+}
@@ -3,7 +3,26 @@
 //! Esta es la versión corregida del ejercicio anterior,
 //! mostrando las mejores prácticas de ownership en Rust.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+
+use rust_lab_macros::Newtype;
+
+/// ID de usuario; `#[derive(Newtype)]` genera From, Deref, Display, Hash y Eq
+#[derive(Debug, Clone, Copy, Newtype)]
+struct UserId(u32);
+
+/// ID de post: también es un u32, pero no se puede confundir con un `UserId`
+#[derive(Debug, Clone, Copy, Newtype)]
+struct PostId(u32);
+
+/// Cambio en un campo entre dos versiones de un usuario
+#[derive(Debug, Clone, PartialEq)]
+enum UserFieldChange {
+    Name { old: String, new: String },
+    Email { old: String, new: String },
+    PostsAdded(Vec<u32>),
+    PostsRemoved(Vec<u32>),
+}
 
 /// Estructura que representa un usuario con datos sensibles
 #[derive(Debug, Clone)]
@@ -47,6 +66,44 @@ impl User {
         // Mueve el String fuera del struct
         std::mem::take(&mut self.name)
     }
+    
+    /// Compara `self` (versión anterior) con `other` (versión nueva)
+    ///
+    /// Solo informa de los campos que difieren. Los valores se clonan:
+    /// el resultado no toma prestado de ninguno de los dos usuarios.
+    fn diff(&self, other: &User) -> Vec<UserFieldChange> {
+        let mut changes = Vec::new();
+        
+        if self.name != other.name {
+            changes.push(UserFieldChange::Name {
+                old: self.name.clone(),
+                new: other.name.clone(),
+            });
+        }
+        
+        if self.email != other.email {
+            changes.push(UserFieldChange::Email {
+                old: self.email.clone(),
+                new: other.email.clone(),
+            });
+        }
+        
+        // Los conjuntos solo toman prestados los IDs; el orden se conserva de los Vec originales
+        let old_posts: HashSet<&u32> = self.posts.iter().collect();
+        let new_posts: HashSet<&u32> = other.posts.iter().collect();
+        
+        let added: Vec<u32> = other.posts.iter().filter(|id| !old_posts.contains(id)).copied().collect();
+        if !added.is_empty() {
+            changes.push(UserFieldChange::PostsAdded(added));
+        }
+        
+        let removed: Vec<u32> = self.posts.iter().filter(|id| !new_posts.contains(id)).copied().collect();
+        if !removed.is_empty() {
+            changes.push(UserFieldChange::PostsRemoved(removed));
+        }
+        
+        changes
+    }
 }
 
 /// Función que demuestra ownership correcto
@@ -152,6 +209,64 @@ fn process_users_correctly() {
     }
 }
 
+/// Función que demuestra comparar dos versiones de un usuario sin moverlas
+fn demonstrate_user_diff() {
+    println!("\n✅ Demostrando auditoría de cambios con User::diff...");
+    
+    let mut before = User::new(1, "Alice".to_string(), "alice@example.com".to_string());
+    before.add_post(101);
+    before.add_post(102);
+    
+    // CORREGIDO: Se clona para editar; `before` sigue disponible para comparar
+    let mut after = before.clone();
+    after.email = "alice@rust-lab.dev".to_string();
+    after.posts.retain(|&id| id != 101);
+    after.add_post(103);
+    
+    for change in before.diff(&after) {
+        match change {
+            UserFieldChange::Name { old, new } => println!("Nombre: {} -> {}", old, new),
+            UserFieldChange::Email { old, new } => println!("Email: {} -> {}", old, new),
+            UserFieldChange::PostsAdded(ids) => println!("Posts añadidos: {:?}", ids),
+            UserFieldChange::PostsRemoved(ids) => println!("Posts eliminados: {:?}", ids),
+        }
+    }
+    
+    println!("Sin cambios: {:?}", after.diff(&after.clone()));
+}
+
+/// Función que demuestra IDs con tipo propio como claves de HashMap
+fn demonstrate_newtype_ids() {
+    println!("\n✅ Demostrando UserId y PostId con #[derive(Newtype)]...");
+    
+    let mut alice = User::new(1, "Alice".to_string(), "alice@example.com".to_string());
+    alice.add_post(101);
+    alice.add_post(102);
+    let mut bob = User::new(2, "Bob".to_string(), "bob@example.com".to_string());
+    bob.add_post(201);
+    
+    // Índice de autores: un PostId lleva a un UserId, y el tipo impide mezclarlos
+    let mut authors: HashMap<PostId, UserId> = HashMap::new();
+    for user in [&alice, &bob] {
+        for &post in &user.posts {
+            authors.insert(PostId::from(post), UserId::from(user.id));
+        }
+    }
+    
+    let users: HashMap<UserId, User> = [alice, bob].into_iter().map(|user| (UserId(user.id), user)).collect();
+    
+    let post = PostId(102);
+    if let Some(author) = authors.get(&post) {
+        // CORREGIDO: `authors.get(author)` no compila; con u32 en las dos claves sí, y devolvería None
+        println!("Post {} escrito por el usuario {} ({})", post, author, users[author].get_name());
+    }
+    
+    // Deref y From devuelven el u32 cuando hace falta operar con él
+    let next_id = UserId::from(*UserId(2) + 1);
+    println!("Siguiente ID libre: {} (u32: {})", next_id, u32::from(next_id));
+    println!("¿Existe el usuario {}? {}", next_id, users.contains_key(&next_id));
+}
+
 fn main() {
     println!("🦀 Rust Lab - Ownership & Borrowing SOLUCIÓN CORRECTA");
     println!("{}", "=".repeat(60));
@@ -160,6 +275,8 @@ fn main() {
     demonstrate_borrowing_correct();
     demonstrate_lifetime_correct();
     process_users_correctly();
+    demonstrate_user_diff();
+    demonstrate_newtype_ids();
     
     println!("\n✅ Todas las demostraciones completadas sin errores de compilación!");
     println!("🎯 Conceptos clave demostrados:");
@@ -167,4 +284,6 @@ fn main() {
     println!("   - Borrowing: referencias inmutables y mutables");
     println!("   - Lifetimes: duración de las referencias");
     println!("   - Move semantics: transferencia de ownership");
+    println!("   - Diff: comparar por referencia y devolver valores propios");
+    println!("   - Newtypes: UserId y PostId generados con #[derive(Newtype)]");
 }
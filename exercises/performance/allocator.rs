@@ -0,0 +1,82 @@
+//! 🦀 Counting Allocator - Medición de memoria en el heap
+//!
+//! Allocator global que delega en `System` y cuenta cada allocation
+//! con atomics, para que los ejercicios muestren uso de memoria
+//! además de tiempos.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Allocator que envuelve `System` y lleva contadores de uso
+pub struct CountingAllocator {
+    allocated_bytes: AtomicUsize,
+    freed_bytes: AtomicUsize,
+    allocation_count: AtomicUsize,
+}
+
+impl CountingAllocator {
+    pub const fn new() -> Self {
+        Self {
+            allocated_bytes: AtomicUsize::new(0),
+            freed_bytes: AtomicUsize::new(0),
+            allocation_count: AtomicUsize::new(0),
+        }
+    }
+    
+    fn record_alloc(&self, size: usize) {
+        self.allocated_bytes.fetch_add(size, Ordering::Relaxed);
+        self.allocation_count.fetch_add(1, Ordering::Relaxed);
+    }
+    
+    fn record_free(&self, size: usize) {
+        self.freed_bytes.fetch_add(size, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            self.record_alloc(layout.size());
+        }
+        ptr
+    }
+    
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+        if !ptr.is_null() {
+            self.record_alloc(layout.size());
+        }
+        ptr
+    }
+    
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        self.record_free(layout.size());
+    }
+    
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        // Un realloc cuenta como liberar el bloque viejo y reservar uno nuevo
+        let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new_ptr.is_null() {
+            self.record_free(layout.size());
+            self.record_alloc(new_size);
+        }
+        new_ptr
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator::new();
+
+/// Bytes actualmente reservados en el heap (reservados - liberados)
+pub fn current_heap_bytes() -> usize {
+    let freed = GLOBAL.freed_bytes.load(Ordering::Relaxed);
+    let allocated = GLOBAL.allocated_bytes.load(Ordering::Relaxed);
+    allocated.saturating_sub(freed)
+}
+
+/// Número total de allocations realizadas desde el arranque
+pub fn allocation_count() -> usize {
+    GLOBAL.allocation_count.load(Ordering::Relaxed)
+}
@@ -0,0 +1,241 @@
+//! 🦀 Base64 - Codificar bytes como texto ASCII (RFC 4648)
+//!
+//! Cada grupo de 3 bytes (24 bits) se parte en 4 índices de 6 bits sobre
+//! un alfabeto de 64 caracteres. Si al final sobran 1 o 2 bytes, el grupo
+//! se completa con ceros y se rellena con `=` hasta 4 caracteres:
+//!
+//! ```text
+//! "f"   -> "Zg=="
+//! "fo"  -> "Zm8="
+//! "foo" -> "Zm9v"
+//! ```
+//!
+//! Las dos direcciones usan tablas, sin ramas por carácter; la de
+//! decodificación se calcula en tiempo de compilación.
+
+use std::fmt;
+use std::io::{self, Read, Write};
+use std::time::Instant;
+
+use super::hyperloglog::xorshift;
+
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const PAD: u8 = b'=';
+
+/// Marca de "no pertenece al alfabeto" en `DECODE_TABLE`
+const INVALID: u8 = 0xFF;
+
+/// Valor de 6 bits de cada byte, o `INVALID`
+const DECODE_TABLE: [u8; 256] = {
+    let mut table = [INVALID; 256];
+    let mut i = 0;
+    while i < ALPHABET.len() {
+        table[ALPHABET[i] as usize] = i as u8;
+        i += 1;
+    }
+    table
+};
+
+/// Bytes de entrada por bloque en `encode_base64_stream`; múltiplo de 3 para no rellenar a mitad
+const STREAM_CHUNK: usize = 3072;
+
+/// Errores al decodificar base64
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Base64Error {
+    /// La longitud no es múltiplo de 4
+    LengthNotMultipleOf4(usize),
+    /// `byte` en la posición `offset` no está en el alfabeto
+    InvalidCharacter { byte: u8, offset: usize },
+    /// Un `=` fuera de las dos últimas posiciones, o seguido de algo que no es `=`
+    InvalidPadding { offset: usize },
+}
+
+impl fmt::Display for Base64Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Base64Error::LengthNotMultipleOf4(len) => write!(f, "Longitud {} no es múltiplo de 4", len),
+            Base64Error::InvalidCharacter { byte, offset } => {
+                write!(f, "Carácter inválido {:?} en la posición {}", *byte as char, offset)
+            }
+            Base64Error::InvalidPadding { offset } => write!(f, "Relleno inválido en la posición {}", offset),
+        }
+    }
+}
+
+impl std::error::Error for Base64Error {}
+
+/// Añade a `out` la codificación de `data`, con relleno si hace falta
+fn encode_into(data: &[u8], out: &mut Vec<u8>) {
+    let chunks = data.chunks_exact(3);
+    let rest = chunks.remainder();
+    
+    for chunk in chunks {
+        let group = (chunk[0] as u32) << 16 | (chunk[1] as u32) << 8 | chunk[2] as u32;
+        out.extend_from_slice(&[
+            ALPHABET[(group >> 18) as usize & 0x3F],
+            ALPHABET[(group >> 12) as usize & 0x3F],
+            ALPHABET[(group >> 6) as usize & 0x3F],
+            ALPHABET[group as usize & 0x3F],
+        ]);
+    }
+    
+    match *rest {
+        [] => {}
+        [a] => {
+            let group = (a as u32) << 16;
+            out.extend_from_slice(&[
+                ALPHABET[(group >> 18) as usize],
+                ALPHABET[(group >> 12) as usize & 0x3F],
+                PAD,
+                PAD,
+            ]);
+        }
+        [a, b] => {
+            let group = (a as u32) << 16 | (b as u32) << 8;
+            out.extend_from_slice(&[
+                ALPHABET[(group >> 18) as usize],
+                ALPHABET[(group >> 12) as usize & 0x3F],
+                ALPHABET[(group >> 6) as usize & 0x3F],
+                PAD,
+            ]);
+        }
+        _ => unreachable!("chunks_exact(3) deja como mucho 2 bytes"),
+    }
+}
+
+/// Longitud exacta de la salida: 4 caracteres por cada grupo de 3 bytes, empezado o no
+fn encoded_len(input_len: usize) -> usize {
+    input_len.div_ceil(3) * 4
+}
+
+/// Codifica `data` en base64 estándar con relleno
+pub fn encode_base64(data: &[u8]) -> String {
+    let mut out = Vec::with_capacity(encoded_len(data.len()));
+    encode_into(data, &mut out);
+    // Todo lo que sale de ALPHABET y PAD es ASCII
+    String::from_utf8(out).expect("base64 siempre es ASCII")
+}
+
+/// Decodifica base64 estándar con relleno obligatorio
+///
+/// No se aceptan espacios ni saltos de línea: cualquier byte fuera del
+/// alfabeto es `InvalidCharacter`.
+pub fn decode_base64(encoded: &str) -> Result<Vec<u8>, Base64Error> {
+    let bytes = encoded.as_bytes();
+    if !bytes.len().is_multiple_of(4) {
+        return Err(Base64Error::LengthNotMultipleOf4(bytes.len()));
+    }
+    
+    // El relleno solo puede ocupar las dos últimas posiciones
+    let padding = bytes.iter().rev().take(2).take_while(|&&b| b == PAD).count();
+    let body = &bytes[..bytes.len() - padding];
+    let mut decoded = Vec::with_capacity(bytes.len() / 4 * 3);
+    let mut group = 0u32;
+    
+    for (offset, &byte) in body.iter().enumerate() {
+        let value = DECODE_TABLE[byte as usize];
+        if value == INVALID {
+            // CORREGIDO: Un '=' en medio es un relleno mal puesto, no un carácter desconocido
+            return Err(if byte == PAD {
+                Base64Error::InvalidPadding { offset }
+            } else {
+                Base64Error::InvalidCharacter { byte, offset }
+            });
+        }
+        group = group << 6 | value as u32;
+        if offset % 4 == 3 {
+            decoded.extend_from_slice(&group.to_be_bytes()[1..]);
+            group = 0;
+        }
+    }
+    
+    // Con relleno, el último grupo tiene 3 (un `=`) o 2 (dos `=`) caracteres válidos;
+    // "x===" no llega aquí porque su tercer carácter es un `=` fuera de sitio
+    match padding {
+        0 => {}
+        1 => decoded.extend_from_slice(&(group << 6).to_be_bytes()[1..3]),
+        _ => decoded.push((group >> 4) as u8),
+    }
+    Ok(decoded)
+}
+
+/// Lee de `reader` hasta llenar `buf` o llegar al final; devuelve cuántos bytes leyó
+fn fill_chunk(reader: &mut impl Read, buf: &mut [u8]) -> io::Result<usize> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        match reader.read(&mut buf[filled..]) {
+            Ok(0) => break,
+            Ok(n) => filled += n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(filled)
+}
+
+/// Codifica todo `reader` en `writer` en bloques de 3072 bytes
+///
+/// Devuelve cuántos bytes escribió. Cada bloque se completa antes de
+/// codificarlo, porque un `read` corto en medio del flujo metería relleno
+/// donde no toca.
+pub fn encode_base64_stream(reader: &mut impl Read, writer: &mut impl Write) -> io::Result<usize> {
+    let mut chunk = [0u8; STREAM_CHUNK];
+    let mut out = Vec::with_capacity(encoded_len(STREAM_CHUNK));
+    let mut written = 0;
+    
+    loop {
+        let len = fill_chunk(reader, &mut chunk)?;
+        if len == 0 {
+            return Ok(written);
+        }
+        out.clear();
+        encode_into(&chunk[..len], &mut out);
+        writer.write_all(&out)?;
+        written += out.len();
+        if len < STREAM_CHUNK {
+            return Ok(written);
+        }
+    }
+}
+
+/// Función que demuestra codificar y decodificar 1 MB en base64
+pub fn demonstrate_base64() {
+    println!("\n✅ Demostrando base64...");
+    
+    for text in ["", "f", "fo", "foo", "foob", "fooba", "foobar"] {
+        println!("{:?} -> {:?}", text, encode_base64(text.as_bytes()));
+    }
+    
+    let mut state = 0x853C_49E6_748F_EA9B;
+    let data: Vec<u8> = (0..1024 * 1024).map(|_| xorshift(&mut state) as u8).collect();
+    
+    let start = Instant::now();
+    let encoded = encode_base64(&data);
+    let encode_time = start.elapsed();
+    let start = Instant::now();
+    let decoded = decode_base64(&encoded);
+    let decode_time = start.elapsed();
+    println!(
+        "1 MB aleatorio: {} caracteres, codificado en {:?}, decodificado en {:?}",
+        encoded.len(),
+        encode_time,
+        decode_time
+    );
+    println!("Ida y vuelta correcta: {}", decoded.is_ok_and(|decoded| decoded == data));
+    
+    let mut streamed = Vec::new();
+    match encode_base64_stream(&mut data.as_slice(), &mut streamed) {
+        Ok(written) => println!(
+            "Streaming: {} bytes escritos, igual que en memoria: {}",
+            written,
+            streamed == encoded.as_bytes()
+        ),
+        Err(e) => println!("Error en streaming: {}", e),
+    }
+    
+    for bad in ["Zm9", "Zm9v!A==", "Z===", "Zg==Zm8="] {
+        if let Err(e) = decode_base64(bad) {
+            println!("{:?} rechazado: {}", bad, e);
+        }
+    }
+}
@@ -0,0 +1,110 @@
+//! 🦀 Batch Processor - Agrupar trabajo para pagar el coste fijo una vez
+//!
+//! Escribir en una base de datos, enviar por red o hacer `fsync` tiene un
+//! coste fijo por llamada mucho mayor que el coste por elemento. Juntando
+//! elementos en lotes ese coste se reparte, pero un lote que nunca se
+//! llena no debería esperar para siempre: se vacía al llegar a
+//! `max_items` o cuando el elemento más antiguo lleva `max_delay` en cola.
+
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Cola de elementos que se entregan en lotes por tamaño o por tiempo
+#[derive(Debug)]
+pub struct BatchProcessor<T> {
+    items: Vec<T>,
+    max_items: usize,
+    max_delay: Duration,
+    /// Cuándo entró el primer elemento del lote actual; `None` con la cola vacía
+    first_queued: Option<Instant>,
+}
+
+impl<T> BatchProcessor<T> {
+    /// `max_items` se trata como mínimo 1: un lote vacío nunca está listo
+    pub fn new(max_items: usize, max_delay: Duration) -> Self {
+        let max_items = max_items.max(1);
+        Self {
+            items: Vec::with_capacity(max_items),
+            max_items,
+            max_delay,
+            first_queued: None,
+        }
+    }
+    
+    pub fn push(&mut self, item: T) {
+        // CORREGIDO: El plazo empieza con el primer elemento, no con la última entrega
+        self.first_queued.get_or_insert_with(Instant::now);
+        self.items.push(item);
+    }
+    
+    /// Entrega hasta `max_items` elementos si el lote está lleno o ha vencido el plazo
+    pub fn flush_ready(&mut self) -> Option<Vec<T>> {
+        let full = self.items.len() >= self.max_items;
+        let expired = self.first_queued.is_some_and(|first| first.elapsed() >= self.max_delay);
+        if !full && !expired {
+            return None;
+        }
+        
+        let rest = self.items.split_off(self.max_items.min(self.items.len()));
+        let batch = std::mem::replace(&mut self.items, rest);
+        // Lo que sobra ya estaba esperando: conserva el instante del lote anterior
+        if self.items.is_empty() {
+            self.first_queued = None;
+        }
+        Some(batch)
+    }
+    
+    /// Entrega lo que quede, lleno o no; para vaciar la cola al terminar
+    pub fn flush(&mut self) -> Option<Vec<T>> {
+        if self.items.is_empty() {
+            return None;
+        }
+        self.first_queued = None;
+        Some(std::mem::take(&mut self.items))
+    }
+}
+
+/// Simula una escritura con coste fijo por llamada y un coste pequeño por elemento
+fn write_batch(items: &[u32]) -> u64 {
+    thread::sleep(Duration::from_micros(200));
+    items.iter().map(|&item| item as u64).sum()
+}
+
+/// Función que demuestra repartir el coste fijo de una escritura entre muchos elementos
+pub fn demonstrate_batch_processor() {
+    println!("\n✅ Demostrando BatchProcessor...");
+    
+    let items: Vec<u32> = (1..=2_000).collect();
+    
+    let start = Instant::now();
+    let one_by_one: u64 = items.iter().map(|item| write_batch(std::slice::from_ref(item))).sum();
+    println!("Uno a uno: {} escrituras en {:?}", items.len(), start.elapsed());
+    
+    let start = Instant::now();
+    let mut processor = BatchProcessor::new(100, Duration::from_millis(50));
+    let mut batches = 0;
+    let mut batched = 0u64;
+    for &item in &items {
+        processor.push(item);
+        if let Some(batch) = processor.flush_ready() {
+            batched += write_batch(&batch);
+            batches += 1;
+        }
+    }
+    if let Some(batch) = processor.flush() {
+        batched += write_batch(&batch);
+        batches += 1;
+    }
+    println!("En lotes de 100: {} escrituras en {:?}", batches, start.elapsed());
+    println!("Mismo resultado: {}", one_by_one == batched);
+    
+    // Un productor lento nunca llena el lote: lo vacía el plazo
+    let mut processor = BatchProcessor::new(100, Duration::from_millis(50));
+    for item in 0..3 {
+        processor.push(item);
+        thread::sleep(Duration::from_millis(5));
+    }
+    println!("Antes del plazo: {:?}", processor.flush_ready());
+    thread::sleep(Duration::from_millis(50));
+    println!("Después del plazo: {:?}", processor.flush_ready());
+}
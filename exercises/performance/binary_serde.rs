@@ -0,0 +1,175 @@
+//! 🦀 Binary Serde - Formato binario compacto para `User`
+//!
+//! El CSV crea un `String` por campo al escribir y al leer. Este formato
+//! escribe los enteros directamente en little-endian y las cadenas con
+//! su longitud delante, así que leer no necesita buscar separadores:
+//!
+//! ```text
+//! id: u32 | len(name): u16 | name | len(email): u16 | email | n_posts: u32 | n_posts × u32
+//! ```
+//!
+//! Con la feature `binary-serde` los enteros pasan por `WriteBytesExt` y
+//! `ReadBytesExt` del crate `byteorder`; sin ella, por `to_le_bytes` y
+//! `from_le_bytes`. Los bytes son los mismos en los dos casos.
+
+use std::io::{self, Read, Write};
+use std::time::Instant;
+
+#[cfg(feature = "binary-serde")]
+use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
+
+use super::csv_users::{parse_users_csv, serialize_users_csv};
+use super::{DemoError, User};
+
+fn invalid_input(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, message)
+}
+
+/// Longitud de `text` en bytes como `u16`, o `InvalidInput` si no cabe
+fn str_len(field: &str, text: &str) -> io::Result<u16> {
+    u16::try_from(text.len())
+        .map_err(|_| invalid_input(format!("{} de {} bytes, el máximo es {}", field, text.len(), u16::MAX)))
+}
+
+/// Escribe `value` en little-endian
+#[cfg(feature = "binary-serde")]
+fn write_u16(writer: &mut impl Write, value: u16) -> io::Result<()> {
+    writer.write_u16::<LittleEndian>(value)
+}
+
+#[cfg(not(feature = "binary-serde"))]
+fn write_u16(writer: &mut impl Write, value: u16) -> io::Result<()> {
+    writer.write_all(&value.to_le_bytes())
+}
+
+/// Escribe `value` en little-endian
+#[cfg(feature = "binary-serde")]
+fn write_u32(writer: &mut impl Write, value: u32) -> io::Result<()> {
+    writer.write_u32::<LittleEndian>(value)
+}
+
+#[cfg(not(feature = "binary-serde"))]
+fn write_u32(writer: &mut impl Write, value: u32) -> io::Result<()> {
+    writer.write_all(&value.to_le_bytes())
+}
+
+/// Escribe un usuario y devuelve cuántos bytes ocupó
+///
+/// Falla con `InvalidInput` si `name` o `email` pasan de 65535 bytes.
+pub fn serialize_user_binary(user: &User, writer: &mut impl Write) -> io::Result<usize> {
+    // Todo se valida antes de escribir, para no dejar un usuario a medias en `writer`
+    let name_len = str_len("name", &user.name)?;
+    let email_len = str_len("email", &user.email)?;
+    let post_count = u32::try_from(user.posts.len())
+        .map_err(|_| invalid_input(format!("{} posts no caben en un u32", user.posts.len())))?;
+    
+    write_u32(writer, user.id)?;
+    write_u16(writer, name_len)?;
+    writer.write_all(user.name.as_bytes())?;
+    write_u16(writer, email_len)?;
+    writer.write_all(user.email.as_bytes())?;
+    write_u32(writer, post_count)?;
+    for &post in &user.posts {
+        write_u32(writer, post)?;
+    }
+    Ok(4 + 2 + user.name.len() + 2 + user.email.len() + 4 + 4 * user.posts.len())
+}
+
+#[cfg(not(feature = "binary-serde"))]
+fn read_array<const N: usize>(reader: &mut impl Read) -> io::Result<[u8; N]> {
+    let mut bytes = [0u8; N];
+    reader.read_exact(&mut bytes)?;
+    Ok(bytes)
+}
+
+/// Lee un `u16` en little-endian
+#[cfg(feature = "binary-serde")]
+fn read_u16(reader: &mut impl Read) -> io::Result<u16> {
+    reader.read_u16::<LittleEndian>()
+}
+
+#[cfg(not(feature = "binary-serde"))]
+fn read_u16(reader: &mut impl Read) -> io::Result<u16> {
+    read_array(reader).map(u16::from_le_bytes)
+}
+
+/// Lee un `u32` en little-endian
+#[cfg(feature = "binary-serde")]
+fn read_u32(reader: &mut impl Read) -> io::Result<u32> {
+    reader.read_u32::<LittleEndian>()
+}
+
+#[cfg(not(feature = "binary-serde"))]
+fn read_u32(reader: &mut impl Read) -> io::Result<u32> {
+    read_array(reader).map(u32::from_le_bytes)
+}
+
+fn read_str(reader: &mut impl Read) -> io::Result<String> {
+    let len = read_u16(reader)? as usize;
+    let mut bytes = vec![0u8; len];
+    reader.read_exact(&mut bytes)?;
+    String::from_utf8(bytes).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+/// Lee un usuario escrito por `serialize_user_binary`
+///
+/// Un origen que se acaba a mitad de usuario da `UnexpectedEof`; texto
+/// que no es UTF-8 da `InvalidData`.
+pub fn deserialize_user_binary(reader: &mut impl Read) -> io::Result<User> {
+    let id = read_u32(reader)?;
+    let name = read_str(reader)?;
+    let email = read_str(reader)?;
+    let post_count = read_u32(reader)? as usize;
+    
+    let mut user = User::new(id, name, email);
+    // CORREGIDO: La cuenta viene de la entrada; no se reserva más de lo que se puede leer de golpe
+    user.posts.reserve(post_count.min(1 << 16));
+    for _ in 0..post_count {
+        user.add_post(read_u32(reader)?);
+    }
+    Ok(user)
+}
+
+/// Función que demuestra el formato binario frente al CSV
+pub fn demonstrate_binary_serde() -> Result<(), DemoError> {
+    println!("\n✅ Demostrando serialización binaria de usuarios...");
+    
+    let users: Vec<User> = (1..=100_000)
+        .map(|i| {
+            let mut user = User::new(i, format!("User{}", i), format!("user{}@example.com", i));
+            for post in 0..(i % 4) {
+                user.add_post(i * 10 + post);
+            }
+            user
+        })
+        .collect();
+    
+    let start = Instant::now();
+    let csv = serialize_users_csv(&users);
+    let from_csv = parse_users_csv(&csv)?;
+    let csv_time = start.elapsed();
+    
+    let start = Instant::now();
+    let mut binary = Vec::new();
+    for user in &users {
+        serialize_user_binary(user, &mut binary)?;
+    }
+    let mut reader = binary.as_slice();
+    let mut from_binary = Vec::with_capacity(users.len());
+    while !reader.is_empty() {
+        from_binary.push(deserialize_user_binary(&mut reader)?);
+    }
+    let binary_time = start.elapsed();
+    
+    println!("CSV: {} bytes, ida y vuelta en {:?}", csv.len(), csv_time);
+    println!("Binario: {} bytes, ida y vuelta en {:?}", binary.len(), binary_time);
+    let same = from_binary.len() == from_csv.len()
+        && from_binary.iter().zip(&from_csv).all(|(a, b)| a.id == b.id && a.posts == b.posts);
+    println!("Mismos usuarios: {}", same);
+    
+    let long_name = User::new(1, "x".repeat(70_000), "a@b.c".to_string());
+    if let Err(e) = serialize_user_binary(&long_name, &mut Vec::new()) {
+        println!("Nombre demasiado largo rechazado: {}", e);
+    }
+    Ok(())
+}
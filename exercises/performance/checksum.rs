@@ -0,0 +1,197 @@
+//! 🦀 Checksum - Detectar registros `User` corruptos con CRC32
+//!
+//! Un CRC32 resume los bytes de un registro en 4 bytes: si un bit cambia
+//! en disco o por la red, el CRC recalculado ya no coincide con el
+//! guardado. No protege contra cambios intencionados (para eso hace falta
+//! un hash criptográfico), pero detecta cualquier error en un byte.
+//!
+//! Se calcula sobre el formato de `binary_serde`, escribiendo directamente
+//! en el hasher: no hace falta un `Vec` intermedio por usuario.
+
+use std::io::{self, Write};
+use std::time::Instant;
+
+use super::binary_serde::serialize_user_binary;
+use super::User;
+
+/// Polinomio de CRC-32/ISO-HDLC (zlib, PNG, Ethernet), en orden de bits invertido
+const POLYNOMIAL: u32 = 0xEDB8_8320;
+
+/// CRC de cada byte posible, calculado al compilar
+const CRC_TABLE: [u32; 256] = {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < table.len() {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 { (crc >> 1) ^ POLYNOMIAL } else { crc >> 1 };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
+/// CRC32 incremental; como `Write` se le puede pasar a cualquier serializador
+#[derive(Debug, Clone)]
+pub struct Crc32 {
+    state: u32,
+}
+
+impl Crc32 {
+    pub fn new() -> Self {
+        Self { state: u32::MAX }
+    }
+    
+    pub fn update(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            let index = (self.state ^ byte as u32) as u8;
+            self.state = (self.state >> 8) ^ CRC_TABLE[index as usize];
+        }
+    }
+    
+    pub fn finalize(&self) -> u32 {
+        !self.state
+    }
+}
+
+impl Default for Crc32 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Write for Crc32 {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+    
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// CRC32 de `bytes` de una vez
+pub fn crc32(bytes: &[u8]) -> u32 {
+    let mut hasher = Crc32::new();
+    hasher.update(bytes);
+    hasher.finalize()
+}
+
+/// CRC32 de la representación binaria de `user`
+///
+/// `serialize_user_binary` rechaza nombres o emails de más de 65535
+/// bytes antes de escribir nada; esos usuarios se resumen con el mismo
+/// orden de campos pero con las longitudes como `u64`.
+pub fn crc32_of_user(user: &User) -> u32 {
+    let mut hasher = Crc32::new();
+    if serialize_user_binary(user, &mut hasher).is_err() {
+        hasher = Crc32::new();
+        hasher.update(&user.id.to_le_bytes());
+        for text in [&user.name, &user.email] {
+            hasher.update(&(text.len() as u64).to_le_bytes());
+            hasher.update(text.as_bytes());
+        }
+        hasher.update(&(user.posts.len() as u64).to_le_bytes());
+        for post in &user.posts {
+            hasher.update(&post.to_le_bytes());
+        }
+    }
+    hasher.finalize()
+}
+
+/// Campo que `corrupt_for_testing` debe estropear
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CorruptField {
+    Id,
+    Name,
+    Email,
+    Posts,
+}
+
+/// Usuario junto al CRC32 calculado al crearlo
+#[derive(Debug, Clone)]
+pub struct ChecksummedUser {
+    pub user: User,
+    pub checksum: u32,
+}
+
+impl ChecksummedUser {
+    pub fn new(user: User) -> Self {
+        let checksum = crc32_of_user(&user);
+        Self { user, checksum }
+    }
+    
+    /// `true` si el usuario sigue teniendo el CRC con el que se guardó
+    pub fn verify(&self) -> bool {
+        crc32_of_user(&self.user) == self.checksum
+    }
+    
+    /// Cambia un byte de `field` sin actualizar el checksum, como haría un fallo de disco
+    pub fn corrupt_for_testing(&mut self, field: CorruptField) {
+        match field {
+            CorruptField::Id => self.user.id ^= 0x01,
+            CorruptField::Name => corrupt_text(&mut self.user.name),
+            CorruptField::Email => corrupt_text(&mut self.user.email),
+            CorruptField::Posts => match self.user.posts.first_mut() {
+                Some(post) => *post ^= 0x01,
+                None => self.user.posts.push(0),
+            },
+        }
+    }
+}
+
+/// Invierte el bit bajo del primer byte ASCII, que sigue siendo ASCII y por tanto UTF-8 válido
+///
+/// Sin bytes ASCII (texto vacío o todo multibyte) añade un `\0`.
+fn corrupt_text(text: &mut String) {
+    let mut bytes = std::mem::take(text).into_bytes();
+    match bytes.iter().position(u8::is_ascii) {
+        Some(index) => bytes[index] ^= 0x01,
+        None => bytes.push(0),
+    }
+    *text = String::from_utf8(bytes).expect("cambiar un byte ASCII por otro mantiene el UTF-8 válido");
+}
+
+/// Índices de los registros cuyo checksum ya no coincide
+pub fn validate_user_batch(users: &[ChecksummedUser]) -> Vec<usize> {
+    users
+        .iter()
+        .enumerate()
+        .filter(|(_, record)| !record.verify())
+        .map(|(index, _)| index)
+        .collect()
+}
+
+/// Función que demuestra detectar registros corruptos en un lote
+pub fn demonstrate_checksums() {
+    println!("\n✅ Demostrando checksums CRC32 de usuarios...");
+    
+    // Valor de comprobación estándar de CRC-32: crc32("123456789") = 0xCBF43926
+    println!("crc32(\"123456789\") = {:#010X}", crc32(b"123456789"));
+    
+    let mut records: Vec<ChecksummedUser> = (1..=50_000)
+        .map(|i| {
+            let mut user = User::new(i, format!("User{}", i), format!("user{}@example.com", i));
+            user.add_post(i * 10);
+            ChecksummedUser::new(user)
+        })
+        .collect();
+    
+    records[7].corrupt_for_testing(CorruptField::Id);
+    records[1_234].corrupt_for_testing(CorruptField::Name);
+    records[20_000].corrupt_for_testing(CorruptField::Email);
+    records[49_999].corrupt_for_testing(CorruptField::Posts);
+    
+    let start = Instant::now();
+    let corrupted = validate_user_batch(&records);
+    println!(
+        "{} registros validados en {:?}, corruptos: {:?}",
+        records.len(),
+        start.elapsed(),
+        corrupted
+    );
+}
@@ -0,0 +1,87 @@
+//! 🦀 Const Compute - Cálculos hechos por el compilador
+//!
+//! `fibonacci_const` es una `const fn`, así que el compilador puede
+//! evaluarla para rellenar `FIB_TABLE`. La tabla queda en el binario
+//! (93 × 8 = 744 bytes) y en tiempo de ejecución solo se indexa.
+
+use std::hint::black_box;
+use std::time::Instant;
+
+use super::fibonacci_optimized;
+
+/// Mayor `n` incluido en `FIB_TABLE` (su Fibonacci cabe incluso en un `i64`)
+pub const MAX_FIB_INDEX: u64 = 92;
+
+/// Fibonacci iterativo evaluable en tiempo de compilación
+///
+/// Sin recursión: la evaluación `const` limita la profundidad de
+/// llamadas. Hace overflow (error de compilación en contexto `const`)
+/// para `n > 93`; ver `checked_fibonacci` para la versión que no hace panic.
+pub const fn fibonacci_const(n: u64) -> u64 {
+    if n == 0 {
+        return 0;
+    }
+    
+    let (mut previous, mut current) = (0u64, 1u64);
+    let mut i = 1;
+    while i < n {
+        let next = previous + current;
+        previous = current;
+        current = next;
+        i += 1;
+    }
+    current
+}
+
+/// Fibonacci de 0 a `MAX_FIB_INDEX`, calculados al compilar
+pub static FIB_TABLE: [u64; 93] = {
+    let mut table = [0u64; 93];
+    let mut i = 0;
+    // CORREGIDO: `for` no está permitido en const, se usa `while`
+    while i < table.len() {
+        table[i] = fibonacci_const(i as u64);
+        i += 1;
+    }
+    table
+};
+
+/// Busca `n` en la tabla; `None` si `n > MAX_FIB_INDEX`
+pub fn lookup_fib(n: u64) -> Option<u64> {
+    FIB_TABLE.get(n as usize).copied()
+}
+
+/// Función que demuestra la tabla calculada en compilación frente al cálculo en runtime
+pub fn demonstrate_const_compute() {
+    println!("\n✅ Demostrando cálculo en tiempo de compilación...");
+    
+    const ITERATIONS: u32 = 100_000;
+    
+    let start = Instant::now();
+    let mut runtime_sum = 0u64;
+    for _ in 0..ITERATIONS {
+        runtime_sum = runtime_sum.wrapping_add(fibonacci_optimized(black_box(90)));
+    }
+    let runtime = start.elapsed();
+    
+    let start = Instant::now();
+    let mut lookup_sum = 0u64;
+    for _ in 0..ITERATIONS {
+        lookup_sum = lookup_sum.wrapping_add(lookup_fib(black_box(90)).unwrap_or(0));
+    }
+    let lookup = start.elapsed();
+    
+    println!("fibonacci_optimized(90) x {}: {:?}", ITERATIONS, runtime);
+    println!("lookup_fib(90) x {}: {:?}", ITERATIONS, lookup);
+    println!("Mismos resultados: {}", runtime_sum == lookup_sum);
+    println!(
+        "Coste en el binario: {} bytes de tabla estática",
+        std::mem::size_of_val(&FIB_TABLE)
+    );
+    println!(
+        "fib({}) = {:?}, fib({}) = {:?}",
+        MAX_FIB_INDEX,
+        lookup_fib(MAX_FIB_INDEX),
+        MAX_FIB_INDEX + 1,
+        lookup_fib(MAX_FIB_INDEX + 1)
+    );
+}
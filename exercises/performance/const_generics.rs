@@ -0,0 +1,101 @@
+//! 🦀 Const Generics - Tamaños de array como parámetros de tipo
+//!
+//! `FIB_TABLE` tiene un tamaño fijo escrito a mano. Con `const N: usize`
+//! una sola función acepta arrays de cualquier tamaño, y el compilador
+//! genera una copia por cada `N` usado: dentro de cada copia los límites
+//! de los bucles son constantes, así que puede desenrollarlos y quitar
+//! las comprobaciones de índice. Las matrices viven en la pila, sin
+//! `Vec` ni allocations, y unas dimensiones incompatibles no compilan.
+
+use std::hint::black_box;
+use std::mem::size_of_val;
+use std::ops::Add;
+use std::time::Instant;
+
+/// Suma de los elementos; un array vacío suma `T::default()`
+pub fn array_sum<T: Add<Output = T> + Copy + Default, const N: usize>(arr: [T; N]) -> T {
+    arr.into_iter().fold(T::default(), |acc, x| acc + x)
+}
+
+/// Producto de una matriz `M×N` por una `N×K`
+///
+/// Que las columnas de `a` y las filas de `b` coincidan lo comprueba el
+/// tipo: `matrix_multiply(a_2x3, a_2x3)` es un error de compilación.
+pub fn matrix_multiply<const M: usize, const N: usize, const K: usize>(
+    a: [[f64; N]; M],
+    b: [[f64; K]; N],
+) -> [[f64; K]; M] {
+    let mut result = [[0.0; K]; M];
+    for (result_row, a_row) in result.iter_mut().zip(&a) {
+        for (k, cell) in result_row.iter_mut().enumerate() {
+            *cell = a_row.iter().zip(&b).map(|(x, b_row)| x * b_row[k]).sum();
+        }
+    }
+    result
+}
+
+/// Mismo producto con las dimensiones en tiempo de ejecución y cada fila en el heap
+///
+/// # Panics
+///
+/// Si las columnas de `a` no coinciden con las filas de `b`.
+pub fn matrix_multiply_heap(a: &[Vec<f64>], b: &[Vec<f64>]) -> Vec<Vec<f64>> {
+    let columns = b.first().map_or(0, Vec::len);
+    a.iter()
+        .map(|a_row| {
+            assert_eq!(a_row.len(), b.len(), "dimensiones incompatibles");
+            (0..columns).map(|k| a_row.iter().zip(b).map(|(x, b_row)| x * b_row[k]).sum()).collect()
+        })
+        .collect()
+}
+
+/// Matriz `R×C` con valores distintos en cada celda
+fn sample_matrix<const R: usize, const C: usize>(seed: f64) -> [[f64; C]; R] {
+    let mut matrix = [[0.0; C]; R];
+    for (i, row) in matrix.iter_mut().enumerate() {
+        for (j, cell) in row.iter_mut().enumerate() {
+            *cell = seed + (i * C + j) as f64 * 0.5;
+        }
+    }
+    matrix
+}
+
+/// Función que demuestra sumas y productos de matrices con tamaños en el tipo
+pub fn demonstrate_const_generic_array_sum() {
+    println!("\n✅ Demostrando const generics para arrays y matrices...");
+    
+    println!("array_sum([1, 2, 3, 4]) = {}", array_sum([1, 2, 3, 4]));
+    println!("array_sum([0.5; 8]) = {}", array_sum([0.5; 8]));
+    println!("array_sum::<i32, 0>([]) = {}", array_sum::<i32, 0>([]));
+    
+    // Una instancia distinta por cada combinación de tipo y tamaño
+    println!("{}", std::any::type_name_of_val(&array_sum::<u8, 3>));
+    println!("{}", std::any::type_name_of_val(&matrix_multiply::<2, 3, 2>));
+    println!("{}", std::any::type_name_of_val(&matrix_multiply::<4, 4, 4>));
+    
+    let a = [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+    let b = [[7.0, 8.0], [9.0, 10.0], [11.0, 12.0]];
+    println!("[2×3] · [3×2] = {:?}", matrix_multiply(a, b));
+    
+    const ITERATIONS: usize = 1_000_000;
+    let (a, b) = (sample_matrix::<4, 4>(1.0), sample_matrix::<4, 4>(2.0));
+    println!("Matriz 4×4 en la pila: {} bytes", size_of_val(&a));
+    
+    let start = Instant::now();
+    let mut stack_total = 0.0;
+    for _ in 0..ITERATIONS {
+        stack_total += matrix_multiply(black_box(a), black_box(b))[3][3];
+    }
+    println!("[[f64; 4]; 4]:  {} productos en {:?}", ITERATIONS, start.elapsed());
+    
+    // CORREGIDO: Cada producto en el heap reserva un Vec por fila más el Vec exterior
+    let (heap_a, heap_b): (Vec<Vec<f64>>, Vec<Vec<f64>>) =
+        (a.iter().map(|row| row.to_vec()).collect(), b.iter().map(|row| row.to_vec()).collect());
+    let start = Instant::now();
+    let mut heap_total = 0.0;
+    for _ in 0..ITERATIONS {
+        heap_total += matrix_multiply_heap(black_box(&heap_a), black_box(&heap_b))[3][3];
+    }
+    println!("Vec<Vec<f64>>:  {} productos en {:?}", ITERATIONS, start.elapsed());
+    println!("Mismo resultado: {}", stack_total == heap_total);
+}
@@ -0,0 +1,179 @@
+//! 🦀 CSV Users - Lectura y escritura de usuarios en CSV
+//!
+//! Formato: una cabecera `id,name,email,posts` y una fila por usuario,
+//! con los IDs de posts separados por `;` (vacío si no tiene posts).
+//! Las funciones genéricas sobre `Write`/`BufRead` procesan el archivo
+//! fila a fila, sin cargarlo entero en memoria.
+
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::time::Instant;
+
+use super::{DemoError, User};
+
+pub const HEADER: &str = "id,name,email,posts";
+
+/// Error al leer un CSV de usuarios
+#[derive(Debug, Clone, PartialEq)]
+pub enum CsvParseError {
+    /// Fallo de lectura del origen
+    Io(String),
+    /// Campo ausente o con formato incorrecto (línea 1-based)
+    InvalidField {
+        line: usize,
+        field: &'static str,
+        message: String,
+    },
+}
+
+impl std::fmt::Display for CsvParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            CsvParseError::Io(msg) => write!(f, "Error de lectura: {}", msg),
+            CsvParseError::InvalidField { line, field, message } => {
+                write!(f, "Línea {}, campo '{}': {}", line, field, message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for CsvParseError {}
+
+/// Escribe la cabecera y una fila por usuario
+///
+/// Los campos no se escapan: `name` y `email` no deben contener comas
+/// ni saltos de línea.
+pub fn write_users_csv<W: Write>(writer: &mut W, users: &[User]) -> io::Result<()> {
+    writeln!(writer, "{}", HEADER)?;
+    for user in users {
+        write!(writer, "{},{},{},", user.id, user.name, user.email)?;
+        // CORREGIDO: Se escribe cada id directamente, sin Vec<String> intermedio
+        for (i, post) in user.posts.iter().enumerate() {
+            if i > 0 {
+                writer.write_all(b";")?;
+            }
+            write!(writer, "{}", post)?;
+        }
+        writer.write_all(b"\n")?;
+    }
+    Ok(())
+}
+
+/// Lee usuarios fila a fila; la cabecera y las líneas vacías se ignoran
+pub fn read_users_csv<R: BufRead>(reader: R) -> Result<Vec<User>, CsvParseError> {
+    let mut users = Vec::new();
+    
+    for (index, line) in reader.lines().enumerate() {
+        let line = line.map_err(|e| CsvParseError::Io(e.to_string()))?;
+        let line_number = index + 1;
+        if line.trim().is_empty() || (line_number == 1 && line == HEADER) {
+            continue;
+        }
+        users.push(parse_row(&line, line_number)?);
+    }
+    
+    Ok(users)
+}
+
+/// Serializa los usuarios a un `String` CSV
+pub fn serialize_users_csv(users: &[User]) -> String {
+    let mut buffer = Vec::new();
+    // Escribir en un Vec<u8> no puede fallar y solo se escribe UTF-8
+    write_users_csv(&mut buffer, users).expect("escribir en memoria no falla");
+    String::from_utf8(buffer).expect("el CSV generado es UTF-8")
+}
+
+/// Parsea un CSV generado por `serialize_users_csv`
+pub fn parse_users_csv(csv: &str) -> Result<Vec<User>, CsvParseError> {
+    read_users_csv(csv.as_bytes())
+}
+
+fn parse_row(line: &str, line_number: usize) -> Result<User, CsvParseError> {
+    let invalid = |field: &'static str, message: String| CsvParseError::InvalidField {
+        line: line_number,
+        field,
+        message,
+    };
+    
+    let mut columns = line.split(',');
+    let mut next = |field: &'static str| {
+        columns
+            .next()
+            .ok_or_else(|| invalid(field, "campo ausente".to_string()))
+    };
+    
+    let id_str = next("id")?;
+    let name = next("name")?;
+    let email = next("email")?;
+    let posts_str = next("posts")?;
+    if columns.next().is_some() {
+        return Err(invalid("posts", "columnas de más en la fila".to_string()));
+    }
+    
+    let id = id_str
+        .trim()
+        .parse()
+        .map_err(|_| invalid("id", format!("'{}' no es un número válido", id_str)))?;
+    
+    let mut user = User::new(id, name.to_string(), email.to_string());
+    if !posts_str.is_empty() {
+        for post in posts_str.split(';') {
+            let post_id = post
+                .trim()
+                .parse()
+                .map_err(|_| invalid("posts", format!("'{}' no es un id de post válido", post)))?;
+            user.add_post(post_id);
+        }
+    }
+    
+    Ok(user)
+}
+
+/// Función que demuestra guardar y cargar usuarios en CSV con buffers
+///
+/// Los CSV inválidos de ejemplo solo se muestran; un fallo al releer lo
+/// que se acaba de escribir es un error real y se propaga.
+pub fn demonstrate_csv_users() -> Result<(), DemoError> {
+    println!("\n✅ Demostrando serialización CSV de usuarios...");
+    
+    let mut alice = User::new(1, "Alice".to_string(), "alice@example.com".to_string());
+    alice.add_post(10);
+    alice.add_post(11);
+    let bob = User::new(2, "Bob".to_string(), "bob@example.com".to_string());
+    
+    let csv = serialize_users_csv(&[alice, bob]);
+    print!("{}", csv);
+    let users = parse_users_csv(&csv)?;
+    println!("Usuarios leídos: {}", users.len());
+    
+    // CORREGIDO: El error indica línea y campo en lugar de un unwrap que hace panic
+    if let Err(e) = parse_users_csv("id,name,email,posts\n1,Ana,ana@example.com,\nx,Luis,luis@example.com,3\n") {
+        println!("{}", e);
+    }
+    
+    // Archivos grandes: BufWriter/BufReader evitan una syscall por fila
+    let path = std::env::temp_dir().join("rust_lab_users.csv");
+    let users: Vec<User> = (1..=10_000)
+        .map(|i| {
+            let mut user = User::new(i, format!("User{}", i), format!("user{}@example.com", i));
+            user.add_post(i * 10);
+            user
+        })
+        .collect();
+    
+    let start = Instant::now();
+    let result = File::create(&path)
+        .and_then(|file| {
+            let mut writer = BufWriter::new(file);
+            write_users_csv(&mut writer, &users)?;
+            writer.flush()
+        })
+        .and_then(|_| File::open(&path))
+        .map_err(DemoError::from)
+        .and_then(|file| Ok(read_users_csv(BufReader::new(file))?));
+    let _ = fs::remove_file(&path);
+    
+    let loaded = result?;
+    println!("{} usuarios escritos y leídos en {:?}", loaded.len(), start.elapsed());
+    Ok(())
+}
@@ -0,0 +1,136 @@
+//! 🦀 Delta Encoding - Guardar diferencias en lugar de valores absolutos
+//!
+//! Los posts de un usuario son IDs ordenados y muy próximos entre sí.
+//! Cada uno ocupa 4 bytes aunque la diferencia con el anterior sea 1.
+//! Guardando el primero tal cual y después solo las diferencias, los
+//! números quedan pequeños; con varint (7 bits por byte) cualquier
+//! diferencia menor que 128 ocupa un único byte.
+
+use std::fmt;
+use std::mem::size_of;
+
+/// Errores al codificar o decodificar deltas
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeltaError {
+    /// `sorted[index]` es menor que el elemento anterior
+    NotSorted { index: usize },
+    /// El varint que empieza en `offset` está cortado o no cabe en un `u32`
+    InvalidVarint { offset: usize },
+}
+
+impl fmt::Display for DeltaError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DeltaError::NotSorted { index } => write!(f, "La entrada no está ordenada en la posición {}", index),
+            DeltaError::InvalidVarint { offset } => write!(f, "Varint inválido en el byte {}", offset),
+        }
+    }
+}
+
+impl std::error::Error for DeltaError {}
+
+/// El primer elemento se copia y el resto pasa a ser la diferencia con el anterior
+///
+/// Los duplicados son válidos (delta 0); un valor menor que el anterior no.
+pub fn delta_encode(sorted: &[u32]) -> Result<Vec<u32>, DeltaError> {
+    let mut deltas = Vec::with_capacity(sorted.len());
+    let mut previous = 0;
+    for (index, &value) in sorted.iter().enumerate() {
+        let delta = value.checked_sub(previous).ok_or(DeltaError::NotSorted { index })?;
+        deltas.push(delta);
+        previous = value;
+    }
+    Ok(deltas)
+}
+
+/// Suma prefija de los deltas; inversa de `delta_encode`
+pub fn delta_decode(deltas: &[u32]) -> Vec<u32> {
+    deltas
+        .iter()
+        .scan(0u32, |current, &delta| {
+            // Unos deltas que no salieron de `delta_encode` pueden pasarse de u32::MAX
+            *current = current.wrapping_add(delta);
+            Some(*current)
+        })
+        .collect()
+}
+
+/// Añade `value` como varint LEB128: 7 bits por byte, el bit alto indica que sigue otro
+pub fn encode_varint(mut value: u32, out: &mut Vec<u8>) {
+    while value >= 0x80 {
+        out.push((value as u8 & 0x7F) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+/// Deltas de `sorted` empaquetados como varints
+pub fn delta_encode_varint(sorted: &[u32]) -> Result<Vec<u8>, DeltaError> {
+    let deltas = delta_encode(sorted)?;
+    let mut bytes = Vec::with_capacity(deltas.len());
+    for delta in deltas {
+        encode_varint(delta, &mut bytes);
+    }
+    Ok(bytes)
+}
+
+/// Inversa de `delta_encode_varint`
+pub fn delta_decode_varint(bytes: &[u8]) -> Result<Vec<u32>, DeltaError> {
+    let mut deltas = Vec::new();
+    let mut offset = 0;
+    
+    while offset < bytes.len() {
+        let start = offset;
+        let mut value = 0u32;
+        let mut shift = 0;
+        loop {
+            let byte = *bytes.get(offset).ok_or(DeltaError::InvalidVarint { offset: start })?;
+            offset += 1;
+            // Un u32 ocupa como mucho 5 bytes y el quinto solo aporta 4 bits
+            if shift == 28 && byte > 0x0F {
+                return Err(DeltaError::InvalidVarint { offset: start });
+            }
+            value |= ((byte & 0x7F) as u32) << shift;
+            if byte & 0x80 == 0 {
+                break;
+            }
+            shift += 7;
+        }
+        deltas.push(value);
+    }
+    
+    Ok(delta_decode(&deltas))
+}
+
+/// Función que demuestra cuánto ocupan IDs secuenciales con delta + varint
+pub fn demonstrate_delta_encoding() {
+    println!("\n✅ Demostrando delta encoding de post IDs...");
+    
+    // IDs crecientes con huecos pequeños, como los posts de un usuario activo
+    let post_ids: Vec<u32> = (0..10_000u32).map(|i| 1_000_000 + i * 3 + i % 2).collect();
+    let raw_bytes = post_ids.len() * size_of::<u32>();
+    
+    match delta_encode_varint(&post_ids) {
+        Ok(encoded) => {
+            println!(
+                "{} IDs: {} bytes en crudo, {} bytes con delta + varint ({:.1}%)",
+                post_ids.len(),
+                raw_bytes,
+                encoded.len(),
+                encoded.len() as f64 / raw_bytes as f64 * 100.0
+            );
+            println!("Decodifica igual: {}", delta_decode_varint(&encoded).as_ref() == Ok(&post_ids));
+        }
+        Err(e) => println!("Error al codificar: {}", e),
+    }
+    
+    if let Ok(deltas) = delta_encode(&post_ids[..5]) {
+        println!("Primeros deltas: {:?}", deltas);
+    }
+    
+    // CORREGIDO: Una entrada desordenada es un error, no deltas que desbordan
+    match delta_encode(&[10, 20, 15]) {
+        Ok(deltas) => println!("Deltas: {:?}", deltas),
+        Err(e) => println!("Error: {}", e),
+    }
+}
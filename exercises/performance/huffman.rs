@@ -0,0 +1,195 @@
+//! 🦀 Huffman - Comprimir bytes según su frecuencia
+//!
+//! Cada byte se sustituye por un código de bits: los más frecuentes
+//! reciben códigos cortos y los raros, largos. El árbol se construye
+//! uniendo siempre los dos nodos de menor frecuencia, para lo que basta
+//! una cola de prioridad de mínimos como `MinPriorityQueue`.
+//! Ningún código es prefijo de otro, así que se decodifica bit a bit sin
+//! separadores.
+
+use std::collections::HashMap;
+
+use super::hyperloglog::xorshift;
+use super::priority_queue::MinPriorityQueue;
+
+/// Nodo del árbol de Huffman: las hojas guardan un byte, los internos dos hijos
+///
+/// `Ord` solo hace falta para meter nodos en una `MinPriorityQueue` dentro
+/// de tuplas cuyo desempate nunca llega hasta el nodo.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum HuffmanNode {
+    Leaf {
+        byte: u8,
+        frequency: usize,
+    },
+    Internal {
+        frequency: usize,
+        left: Box<HuffmanNode>,
+        right: Box<HuffmanNode>,
+    },
+}
+
+impl HuffmanNode {
+    pub fn frequency(&self) -> usize {
+        match self {
+            HuffmanNode::Leaf { frequency, .. } | HuffmanNode::Internal { frequency, .. } => *frequency,
+        }
+    }
+}
+
+/// Cuenta cuántas veces aparece cada byte
+pub fn byte_frequencies(data: &[u8]) -> HashMap<u8, usize> {
+    let mut frequencies = HashMap::new();
+    for &byte in data {
+        *frequencies.entry(byte).or_insert(0) += 1;
+    }
+    frequencies
+}
+
+/// Construye el árbol uniendo los dos nodos de menor frecuencia hasta que queda uno
+///
+/// Sin frecuencias devuelve una hoja vacía, suficiente para codificar `&[]`.
+pub fn build_huffman_tree(frequencies: &HashMap<u8, usize>) -> HuffmanNode {
+    // Las hojas se insertan en orden de byte para que el desempate sea determinista
+    let mut leaves: Vec<(u8, usize)> = frequencies.iter().map(|(&byte, &frequency)| (byte, frequency)).collect();
+    leaves.sort_unstable();
+    
+    // (frecuencia, orden de inserción, nodo): el orden es único, así que
+    // nunca se llega a comparar el nodo y a igualdad de frecuencia sale
+    // primero el más antiguo; el árbol no depende del orden del `HashMap`
+    let mut queue = MinPriorityQueue::new();
+    let mut order = 0;
+    for (byte, frequency) in leaves {
+        queue.push((frequency, order, HuffmanNode::Leaf { byte, frequency }));
+        order += 1;
+    }
+    
+    while queue.len() > 1 {
+        let (_, _, left) = queue.pop_min().unwrap();
+        let (_, _, right) = queue.pop_min().unwrap();
+        let frequency = left.frequency() + right.frequency();
+        let node = HuffmanNode::Internal {
+            frequency,
+            left: Box::new(left),
+            right: Box::new(right),
+        };
+        queue.push((frequency, order, node));
+        order += 1;
+    }
+    
+    queue
+        .pop_min()
+        .map(|(_, _, node)| node)
+        .unwrap_or(HuffmanNode::Leaf { byte: 0, frequency: 0 })
+}
+
+/// Tabla byte -> código; `false` es ir a la izquierda
+fn code_table(tree: &HuffmanNode) -> Vec<Option<Vec<bool>>> {
+    let mut table = vec![None; 256];
+    
+    // Con un solo símbolo la raíz es una hoja: se le da el código "0" para que ocupe un bit
+    if let HuffmanNode::Leaf { byte, .. } = tree {
+        table[*byte as usize] = Some(vec![false]);
+        return table;
+    }
+    
+    let mut stack = vec![(tree, Vec::new())];
+    while let Some((node, code)) = stack.pop() {
+        match node {
+            HuffmanNode::Leaf { byte, .. } => table[*byte as usize] = Some(code),
+            HuffmanNode::Internal { left, right, .. } => {
+                let mut right_code = code.clone();
+                right_code.push(true);
+                stack.push((right, right_code));
+                
+                let mut left_code = code;
+                left_code.push(false);
+                stack.push((left, left_code));
+            }
+        }
+    }
+    
+    table
+}
+
+/// Codifica `data` con los códigos de `tree`
+///
+/// Devuelve los bits empaquetados (el primero en el bit más significativo
+/// de cada byte) y cuántos son válidos: el último byte puede llevar
+/// relleno. Entra en pánico si `data` contiene un byte que no está en el árbol.
+pub fn encode(data: &[u8], tree: &HuffmanNode) -> (Vec<u8>, usize) {
+    let table = code_table(tree);
+    let mut packed = Vec::new();
+    let mut bit_count = 0;
+    
+    for &byte in data {
+        let code = table[byte as usize]
+            .as_ref()
+            .unwrap_or_else(|| panic!("el byte {} no está en el árbol de Huffman", byte));
+        for &bit in code {
+            if bit_count % 8 == 0 {
+                packed.push(0);
+            }
+            if bit {
+                *packed.last_mut().unwrap() |= 0x80 >> (bit_count % 8);
+            }
+            bit_count += 1;
+        }
+    }
+    
+    (packed, bit_count)
+}
+
+/// Recorre el árbol bit a bit; cada hoja alcanzada emite su byte
+pub fn decode(encoded: &[u8], bit_count: usize, tree: &HuffmanNode) -> Vec<u8> {
+    let bit_at = |index: usize| encoded[index / 8] & (0x80 >> (index % 8)) != 0;
+    
+    // Árbol de un solo símbolo: cada bit es una aparición del byte
+    if let HuffmanNode::Leaf { byte, .. } = tree {
+        return vec![*byte; bit_count];
+    }
+    
+    let mut decoded = Vec::new();
+    let mut node = tree;
+    for index in 0..bit_count {
+        if let HuffmanNode::Internal { left, right, .. } = node {
+            node = if bit_at(index) { right } else { left };
+        }
+        if let HuffmanNode::Leaf { byte, .. } = node {
+            decoded.push(*byte);
+            node = tree;
+        }
+    }
+    
+    decoded
+}
+
+/// Función que demuestra el ratio de compresión según la distribución de los bytes
+pub fn demonstrate_compression_ratio() {
+    println!("\n✅ Demostrando compresión Huffman...");
+    
+    let text = "el usuario publicó un nuevo post sobre ownership y borrowing en rust. "
+        .repeat(2_000)
+        .into_bytes();
+    let mut state = 0x2545_F491_4F6C_DD1D;
+    let random: Vec<u8> = (0..text.len()).map(|_| xorshift(&mut state) as u8).collect();
+    
+    for (label, data) in [("Texto repetitivo", &text), ("Bytes aleatorios", &random)] {
+        let tree = build_huffman_tree(&byte_frequencies(data));
+        let (encoded, bit_count) = encode(data, &tree);
+        let ratio = encoded.len() as f64 / data.len() as f64;
+        
+        println!(
+            "{}: {} bytes -> {} bytes ({:.1}% del original, {:.2} bits por byte)",
+            label,
+            data.len(),
+            encoded.len(),
+            ratio * 100.0,
+            bit_count as f64 / data.len() as f64
+        );
+        println!("   Decodifica igual: {}", decode(&encoded, bit_count, &tree) == *data);
+    }
+    
+    // Con 256 bytes equiprobables no hay nada que aprovechar: ~8 bits por byte
+    println!("Huffman solo comprime si la distribución no es uniforme");
+}
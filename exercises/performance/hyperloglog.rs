@@ -0,0 +1,125 @@
+//! 🦀 HyperLogLog - Contar elementos distintos con memoria fija
+//!
+//! Un `HashSet` necesita memoria proporcional al número de elementos
+//! distintos. HyperLogLog usa `2^b` registros de un byte: cada elemento
+//! se hashea, los primeros `b` bits eligen un registro y este guarda la
+//! racha de ceros más larga vista en el resto del hash. Con `b = 14`
+//! son 16 KiB y el error típico es 1.04 / √(2^14) ≈ 0.8%.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
+use std::time::Instant;
+
+/// Estimador de cardinalidad con `2^b` registros
+#[derive(Debug, Clone)]
+pub struct HyperLogLog {
+    b: u8,
+    registers: Vec<u8>,
+}
+
+impl HyperLogLog {
+    /// Crea un estimador con `2^b` registros; `b` debe estar en `4..=16`
+    pub fn new(b: u8) -> Self {
+        assert!((4..=16).contains(&b), "la precisión b debe estar entre 4 y 16, recibido {}", b);
+        Self {
+            b,
+            registers: vec![0; 1 << b],
+        }
+    }
+    
+    pub fn add(&mut self, item: &[u8]) {
+        // DefaultHasher::new() usa claves fijas: el mismo elemento siempre cae en el mismo registro
+        let mut hasher = DefaultHasher::new();
+        item.hash(&mut hasher);
+        let hash = hasher.finish();
+        
+        let index = (hash >> (64 - self.b)) as usize;
+        // Posición del primer 1 en los bits restantes; el bit centinela la limita a 64 - b + 1
+        let rest = (hash << self.b) | (1 << (self.b - 1));
+        let rank = rest.leading_zeros() as u8 + 1;
+        
+        let register = &mut self.registers[index];
+        *register = (*register).max(rank);
+    }
+    
+    /// Estimación del número de elementos distintos añadidos
+    pub fn count(&self) -> f64 {
+        let m = self.registers.len() as f64;
+        let alpha = match self.registers.len() {
+            16 => 0.673,
+            32 => 0.697,
+            64 => 0.709,
+            _ => 0.7213 / (1.0 + 1.079 / m),
+        };
+        
+        let sum: f64 = self.registers.iter().map(|&r| 2f64.powi(-(r as i32))).sum();
+        let estimate = alpha * m * m / sum;
+        
+        // Corrección para cardinalidades pequeñas: contar registros vacíos (linear counting)
+        let zeros = self.registers.iter().filter(|&&r| r == 0).count();
+        if estimate <= 2.5 * m && zeros > 0 {
+            m * (m / zeros as f64).ln()
+        } else {
+            // Con un hash de 64 bits no hace falta la corrección de rango grande
+            estimate
+        }
+    }
+    
+    /// Une otro estimador: el resultado estima la cardinalidad de la unión
+    pub fn merge(&mut self, other: &Self) {
+        assert_eq!(self.b, other.b, "solo se pueden unir estimadores con la misma precisión");
+        for (mine, theirs) in self.registers.iter_mut().zip(&other.registers) {
+            *mine = (*mine).max(*theirs);
+        }
+    }
+}
+
+/// Generador xorshift para no depender de `rand` en los ejercicios
+pub fn xorshift(state: &mut u64) -> u64 {
+    *state ^= *state << 13;
+    *state ^= *state >> 7;
+    *state ^= *state << 17;
+    *state
+}
+
+/// Función que demuestra estimar elementos distintos frente a contarlos con un HashSet
+pub fn demonstrate_hyperloglog() {
+    println!("\n✅ Demostrando HyperLogLog frente a HashSet...");
+    
+    // 1_000_000 strings elegidas al azar entre 500_000 posibles: hay repetidas
+    let mut state = 0x2545_F491_4F6C_DD1D;
+    let items: Vec<String> = (0..1_000_000)
+        .map(|_| format!("user-{}", xorshift(&mut state) % 500_000))
+        .collect();
+    
+    let start = Instant::now();
+    let exact: HashSet<&str> = items.iter().map(String::as_str).collect();
+    let exact_time = start.elapsed();
+    
+    let start = Instant::now();
+    let mut hll = HyperLogLog::new(14);
+    for item in &items {
+        hll.add(item.as_bytes());
+    }
+    let estimate = hll.count();
+    let hll_time = start.elapsed();
+    
+    let error = (estimate - exact.len() as f64).abs() / exact.len() as f64 * 100.0;
+    println!("HashSet: {} distintos en {:?}", exact.len(), exact_time);
+    println!("HyperLogLog (b=14): {:.0} estimados en {:?} (error {:.2}%)", estimate, hll_time, error);
+    println!(
+        "Memoria: {} registros de 1 byte frente a {} strings en el HashSet",
+        hll.registers.len(),
+        exact.len()
+    );
+    
+    // CORREGIDO: Los estimadores de cada partición se unen sin volver a leer los datos
+    let (first, second) = items.split_at(items.len() / 2);
+    let mut left = HyperLogLog::new(14);
+    let mut right = HyperLogLog::new(14);
+    first.iter().for_each(|item| left.add(item.as_bytes()));
+    second.iter().for_each(|item| right.add(item.as_bytes()));
+    left.merge(&right);
+    println!("Unión de dos mitades: {:.0} estimados", left.count());
+}
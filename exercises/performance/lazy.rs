@@ -0,0 +1,75 @@
+//! 🦀 Lazy - Inicialización perezosa sin crates externos
+//!
+//! Equivalente a `lazy_static!` construido sobre `std::sync::OnceLock`:
+//! el valor se calcula en el primer acceso y después solo se lee.
+
+use std::sync::OnceLock;
+use std::thread;
+use std::time::Instant;
+
+/// Valor calculado una única vez, en el primer `get()`
+pub struct Lazy<T> {
+    cell: OnceLock<T>,
+    init: fn() -> T,
+}
+
+impl<T> Lazy<T> {
+    /// `const` para poder usarlo en un `static`
+    pub const fn new(init: fn() -> T) -> Self {
+        Self {
+            cell: OnceLock::new(),
+            init,
+        }
+    }
+    
+    /// Devuelve el valor, inicializándolo si es el primer acceso
+    ///
+    /// Si varios threads llegan a la vez, solo uno ejecuta `init`
+    /// y el resto espera a su resultado.
+    pub fn get(&self) -> &T {
+        self.cell.get_or_init(self.init)
+    }
+    
+    pub fn is_initialized(&self) -> bool {
+        self.cell.get().is_some()
+    }
+}
+
+/// Tabla de cuadrados precalculada
+fn compute_squares() -> [u32; 1000] {
+    let mut table = [0u32; 1000];
+    for (i, slot) in table.iter_mut().enumerate() {
+        *slot = (i * i) as u32;
+    }
+    table
+}
+
+static SQUARES: Lazy<[u32; 1000]> = Lazy::new(compute_squares);
+
+/// Función que demuestra inicialización perezosa compartida entre threads
+pub fn demonstrate_lazy_init() {
+    println!("\n✅ Demostrando inicialización perezosa con Lazy<T>...");
+    
+    println!("¿Tabla inicializada antes del primer acceso? {}", SQUARES.is_initialized());
+    
+    // CORREGIDO: Varios threads compiten por el primer acceso, init corre una vez
+    let handles: Vec<_> = (0..4)
+        .map(|i| {
+            thread::spawn(move || {
+                let start = Instant::now();
+                let value = SQUARES.get()[i * 100];
+                (i, value, start.elapsed())
+            })
+        })
+        .collect();
+    
+    for handle in handles {
+        let (i, value, duration) = handle.join().unwrap();
+        println!("Thread {}: {}² = {} en {:?}", i, i * 100, value, duration);
+    }
+    
+    // Los accesos posteriores solo leen el valor ya calculado
+    let start = Instant::now();
+    let value = SQUARES.get()[999];
+    println!("Acceso posterior: 999² = {} en {:?}", value, start.elapsed());
+}
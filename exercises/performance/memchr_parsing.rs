@@ -0,0 +1,162 @@
+//! 🦀 Memchr Parsing - Separar campos CSV buscando bytes en lugar de caracteres
+//!
+//! Recorrer una fila `char` a `char` decodifica UTF-8 y compara cada
+//! carácter, aunque solo interesen `,` y `"`, que son ASCII. Buscar esos
+//! bytes directamente sobre `&[u8]` es correcto (en UTF-8 ningún byte de
+//! un carácter multibyte es ASCII) y, con la feature `simd-csv`,
+//! `memchr` compara 16 o 32 bytes por instrucción.
+//!
+//! Los campos son slices de la fila original y se guardan en un
+//! `SmallVec` con sitio para 8: una fila normal no reserva memoria en el
+//! heap. Las comas entre comillas no separan campos; las comillas se
+//! quedan en el slice, sin quitar ni desescapar.
+
+use std::time::Instant;
+
+use smallvec::SmallVec;
+
+/// Campos que caben en la pila antes de pasar al heap
+pub const INLINE_FIELDS: usize = 8;
+
+/// Posición del primer `,` o `"` de `haystack`
+#[cfg(feature = "simd-csv")]
+fn find_delimiter(haystack: &[u8]) -> Option<usize> {
+    memchr::memchr2(b',', b'"', haystack)
+}
+
+#[cfg(not(feature = "simd-csv"))]
+fn find_delimiter(haystack: &[u8]) -> Option<usize> {
+    haystack.iter().position(|&b| b == b',' || b == b'"')
+}
+
+/// Posición de la primera `"` de `haystack`
+#[cfg(feature = "simd-csv")]
+fn find_quote(haystack: &[u8]) -> Option<usize> {
+    memchr::memchr(b'"', haystack)
+}
+
+#[cfg(not(feature = "simd-csv"))]
+fn find_quote(haystack: &[u8]) -> Option<usize> {
+    haystack.iter().position(|&b| b == b'"')
+}
+
+/// Separa `line` por las comas que no están entre comillas
+///
+/// Una línea vacía no tiene campos. Dentro de un campo, `""` cierra y
+/// vuelve a abrir las comillas, así que una comilla escapada no cambia
+/// el resultado; una comilla sin cerrar se extiende hasta el final.
+pub fn fast_split_csv_line(line: &[u8]) -> SmallVec<[&[u8]; INLINE_FIELDS]> {
+    let mut fields = SmallVec::new();
+    if line.is_empty() {
+        return fields;
+    }
+    
+    let mut field_start = 0;
+    let mut pos = 0;
+    while let Some(offset) = find_delimiter(&line[pos..]) {
+        let index = pos + offset;
+        if line[index] == b',' {
+            fields.push(&line[field_start..index]);
+            field_start = index + 1;
+            pos = field_start;
+        } else {
+            // Se salta hasta la comilla que cierra: las comas de en medio son texto
+            match find_quote(&line[index + 1..]) {
+                Some(close) => pos = index + 1 + close + 1,
+                None => break,
+            }
+        }
+    }
+    fields.push(&line[field_start..]);
+    fields
+}
+
+/// Versión de referencia: recorre la fila carácter a carácter
+pub fn split_csv_line_chars(line: &str) -> Vec<&str> {
+    let mut fields = Vec::new();
+    if line.is_empty() {
+        return fields;
+    }
+    
+    let mut field_start = 0;
+    let mut in_quotes = false;
+    for (index, c) in line.char_indices() {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            ',' if !in_quotes => {
+                fields.push(&line[field_start..index]);
+                field_start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    fields.push(&line[field_start..]);
+    fields
+}
+
+/// Fila `id,name,email,posts`; uno de cada tres nombres lleva coma y va entre comillas
+fn user_line(id: u32) -> String {
+    let name = if id.is_multiple_of(3) {
+        format!("\"Apellido{}, Nombre{}\"", id, id)
+    } else {
+        format!("User{}", id)
+    };
+    format!("{},{},user{}@example.com,{}", id, name, id, id % 50)
+}
+
+/// Función que demuestra separar 1M de filas CSV con memchr y SmallVec frente a str::split
+pub fn demonstrate_string_view_optimization() {
+    println!("\n✅ Demostrando parseo de nombres con búsqueda de bytes...");
+    
+    const LINES: u32 = 1_000_000;
+    let mut csv = String::with_capacity(LINES as usize * 48);
+    for id in 0..LINES {
+        csv.push_str(&user_line(id));
+        csv.push('\n');
+    }
+    let lines: Vec<&str> = csv.lines().collect();
+    println!("{} filas, {} MB", lines.len(), csv.len() / 1_000_000);
+    
+    // Cada variante suma la longitud de los nombres (columna 1) para que el trabajo no se descarte
+    let start = Instant::now();
+    let split_total: usize = lines.iter().filter_map(|line| line.split(',').nth(1)).map(str::len).sum();
+    println!("str::split(','):      {:?} (parte los nombres entrecomillados)", start.elapsed());
+    
+    let start = Instant::now();
+    let chars_total: usize = lines
+        .iter()
+        .filter_map(|line| split_csv_line_chars(line).get(1).map(|name| name.len()))
+        .sum();
+    println!("char a char + Vec:    {:?}", start.elapsed());
+    
+    let start = Instant::now();
+    let fast_total: usize = lines
+        .iter()
+        .filter_map(|line| fast_split_csv_line(line.as_bytes()).get(1).map(|name| name.len()))
+        .sum();
+    println!("bytes + SmallVec:     {:?}", start.elapsed());
+    #[cfg(not(feature = "simd-csv"))]
+    println!("Compilar con --features simd-csv para buscar los bytes con memchr");
+    
+    println!(
+        "Bytes de nombres: split {}, char a char {}, bytes {} (iguales: {})",
+        split_total,
+        chars_total,
+        fast_total,
+        chars_total == fast_total
+    );
+    
+    let first_quoted = user_line(3);
+    let fields = fast_split_csv_line(first_quoted.as_bytes());
+    let name = String::from_utf8_lossy(fields[1]);
+    println!("{} -> nombre {}, {} campos", first_quoted, name, fields.len());
+    
+    let wide = "a,b,c,d,e,f,g,h,i";
+    println!(
+        "{} campos en la pila: {}, {} campos en la pila: {}",
+        fields.len(),
+        !fields.spilled(),
+        wide.split(',').count(),
+        !fast_split_csv_line(wide.as_bytes()).spilled()
+    );
+}
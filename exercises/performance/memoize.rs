@@ -0,0 +1,151 @@
+//! 🦀 Memoize - Memoización genérica de funciones
+//!
+//! `fibonacci_optimized` lleva su memoización escrita a mano. `MemoizedFn`
+//! envuelve cualquier función pura y cachea sus resultados por argumento.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+/// Función envuelta con una caché de resultados
+pub struct MemoizedFn<K: Hash + Eq, V: Clone> {
+    func: Box<dyn Fn(K) -> V>,
+    cache: HashMap<K, V>,
+    hits: usize,
+    misses: usize,
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> MemoizedFn<K, V> {
+    pub fn new(f: impl Fn(K) -> V + 'static) -> Self {
+        Self {
+            func: Box::new(f),
+            cache: HashMap::new(),
+            hits: 0,
+            misses: 0,
+        }
+    }
+    
+    /// Devuelve el resultado cacheado o llama a la función una sola vez
+    pub fn call(&mut self, arg: K) -> V {
+        if let Some(value) = self.cache.get(&arg) {
+            self.hits += 1;
+            return value.clone();
+        }
+        
+        self.misses += 1;
+        let value = (self.func)(arg.clone());
+        self.cache.insert(arg, value.clone());
+        value
+    }
+    
+    /// Proporción de llamadas servidas desde la caché
+    pub fn hit_rate(&self) -> f64 {
+        hit_rate(self.hits, self.misses)
+    }
+    
+    pub fn cache_len(&self) -> usize {
+        self.cache.len()
+    }
+}
+
+/// Variante compartible entre threads con la caché en un `Mutex`
+pub struct ThreadSafeMemoizedFn<K: Hash + Eq, V: Clone> {
+    func: Box<dyn Fn(K) -> V + Send + Sync>,
+    cache: Mutex<HashMap<K, V>>,
+    hits: AtomicUsize,
+    misses: AtomicUsize,
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> ThreadSafeMemoizedFn<K, V> {
+    pub fn new(f: impl Fn(K) -> V + Send + Sync + 'static) -> Self {
+        Self {
+            func: Box::new(f),
+            cache: Mutex::new(HashMap::new()),
+            hits: AtomicUsize::new(0),
+            misses: AtomicUsize::new(0),
+        }
+    }
+    
+    /// Igual que `MemoizedFn::call` pero con `&self`
+    ///
+    /// El lock se mantiene durante el cálculo para que dos threads
+    /// no calculen a la vez el mismo argumento.
+    pub fn call(&self, arg: K) -> V {
+        let mut cache = match self.cache.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        
+        if let Some(value) = cache.get(&arg) {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+            return value.clone();
+        }
+        
+        self.misses.fetch_add(1, Ordering::Relaxed);
+        let value = (self.func)(arg.clone());
+        cache.insert(arg, value.clone());
+        value
+    }
+    
+    pub fn hit_rate(&self) -> f64 {
+        hit_rate(self.hits.load(Ordering::Relaxed), self.misses.load(Ordering::Relaxed))
+    }
+}
+
+fn hit_rate(hits: usize, misses: usize) -> f64 {
+    let total = hits + misses;
+    if total == 0 {
+        0.0
+    } else {
+        hits as f64 / total as f64
+    }
+}
+
+/// Cuenta los factores primos (con repetición) por división de prueba
+pub fn count_prime_factors(mut n: u64) -> u32 {
+    let mut count = 0;
+    let mut divisor = 2;
+    // CORREGIDO: `divisor * divisor` desborda u64 si n no tiene factores por debajo de 2^32
+    while divisor <= n / divisor {
+        while n.is_multiple_of(divisor) {
+            n /= divisor;
+            count += 1;
+        }
+        divisor += 1;
+    }
+    if n > 1 {
+        count += 1;
+    }
+    count
+}
+
+/// Función que demuestra memoización genérica con tasa de aciertos
+pub fn demonstrate_memoization() {
+    println!("\n✅ Demostrando memoización genérica...");
+    
+    // Muchos valores repetidos: el caso ideal para cachear
+    let data: Vec<u64> = (0..100_000u64).map(|i| 1_000_000_007 + i % 500).collect();
+    
+    let mut memo = MemoizedFn::new(count_prime_factors);
+    let total: u32 = data.iter().map(|&n| memo.call(n)).sum();
+    println!("Factores primos totales: {}", total);
+    println!("Entradas en caché: {}", memo.cache_len());
+    println!("Tasa de aciertos: {:.2}%", memo.hit_rate() * 100.0);
+    
+    // CORREGIDO: compartir la caché entre threads con Arc
+    let shared = Arc::new(ThreadSafeMemoizedFn::new(count_prime_factors));
+    let mut handles = vec![];
+    for chunk in data.chunks(25_000) {
+        let shared = Arc::clone(&shared);
+        let chunk = chunk.to_vec();
+        handles.push(thread::spawn(move || {
+            chunk.iter().map(|&n| shared.call(n)).sum::<u32>()
+        }));
+    }
+    
+    let threaded_total: u32 = handles.into_iter().map(|h| h.join().unwrap()).sum();
+    println!("Total con 4 threads: {} (igual: {})", threaded_total, threaded_total == total);
+    println!("Tasa de aciertos compartida: {:.2}%", shared.hit_rate() * 100.0);
+}
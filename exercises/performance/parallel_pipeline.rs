@@ -0,0 +1,58 @@
+//! 🦀 Parallel Pipeline - Cadena de etapas paralelas con caché intermedia
+//!
+//! Cada etapa (`map`, `filter`) se ejecuta en paralelo con `rayon` y su
+//! resultado queda guardado en un `Vec`. Clonar el pipeline en una etapa
+//! permite reejecutar solo las etapas siguientes sin repetir las previas.
+
+use rayon::prelude::*;
+
+/// Pipeline cuyos resultados intermedios se materializan en cada etapa
+#[derive(Debug, Clone)]
+pub struct DataPipeline<T> {
+    cache: Vec<T>,
+    stage_sizes: Vec<usize>,
+}
+
+impl<T: Send> DataPipeline<T> {
+    pub fn new(data: Vec<T>) -> Self {
+        let len = data.len();
+        Self {
+            cache: data,
+            stage_sizes: vec![len],
+        }
+    }
+    
+    /// Transforma cada elemento en paralelo
+    pub fn map<U: Send>(self, f: impl Fn(T) -> U + Sync + Send) -> DataPipeline<U> {
+        let cache: Vec<U> = self.cache.into_par_iter().map(f).collect();
+        let mut stage_sizes = self.stage_sizes;
+        stage_sizes.push(cache.len());
+        DataPipeline { cache, stage_sizes }
+    }
+    
+    /// Conserva los elementos que cumplen el predicado, en paralelo
+    pub fn filter(self, f: impl Fn(&T) -> bool + Sync + Send) -> DataPipeline<T> {
+        let cache: Vec<T> = self.cache.into_par_iter().filter(f).collect();
+        let mut stage_sizes = self.stage_sizes;
+        stage_sizes.push(cache.len());
+        DataPipeline { cache, stage_sizes }
+    }
+    
+    /// Resultado de la última etapa ya calculada
+    pub fn cached(&self) -> &[T] {
+        &self.cache
+    }
+    
+    /// Número de elementos a la salida de cada etapa (la 0 es la entrada)
+    pub fn stage_sizes(&self) -> &[usize] {
+        &self.stage_sizes
+    }
+    
+    /// Devuelve el resultado final
+    ///
+    /// `collect` de rayon conserva el orden original, así que el
+    /// resultado coincide con el de la cadena secuencial equivalente.
+    pub fn collect_parallel(self) -> Vec<T> {
+        self.cache
+    }
+}
@@ -3,9 +3,45 @@
 //! Esta es la versión corregida del ejercicio anterior,
 //! mostrando las mejores prácticas de optimización en Rust.
 
+mod allocator;
+mod base64;
+mod batch_processor;
+mod binary_serde;
+mod checksum;
+mod const_compute;
+mod const_generics;
+mod csv_users;
+mod delta_encoding;
+mod huffman;
+mod hyperloglog;
+mod lazy;
+mod memchr_parsing;
+mod memoize;
+mod parallel_pipeline;
+mod priority_queue;
+mod radix_sort;
+mod rle;
+mod rope;
+mod scoped_timer;
+mod simd_basics;
+mod simd_sum;
+mod stats;
+mod stopwatch;
+mod string_pool;
+mod suffix_array;
+mod zero_copy;
+#[path = "../concurrency/shared_counter.rs"]
+mod shared_counter;
+
 use std::collections::HashMap;
+use std::process::ExitCode;
 use std::time::Instant;
 
+use allocator::{allocation_count, current_heap_bytes};
+use parallel_pipeline::DataPipeline;
+use scoped_timer::ScopedTimer;
+use shared_counter::SharedCounter;
+
 /// Estructura que representa un usuario con datos optimizada
 #[derive(Debug, Clone)]
 struct User {
@@ -45,6 +81,184 @@ impl User {
     fn get_posts(&self) -> &[u32] {
         &self.posts
     }
+    
+    /// Ordena los posts y quita los duplicados, restaurando el invariante de `find_post`
+    ///
+    /// Pensado para reparar datos antiguos en los que `add_post` se llamó
+    /// con IDs desordenados o repetidos.
+    fn dedup_posts(&mut self) {
+        sort_unique_in_place(&mut self.posts);
+    }
+    
+    /// Cambia el nombre; uno vacío o solo con espacios se rechaza y se mantiene el actual
+    fn rename(&mut self, new_name: impl Into<String>) -> Result<(), ValidationError> {
+        let new_name = new_name.into();
+        if new_name.trim().is_empty() {
+            return Err(ValidationError::EmptyName);
+        }
+        self.name = new_name;
+        Ok(())
+    }
+    
+    /// Valida todos los campos y devuelve todos los problemas encontrados
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let mut errors = Vec::new();
+        
+        if self.id == 0 {
+            errors.push(ValidationError::ZeroId);
+        }
+        
+        if self.name.trim().is_empty() {
+            errors.push(ValidationError::EmptyName);
+        }
+        
+        if !is_valid_email(&self.email) {
+            errors.push(ValidationError::InvalidEmail(self.email.clone()));
+        }
+        
+        // find_post usa búsqueda binaria: los posts deben estar ordenados y sin duplicados
+        if !self.posts.windows(2).all(|w| w[0] < w[1]) {
+            errors.push(ValidationError::UnsortedPosts);
+        }
+        
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+/// Errores de validación de un usuario
+#[derive(Debug, Clone, PartialEq)]
+enum ValidationError {
+    ZeroId,
+    EmptyName,
+    InvalidEmail(String),
+    UnsortedPosts,
+    UnknownUser(u32),
+}
+
+impl std::fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ValidationError::ZeroId => write!(f, "El id no puede ser 0"),
+            ValidationError::EmptyName => write!(f, "El nombre no puede estar vacío"),
+            ValidationError::InvalidEmail(email) => write!(f, "Email inválido: '{}'", email),
+            ValidationError::UnsortedPosts => write!(f, "Los posts deben estar ordenados y sin duplicados"),
+            ValidationError::UnknownUser(id) => write!(f, "No existe ningún usuario con id {}", id),
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+/// Usuarios por id con un índice secundario por email
+#[derive(Debug, Default)]
+struct UserRegistry {
+    users: HashMap<u32, User>,
+    by_email: HashMap<String, u32>,
+}
+
+impl UserRegistry {
+    fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Registra el usuario, sustituyendo al que tuviera el mismo id
+    fn insert(&mut self, user: User) {
+        if let Some(previous) = self.users.get(&user.id) {
+            self.by_email.remove(&previous.email);
+        }
+        self.by_email.insert(user.email.clone(), user.id);
+        self.users.insert(user.id, user);
+    }
+    
+    fn get_by_id(&self, id: u32) -> Option<&User> {
+        self.users.get(&id)
+    }
+    
+    fn get_by_email(&self, email: &str) -> Option<&User> {
+        self.by_email.get(email).and_then(|id| self.users.get(id))
+    }
+    
+    /// Renombra el usuario guardado con las mismas reglas que `User::rename`
+    fn rename_user(&mut self, id: u32, new_name: impl Into<String>) -> Result<(), ValidationError> {
+        let user = self.users.get_mut(&id).ok_or(ValidationError::UnknownUser(id))?;
+        // El nombre no es clave de ningún índice, así que by_email no cambia
+        user.rename(new_name)
+    }
+}
+
+/// Fallo real de un demo, que `main` convierte en un código de salida distinto de 0
+#[derive(Debug)]
+enum DemoError {
+    Csv(csv_users::CsvParseError),
+    Io(std::io::Error),
+}
+
+impl std::fmt::Display for DemoError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            DemoError::Csv(e) => write!(f, "Error en el CSV: {}", e),
+            DemoError::Io(e) => write!(f, "Error de E/S: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for DemoError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            DemoError::Csv(e) => Some(e),
+            DemoError::Io(e) => Some(e),
+        }
+    }
+}
+
+impl From<csv_users::CsvParseError> for DemoError {
+    fn from(e: csv_users::CsvParseError) -> Self {
+        DemoError::Csv(e)
+    }
+}
+
+impl From<std::io::Error> for DemoError {
+    fn from(e: std::io::Error) -> Self {
+        DemoError::Io(e)
+    }
+}
+
+/// Comprobación sencilla de formato: `local@dominio.tld`
+fn is_valid_email(email: &str) -> bool {
+    match email.split_once('@') {
+        Some((local, domain)) => {
+            !local.is_empty()
+                && !domain.contains('@')
+                && domain.contains('.')
+                && !domain.starts_with('.')
+                && !domain.ends_with('.')
+        }
+        None => false,
+    }
+}
+
+/// Emails que aparecen en más de un usuario, con los ids que los comparten
+///
+/// Pensado para revisar datos ya existentes antes de cargarlos en un
+/// `UserRegistry`, cuyo índice por email solo guarda un id por email.
+fn detect_duplicate_emails(users: &[User]) -> Vec<(String, Vec<u32>)> {
+    let mut ids_by_email: HashMap<&str, Vec<u32>> = HashMap::new();
+    for user in users {
+        ids_by_email.entry(&user.email).or_default().push(user.id);
+    }
+    
+    // En el orden de la primera aparición, para que el resultado no dependa del hash
+    users
+        .iter()
+        .filter_map(|user| {
+            let ids = ids_by_email.remove(user.email.as_str())?;
+            (ids.len() > 1).then(|| (user.email.clone(), ids))
+        })
+        .collect()
 }
 
 /// Función que demuestra optimización de Vec
@@ -80,16 +294,18 @@ fn demonstrate_vec_optimization() {
 fn demonstrate_string_optimization() {
     println!("\n✅ Demostrando optimización de String...");
     
-    let start = Instant::now();
-    let mut result = String::with_capacity(10000); // CORREGIDO: Pre-allocar capacidad
-    
-    // CORREGIDO: Usar push_str en lugar de concatenación
-    for i in 0..1000 {
-        result.push_str(&format!("Item{}, ", i));
-    }
+    // CORREGIDO: El timer reporta solo al salir de scope
+    let result = {
+        let _t = ScopedTimer::new("concatenar 1000 strings");
+        let mut result = String::with_capacity(10000); // CORREGIDO: Pre-allocar capacidad
+        
+        // CORREGIDO: Usar push_str en lugar de concatenación
+        for i in 0..1000 {
+            result.push_str(&format!("Item{}, ", i));
+        }
+        result
+    };
     
-    let duration = start.elapsed();
-    println!("Tiempo para concatenar 1000 strings: {:?}", duration);
     println!("Longitud del resultado: {}", result.len());
 }
 
@@ -154,6 +370,17 @@ fn demonstrate_iterator_optimization() {
     let duration = start.elapsed();
     println!("Tiempo para procesar usuarios (una pasada): {:?}", duration);
     println!("Usuarios procesados: {}", result.len());
+    
+    // CORREGIDO: Mínimo y máximo en un solo recorrido en lugar de min() + max()
+    let ids: Vec<u32> = users.iter().map(|u| u.id).collect();
+    match min_max(&ids) {
+        Some((min, max)) => println!("Rango de ids (una pasada): {}..={}", min, max),
+        None => println!("No hay usuarios"),
+    }
+    
+    // CORREGIDO: Dos grupos de referencias en una pasada, sin clonar usuarios
+    let (even, odd) = partition_users(&users, |u| u.id % 2 == 0);
+    println!("Ids pares: {}, ids impares: {}", even.len(), odd.len());
 }
 
 /// Función que demuestra optimización de heap allocations
@@ -161,6 +388,7 @@ fn demonstrate_heap_optimization() {
     println!("\n✅ Demostrando optimización de heap allocations...");
     
     let start = Instant::now();
+    let allocs_before = allocation_count();
     
     // CORREGIDO: Usar Vec directamente en lugar de Box
     let mut data = Vec::with_capacity(10000);
@@ -170,9 +398,15 @@ fn demonstrate_heap_optimization() {
     
     let duration = start.elapsed();
     println!("Tiempo para crear 10000 i32: {:?}", duration);
+    println!(
+        "Allocations: {}, bytes en heap: {}",
+        allocation_count() - allocs_before,
+        current_heap_bytes()
+    );
     
     // CORREGIDO: Vec<Vec<T>> en lugar de Vec<Box<Vec<T>>>
     let start = Instant::now();
+    let allocs_before = allocation_count();
     let mut vecs = Vec::with_capacity(100);
     for i in 0..100 {
         let vec = vec![i; 100]; // CORREGIDO: Sin Box innecesario
@@ -181,6 +415,11 @@ fn demonstrate_heap_optimization() {
     
     let duration = start.elapsed();
     println!("Tiempo para crear 100 Vec<i32>: {:?}", duration);
+    println!(
+        "Allocations: {}, bytes en heap: {}",
+        allocation_count() - allocs_before,
+        current_heap_bytes()
+    );
 }
 
 /// Función que demuestra optimización de recursión
@@ -195,6 +434,13 @@ fn demonstrate_recursion_optimization() {
     let duration = start.elapsed();
     println!("Tiempo para fibonacci(35) optimizado: {:?}", duration);
     println!("Resultado: {}", result);
+    
+    for n in [93, 94] {
+        match checked_fibonacci(n) {
+            Ok(value) => println!("checked_fibonacci({}) = {}", n, value),
+            Err(e) => println!("checked_fibonacci({}): {}", n, e),
+        }
+    }
 }
 
 /// Función que demuestra optimización de locks
@@ -215,6 +461,27 @@ fn demonstrate_lock_optimization() {
     
     let duration = start.elapsed();
     println!("Tiempo para 10000 operaciones con un lock: {:?}", duration);
+    
+    // CORREGIDO: La misma lección como API: add por elemento vs add_batch
+    let values: Vec<i32> = (0..10000).collect();
+    
+    let counter = SharedCounter::new();
+    let start = Instant::now();
+    for &value in &values {
+        if let Err(e) = counter.add(value) {
+            println!("Error en add: {}", e);
+        }
+    }
+    println!("add() por elemento (10000 locks): {:?}", start.elapsed());
+    
+    let batch_counter = SharedCounter::new();
+    let start = Instant::now();
+    match batch_counter.add_batch(&values) {
+        Ok(total) => println!("add_batch() (1 lock): {:?}, total {}", start.elapsed(), total),
+        Err(e) => println!("Error en add_batch: {}", e),
+    }
+    
+    println!("Mismos totales: {}", counter.get().ok() == batch_counter.get().ok());
 }
 
 /// Función que demuestra optimización con Cow
@@ -262,6 +529,189 @@ fn demonstrate_slice_optimization() {
     let duration = start.elapsed();
     println!("Tiempo para procesar chunks: {:?}", duration);
     println!("Suma: {}", sum);
+    
+    // CORREGIDO: Versión genérica reutilizable para cualquier tipo numérico
+    match chunk_sum(&data, 3) {
+        Ok(sums) => println!("Sumas por chunk de 3 (i32): {:?}", sums),
+        Err(e) => println!("Error en chunk_sum: {}", e),
+    }
+    
+    let prices = [1.5, 2.5, 3.0, 4.0, 0.5];
+    match chunk_sum(&prices, 2) {
+        Ok(sums) => println!("Sumas por chunk de 2 (f64): {:?}", sums),
+        Err(e) => println!("Error en chunk_sum: {}", e),
+    }
+}
+
+/// Función que demuestra sumas que detectan o limitan el overflow
+fn demonstrate_overflow_aware_sum() {
+    println!("\n✅ Demostrando sumas con control de overflow...");
+    
+    let normal = [10, -3, 250, 7];
+    println!("Slice normal: checked {:?}, saturating {}", checked_sum(&normal), saturating_sum(&normal));
+    
+    // Con `+` esto haría panic en debug y daría un valor negativo en release
+    let large = [i64::MAX - 5, 3, 4];
+    match checked_sum(&large) {
+        Ok(sum) => println!("checked_sum: {}", sum),
+        Err(e) => println!("checked_sum: {}", e),
+    }
+    println!("wrapping (lo que hace `+` en release): {}", large.iter().fold(0i64, |acc, &x| acc.wrapping_add(x)));
+    println!("saturating_sum: {} (i64::MAX)", saturating_sum(&large));
+    println!("saturating_sum por abajo: {}", saturating_sum(&[i64::MIN, -1]));
+}
+
+/// Suma cada chunk de `chunk` elementos; el último chunk puede ser más corto
+fn chunk_sum<T>(data: &[T], chunk: usize) -> Result<Vec<T>, String>
+where
+    T: Copy + std::iter::Sum,
+{
+    if chunk == 0 {
+        return Err("El tamaño de chunk no puede ser 0".to_string());
+    }
+    
+    Ok(data.chunks(chunk)
+        .map(|c| c.iter().copied().sum())
+        .collect())
+}
+
+/// Devuelve `(mínimo, máximo)` recorriendo el slice una sola vez
+///
+/// Con `PartialOrd`, los valores no comparables (p. ej. `NaN`) nunca
+/// reemplazan al mínimo o máximo actual.
+fn min_max<T: Copy + PartialOrd>(items: &[T]) -> Option<(T, T)> {
+    let (&first, rest) = items.split_first()?;
+    
+    Some(rest.iter().fold((first, first), |(min, max), &item| {
+        if item < min {
+            (item, max)
+        } else if item > max {
+            (min, item)
+        } else {
+            (min, max)
+        }
+    }))
+}
+
+/// Ordena `v` y quita los duplicados, reutilizando su memoria
+fn sorted_unique<T: Ord>(mut v: Vec<T>) -> Vec<T> {
+    sort_unique_in_place(&mut v);
+    v
+}
+
+/// Como `sorted_unique`, sobre un `Vec` que se sigue usando
+///
+/// `sort_unstable` basta: tras el `dedup` no quedan elementos iguales
+/// cuyo orden relativo importe.
+fn sort_unique_in_place<T: Ord>(v: &mut Vec<T>) {
+    v.sort_unstable();
+    v.dedup();
+}
+
+/// Separa los usuarios según `pred` en una sola pasada, sin clonarlos
+///
+/// El primer `Vec` contiene los que cumplen el predicado y el segundo
+/// el resto, ambos en el orden original.
+fn partition_users(users: &[User], pred: impl Fn(&User) -> bool) -> (Vec<&User>, Vec<&User>) {
+    users.iter().partition(|user| pred(user))
+}
+
+/// Función que demuestra validación completa de usuarios
+fn demonstrate_user_validation() {
+    println!("\n✅ Demostrando validación de usuarios...");
+    
+    let mut valid = User::new(1, "Alice".to_string(), "alice@example.com".to_string());
+    valid.add_post(101);
+    valid.add_post(102);
+    
+    let mut invalid = User::new(0, "  ".to_string(), "no-es-un-email".to_string());
+    invalid.add_post(7);
+    invalid.add_post(3);
+    
+    for user in [&valid, &invalid] {
+        // CORREGIDO: Se informan todos los errores, no solo el primero
+        match user.validate() {
+            Ok(()) => println!("Usuario {} válido", user.id),
+            Err(errors) => {
+                println!("Usuario {} inválido ({} errores):", user.id, errors.len());
+                for error in errors {
+                    println!("   - {}", error);
+                }
+            }
+        }
+    }
+    
+    // CORREGIDO: Los posts desordenados se reparan en lugar de rechazar al usuario
+    let mut legacy = User::new(2, "Bob".to_string(), "bob@example.com".to_string());
+    for post_id in [3, 1, 2, 1, 3] {
+        legacy.add_post(post_id);
+    }
+    println!("Posts heredados: {:?}, válido: {}", legacy.get_posts(), legacy.validate().is_ok());
+    legacy.dedup_posts();
+    println!(
+        "Tras dedup_posts: {:?}, find_post(1): {}, válido: {}",
+        legacy.get_posts(),
+        legacy.find_post(1),
+        legacy.validate().is_ok()
+    );
+    
+    let bulk = vec![
+        valid.clone(),
+        User::new(3, "Carol".to_string(), "carol@example.com".to_string()),
+        User::new(4, "Alice B.".to_string(), "alice@example.com".to_string()),
+    ];
+    for (email, ids) in detect_duplicate_emails(&bulk) {
+        println!("Email duplicado {} en los usuarios {:?}", email, ids);
+    }
+    let all_posts = sorted_unique(bulk.iter().flat_map(|user| user.get_posts().iter().copied()).collect());
+    println!("Posts del lote sin repetir: {:?}", all_posts);
+    
+    // CORREGIDO: El nombre solo cambia a través de rename, que rechaza nombres vacíos
+    let mut registry = UserRegistry::new();
+    registry.insert(valid);
+    registry.insert(legacy);
+    for (id, new_name) in [(1, "Alicia"), (2, "   "), (9, "Carol")] {
+        match registry.rename_user(id, new_name) {
+            Ok(()) => println!("Usuario {} renombrado a {:?}", id, new_name),
+            Err(e) => println!("No se renombró el usuario {}: {}", id, e),
+        }
+    }
+    if let (Some(alice), Some(bob)) = (registry.get_by_email("alice@example.com"), registry.get_by_id(2)) {
+        println!("Por email: {} (id {}), por id: {}", alice.name, alice.id, bob.name);
+    }
+}
+
+/// Función que demuestra un pipeline paralelo frente a la cadena secuencial
+fn demonstrate_parallel_pipeline() {
+    println!("\n✅ Demostrando pipeline paralelo con caché intermedia...");
+    
+    let mut users = create_test_users_optimized(50_000);
+    for user in &mut users {
+        for post_id in 0..(user.id % 5) {
+            user.add_post(post_id);
+        }
+    }
+    
+    let start = Instant::now();
+    let sequential: Vec<usize> = users.iter()
+        .filter(|u| u.id % 3 == 0)
+        .map(|u| u.get_posts().len() * 2)
+        .collect();
+    println!("Cadena secuencial: {:?}", start.elapsed());
+    
+    // CORREGIDO: Cada etapa en paralelo, resultado guardado entre etapas
+    let start = Instant::now();
+    let filtered = DataPipeline::new(users).filter(|u| u.id % 3 == 0);
+    let parallel = filtered.clone()
+        .map(|u| u.get_posts().len() * 2)
+        .collect_parallel();
+    println!("Pipeline paralelo: {:?}", start.elapsed());
+    println!("Mismos resultados: {}", parallel == sequential);
+    
+    // Reutilizar la etapa filtrada sin volver a filtrar
+    let names = filtered.map(|u| u.name);
+    println!("Elementos por etapa: {:?}", names.stage_sizes());
+    println!("Primer nombre filtrado: {:?}", names.cached().first());
 }
 
 /// Función auxiliar para crear usuarios de prueba optimizada
@@ -295,7 +745,58 @@ fn fibonacci_optimized(n: u32) -> u64 {
     memo[n as usize]
 }
 
-fn main() {
+/// Errores de operaciones aritméticas comprobadas
+#[derive(Debug, Clone, PartialEq)]
+enum ArithmeticError {
+    /// El resultado no cabe en el tipo de destino
+    Overflow,
+}
+
+impl std::fmt::Display for ArithmeticError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ArithmeticError::Overflow => write!(f, "Overflow aritmético"),
+        }
+    }
+}
+
+impl std::error::Error for ArithmeticError {}
+
+/// Fibonacci iterativo que devuelve `Overflow` en lugar de desbordar
+///
+/// `fib(93)` es el último valor que cabe en un `u64`.
+fn checked_fibonacci(n: u32) -> Result<u64, ArithmeticError> {
+    if n == 0 {
+        return Ok(0);
+    }
+    
+    let (mut previous, mut current) = (0u64, 1u64);
+    for _ in 1..n {
+        // CORREGIDO: checked_add detecta el primer overflow en lugar de dar un valor erróneo
+        let next = previous.checked_add(current).ok_or(ArithmeticError::Overflow)?;
+        previous = current;
+        current = next;
+    }
+    Ok(current)
+}
+
+/// Suma con `checked_add`: el primer overflow (por arriba o por abajo) es un error
+fn checked_sum(data: &[i64]) -> Result<i64, ArithmeticError> {
+    data.iter()
+        .try_fold(0i64, |acc, &x| acc.checked_add(x))
+        .ok_or(ArithmeticError::Overflow)
+}
+
+/// Suma con `saturating_add`: se queda en `i64::MAX` / `i64::MIN` en lugar de desbordar
+///
+/// Satura en cada paso, así que el resultado depende del orden:
+/// `[i64::MAX, 1, -1]` da `i64::MAX - 1`, no `i64::MAX`.
+fn saturating_sum(data: &[i64]) -> i64 {
+    data.iter().fold(0i64, |acc, &x| acc.saturating_add(x))
+}
+
+/// Ejecuta todos los demos; se detiene en el primer fallo real
+fn run_performance_demo() -> Result<(), DemoError> {
     println!("🦀 Rust Lab - Performance Optimization SOLUCIÓN CORRECTA");
     println!("{}", "=".repeat(70));
     
@@ -309,17 +810,84 @@ fn main() {
     demonstrate_lock_optimization();
     demonstrate_cow_optimization();
     demonstrate_slice_optimization();
+    demonstrate_overflow_aware_sum();
+    demonstrate_user_validation();
+    demonstrate_parallel_pipeline();
+    scoped_timer::demonstrate_scoped_timer();
+    stopwatch::demonstrate_stopwatch();
+    memoize::demonstrate_memoization();
+    lazy::demonstrate_lazy_init();
+    csv_users::demonstrate_csv_users()?;
+    binary_serde::demonstrate_binary_serde()?;
+    checksum::demonstrate_checksums();
+    base64::demonstrate_base64();
+    batch_processor::demonstrate_batch_processor();
+    const_compute::demonstrate_const_compute();
+    const_generics::demonstrate_const_generic_array_sum();
+    hyperloglog::demonstrate_hyperloglog();
+    zero_copy::demonstrate_zero_copy_parsing();
+    memchr_parsing::demonstrate_string_view_optimization();
+    radix_sort::demonstrate_radix_sort();
+    huffman::demonstrate_compression_ratio();
+    rle::demonstrate_rle();
+    rope::demonstrate_rope();
+    delta_encoding::demonstrate_delta_encoding();
+    priority_queue::demonstrate_priority_queue();
+    simd_basics::demonstrate_popcount();
+    simd_sum::demonstrate_simd_sum();
+    stats::demonstrate_histogram();
+    suffix_array::demonstrate_suffix_array();
+    string_pool::demonstrate_string_pool();
     
     println!("\n✅ Todas las optimizaciones completadas!");
     println!("🎯 Conceptos clave demostrados:");
     println!("   - Pre-allocation: Reservar capacidad anticipadamente");
+    println!("   - Allocation tracking: Medir bytes y allocations en el heap");
+    println!("   - RAII timers: Medir el tiempo de un scope con Drop");
+    println!("   - Lap timing: Stopwatch con una vuelta por fase del benchmark");
+    println!("   - Batching: Lotes que se vacían por tamaño o por tiempo");
     println!("   - String optimization: Usar push_str en lugar de concatenación");
     println!("   - Iterator optimization: Una sola pasada sobre los datos");
     println!("   - Clone avoidance: Usar referencias cuando sea posible");
     println!("   - Memory layout: Estructuras de datos eficientes");
     println!("   - Algorithm optimization: Búsqueda binaria, memoización");
+    println!("   - Overflow-aware sums: checked_sum devuelve error, saturating_sum se queda en el límite");
+    println!("   - Radix sort: Ordenar enteros por dígitos sin comparar");
+    println!("   - Huffman: Códigos más cortos para los bytes más frecuentes");
+    println!("   - Run-length encoding: Pares (byte, longitud) para rachas repetidas");
+    println!("   - Ropes: Árbol de trozos para insertar y borrar en mitad de textos largos");
+    println!("   - Delta encoding: Diferencias entre IDs ordenados en varints de 1 byte");
+    println!("   - Priority queues: Montículos de máximos y de mínimos con pop_max/pop_min");
+    println!("   - Inline assembly: popcnt con asm! y detección de la CPU en tiempo de ejecución");
+    println!("   - SIMD intrinsics: Sumar 8 u32 por instrucción con AVX2 y el resto en escalar");
+    println!("   - Histograms: Repartir los tiempos de un benchmark en intervalos de min a max");
+    println!("   - Suffix arrays: Sufijos ordenados para buscar subcadenas con búsqueda binaria");
+    println!("   - String interning: Box<str> en un Vec para que cada &str siga válido al crecer el pool");
+    println!("   - Generic memoization: Cachear cualquier función pura");
+    println!("   - Lazy initialization: Calcular solo en el primer acceso");
+    println!("   - Const evaluation: Tablas calculadas en tiempo de compilación");
+    println!("   - Const generics: Tamaños de array en el tipo, una copia del código por tamaño");
+    println!("   - Probabilistic counting: HyperLogLog con memoria fija");
     println!("   - Lock optimization: Minimizar tiempo de lock");
+    println!("   - Parallel pipelines: Etapas paralelas con rayon");
+    println!("   - Buffered I/O: BufReader/BufWriter para archivos grandes");
+    println!("   - Binary formats: Enteros little-endian y cadenas con longitud delante");
+    println!("   - CRC32: Detectar registros corruptos con un checksum de 4 bytes");
+    println!("   - Base64: Tablas de 64 entradas y relleno con =");
+    println!("   - Zero-copy parsing: &str que apuntan al buffer original");
+    println!("   - Byte search: memchr y SmallVec para separar campos sin reservar memoria");
     println!("   - Cow optimization: Copy-on-write para flexibilidad");
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    match run_performance_demo() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("❌ El demo falló: {}", e);
+            ExitCode::FAILURE
+        }
+    }
 }
 
 
@@ -0,0 +1,161 @@
+//! 🦀 Priority Queue - Montículo binario con una API explícita
+//!
+//! `BinaryHeap` es un montículo de máximos, pero sus métodos se llaman
+//! `pop` y `peek`, y para obtener el mínimo hay que envolver cada valor
+//! en `Reverse` a mano. `PriorityQueue` y `MinPriorityQueue` dejan claro
+//! en el nombre de cada método qué extremo se obtiene.
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::time::Instant;
+
+/// Cola de prioridad que siempre devuelve primero el mayor elemento
+#[derive(Debug, Clone)]
+pub struct PriorityQueue<T: Ord> {
+    heap: BinaryHeap<T>,
+}
+
+impl<T: Ord> PriorityQueue<T> {
+    pub fn new() -> Self {
+        Self { heap: BinaryHeap::new() }
+    }
+    
+    /// O(log n)
+    pub fn push(&mut self, item: T) {
+        self.heap.push(item);
+    }
+    
+    /// Saca el mayor elemento en O(log n)
+    pub fn pop_max(&mut self) -> Option<T> {
+        self.heap.pop()
+    }
+    
+    /// Mira el mayor elemento sin sacarlo, en O(1)
+    pub fn peek_max(&self) -> Option<&T> {
+        self.heap.peek()
+    }
+    
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+    
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+}
+
+impl<T: Ord> Default for PriorityQueue<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord> FromIterator<T> for PriorityQueue<T> {
+    /// Construye el montículo en O(n), más rápido que n llamadas a `push`
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self {
+            heap: iter.into_iter().collect(),
+        }
+    }
+}
+
+/// Cola de prioridad que siempre devuelve primero el menor elemento
+///
+/// Internamente es un `BinaryHeap<Reverse<T>>`; el `Reverse` no sale de
+/// esta estructura.
+#[derive(Debug, Clone)]
+pub struct MinPriorityQueue<T: Ord> {
+    heap: BinaryHeap<Reverse<T>>,
+}
+
+impl<T: Ord> MinPriorityQueue<T> {
+    pub fn new() -> Self {
+        Self { heap: BinaryHeap::new() }
+    }
+    
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            heap: BinaryHeap::with_capacity(capacity),
+        }
+    }
+    
+    /// O(log n)
+    pub fn push(&mut self, item: T) {
+        self.heap.push(Reverse(item));
+    }
+    
+    /// Saca el menor elemento en O(log n)
+    pub fn pop_min(&mut self) -> Option<T> {
+        self.heap.pop().map(|Reverse(item)| item)
+    }
+    
+    /// Mira el menor elemento sin sacarlo, en O(1)
+    pub fn peek_min(&self) -> Option<&T> {
+        self.heap.peek().map(|Reverse(item)| item)
+    }
+    
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+    
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+}
+
+impl<T: Ord> Default for MinPriorityQueue<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord> FromIterator<T> for MinPriorityQueue<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self {
+            heap: iter.into_iter().map(Reverse).collect(),
+        }
+    }
+}
+
+/// Función que demuestra colas de prioridad de máximos y de mínimos
+pub fn demonstrate_priority_queue() {
+    println!("\n✅ Demostrando PriorityQueue y MinPriorityQueue...");
+    
+    // Las tuplas se comparan campo a campo: primero la prioridad
+    let mut tasks = PriorityQueue::new();
+    tasks.push((2, "responder emails"));
+    tasks.push((5, "arreglar producción"));
+    tasks.push((1, "actualizar docs"));
+    println!("Siguiente tarea: {:?} ({} pendientes)", tasks.peek_max(), tasks.len());
+    while let Some((priority, task)) = tasks.pop_max() {
+        println!("   [{}] {}", priority, task);
+    }
+    println!("¿Quedan tareas? {}", !tasks.is_empty());
+    
+    let mut deadlines: MinPriorityQueue<u32> = [30, 5, 12, 1].into_iter().collect();
+    print!("Deadlines por orden:");
+    while let Some(deadline) = deadlines.pop_min() {
+        print!(" {}", deadline);
+    }
+    println!(" (vacía: {})", deadlines.is_empty());
+    
+    // CORREGIDO: Para los k mayores basta un montículo de mínimos de tamaño k, no ordenar todo
+    let values: Vec<u32> = (0..1_000_000u32).map(|i| i.wrapping_mul(2_654_435_761) % 1_000_003).collect();
+    let start = Instant::now();
+    let mut top = MinPriorityQueue::with_capacity(10);
+    for &value in &values {
+        // El mínimo del montículo es el umbral para entrar en el top
+        if top.len() < 10 {
+            top.push(value);
+        } else if top.peek_min().is_some_and(|&min| value > min) {
+            top.pop_min();
+            top.push(value);
+        }
+    }
+    let mut top10 = Vec::with_capacity(top.len());
+    while let Some(value) = top.pop_min() {
+        top10.push(value);
+    }
+    top10.reverse();
+    println!("Top 10 de {} valores en {:?}: {:?}", values.len(), start.elapsed(), top10);
+}
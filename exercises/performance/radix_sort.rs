@@ -0,0 +1,111 @@
+//! 🦀 Radix Sort - Ordenar IDs sin comparar elementos
+//!
+//! `User::find_post` usa `binary_search`, pero `add_post` añade al final
+//! sin ordenar. Para enteros de ancho fijo, un radix sort LSD ordena en
+//! O(n) pasadas por dígito: cada pasada reparte los elementos según 16
+//! bits con un array de conteo, de menos a más significativo. Como cada
+//! reparto es estable, al terminar el último dígito todo queda ordenado.
+
+use std::time::Instant;
+
+use super::hyperloglog::xorshift;
+use super::User;
+
+const DIGIT_BITS: u32 = 16;
+const BUCKETS: usize = 1 << DIGIT_BITS;
+const DIGIT_MASK: u64 = BUCKETS as u64 - 1;
+
+/// Una pasada estable de `src` a `dst` según el dígito que devuelve `digit`
+fn counting_pass<T: Copy>(src: &[T], dst: &mut [T], digit: impl Fn(T) -> usize) {
+    let mut counts = vec![0usize; BUCKETS];
+    for &value in src {
+        counts[digit(value)] += 1;
+    }
+    
+    // Suma prefija: cada bucket pasa a guardar su primera posición en `dst`
+    let mut next = 0;
+    for count in counts.iter_mut() {
+        let bucket_len = *count;
+        *count = next;
+        next += bucket_len;
+    }
+    
+    for &value in src {
+        let bucket = &mut counts[digit(value)];
+        dst[*bucket] = value;
+        *bucket += 1;
+    }
+}
+
+/// Ordena `u32` en dos pasadas: 16 bits bajos y luego 16 bits altos
+pub fn radix_sort(data: &mut [u32]) {
+    if data.len() < 2 {
+        return;
+    }
+    
+    let mut buffer = vec![0; data.len()];
+    counting_pass(data, &mut buffer, |value| (value as u64 & DIGIT_MASK) as usize);
+    counting_pass(&buffer, data, |value| (value >> DIGIT_BITS) as usize);
+}
+
+/// Ordena `u64` en cuatro pasadas de 16 bits
+pub fn radix_sort_u64(data: &mut [u64]) {
+    if data.len() < 2 {
+        return;
+    }
+    
+    let mut buffer = vec![0; data.len()];
+    // Las pasadas alternan entre `data` y `buffer`; con un número par el resultado acaba en `data`
+    for pass in (0..4).step_by(2) {
+        let low = pass * DIGIT_BITS;
+        let high = low + DIGIT_BITS;
+        counting_pass(data, &mut buffer, |value| ((value >> low) & DIGIT_MASK) as usize);
+        counting_pass(&buffer, data, |value| ((value >> high) & DIGIT_MASK) as usize);
+    }
+}
+
+/// Función que demuestra radix sort frente a los sorts de la biblioteca estándar
+pub fn demonstrate_radix_sort() {
+    println!("\n✅ Demostrando radix sort con IDs u32...");
+    
+    let mut state = 0x9E37_79B9_7F4A_7C15;
+    let original: Vec<u32> = (0..1_000_000).map(|_| xorshift(&mut state) as u32).collect();
+    
+    let mut stable = original.clone();
+    let start = Instant::now();
+    stable.sort();
+    let stable_time = start.elapsed();
+    
+    let mut unstable = original.clone();
+    let start = Instant::now();
+    unstable.sort_unstable();
+    let unstable_time = start.elapsed();
+    
+    let mut radix = original;
+    let start = Instant::now();
+    radix_sort(&mut radix);
+    let radix_time = start.elapsed();
+    
+    println!("slice::sort:          {:?}", stable_time);
+    println!("slice::sort_unstable: {:?}", unstable_time);
+    println!("radix_sort:           {:?}", radix_time);
+    println!("Mismo resultado: {}", radix == unstable);
+    
+    let mut wide: Vec<u64> = (0..1_000_000).map(|_| xorshift(&mut state)).collect();
+    let start = Instant::now();
+    radix_sort_u64(&mut wide);
+    println!(
+        "radix_sort_u64 (4 pasadas): {:?}, ordenado: {}",
+        start.elapsed(),
+        wide.windows(2).all(|w| w[0] <= w[1])
+    );
+    
+    // CORREGIDO: Ordenar los posts antes de buscar con binary_search
+    let mut user = User::new(1, "Ana".to_string(), "ana@example.com".to_string());
+    for post_id in [42, 7, 1000, 3, 99] {
+        user.add_post(post_id);
+    }
+    println!("Antes de ordenar, find_post(42): {}", user.find_post(42));
+    radix_sort(&mut user.posts);
+    println!("Posts ordenados: {:?}, find_post(42): {}", user.get_posts(), user.find_post(42));
+}
@@ -0,0 +1,133 @@
+//! 🦀 RLE - Run-length encoding para datos con muchas repeticiones
+//!
+//! Cada racha de bytes iguales se guarda como un par `(byte, longitud)`.
+//! La longitud cabe en un `u8`, así que las rachas de más de 255 se
+//! parten en varios pares. Solo compensa si hay rachas largas: con
+//! datos sin repetir, el resultado ocupa el doble que la entrada.
+
+use std::io::{self, Cursor, Read, Write};
+use std::time::Instant;
+
+use super::hyperloglog::xorshift;
+
+/// Rachas de `data` como `(byte, longitud)`, con longitud entre 1 y 255
+fn runs(data: &[u8]) -> impl Iterator<Item = (u8, u8)> + '_ {
+    data.chunk_by(|a, b| a == b)
+        .flat_map(|run| run.chunks(u8::MAX as usize).map(|chunk| (chunk[0], chunk.len() as u8)))
+}
+
+pub fn rle_encode(data: &[u8]) -> Vec<(u8, u8)> {
+    runs(data).collect()
+}
+
+pub fn rle_decode(encoded: &[(u8, u8)]) -> Vec<u8> {
+    let total = encoded.iter().map(|&(_, count)| count as usize).sum();
+    let mut decoded = Vec::with_capacity(total);
+    for &(byte, count) in encoded {
+        decoded.extend(std::iter::repeat_n(byte, count as usize));
+    }
+    decoded
+}
+
+/// Escribe los pares como 2 bytes (`byte`, `longitud`) sin acumularlos en memoria
+///
+/// Devuelve cuántos bytes se escribieron.
+pub fn rle_encode_to_writer(data: &[u8], writer: &mut impl Write) -> io::Result<usize> {
+    let mut written = 0;
+    for (byte, count) in runs(data) {
+        writer.write_all(&[byte, count])?;
+        written += 2;
+    }
+    Ok(written)
+}
+
+/// Lee pares hasta el final del flujo
+///
+/// Lee de 2 en 2 bytes: con un `File` conviene envolverlo en un
+/// `BufReader`. Un byte suelto al final o una longitud 0 son
+/// `InvalidData`.
+pub fn rle_decode_from_reader(reader: &mut impl Read) -> io::Result<Vec<u8>> {
+    let mut decoded = Vec::new();
+    let mut pair = [0u8; 2];
+    
+    loop {
+        // Solo un EOF justo entre pares es un final limpio
+        let read = reader.read(&mut pair[..1])?;
+        if read == 0 {
+            return Ok(decoded);
+        }
+        reader.read_exact(&mut pair[1..]).map_err(|e| match e.kind() {
+            io::ErrorKind::UnexpectedEof => io::Error::new(io::ErrorKind::InvalidData, "par RLE incompleto al final"),
+            _ => e,
+        })?;
+        
+        let [byte, count] = pair;
+        if count == 0 {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "racha RLE de longitud 0"));
+        }
+        decoded.extend(std::iter::repeat_n(byte, count as usize));
+    }
+}
+
+/// Datos sintéticos: cada byte repite el anterior con un 90% de probabilidad
+fn repetitive_data(len: usize) -> Vec<u8> {
+    let mut state = 0x2545_F491_4F6C_DD1D;
+    let mut current = 0u8;
+    (0..len)
+        .map(|_| {
+            if xorshift(&mut state).is_multiple_of(10) {
+                current = xorshift(&mut state) as u8;
+            }
+            current
+        })
+        .collect()
+}
+
+/// Función que demuestra RLE sobre datos repetitivos (y zstd con la feature `compression`)
+pub fn demonstrate_rle() {
+    println!("\n✅ Demostrando run-length encoding...");
+    
+    let data = repetitive_data(1_000_000);
+    
+    let start = Instant::now();
+    let encoded = rle_encode(&data);
+    let encode_time = start.elapsed();
+    println!(
+        "RLE: {} bytes -> {} pares ({} bytes, {:.1}%) en {:?}",
+        data.len(),
+        encoded.len(),
+        encoded.len() * 2,
+        (encoded.len() * 2) as f64 / data.len() as f64 * 100.0,
+        encode_time
+    );
+    println!("Decodifica igual: {}", rle_decode(&encoded) == data);
+    
+    // CORREGIDO: En streaming no hace falta el Vec de pares intermedio
+    let mut buffer = Vec::new();
+    match rle_encode_to_writer(&data, &mut buffer) {
+        Ok(written) => {
+            let recovered = rle_decode_from_reader(&mut Cursor::new(&buffer)).is_ok_and(|decoded| decoded == data);
+            println!("Writer: {} bytes escritos, el reader recupera los datos: {}", written, recovered);
+        }
+        Err(e) => println!("Error al escribir: {}", e),
+    }
+    
+    #[cfg(feature = "compression")]
+    {
+        let start = Instant::now();
+        match zstd::encode_all(&data[..], 3) {
+            Ok(compressed) => println!(
+                "zstd (nivel 3): {} bytes ({:.1}%) en {:?}",
+                compressed.len(),
+                compressed.len() as f64 / data.len() as f64 * 100.0,
+                start.elapsed()
+            ),
+            Err(e) => println!("Error en zstd: {}", e),
+        }
+    }
+    #[cfg(not(feature = "compression"))]
+    println!("Compilar con --features compression para comparar con zstd");
+    
+    let no_runs: Vec<u8> = (0..=255).collect();
+    println!("Sin rachas: {} bytes -> {} bytes", no_runs.len(), rle_encode(&no_runs).len() * 2);
+}
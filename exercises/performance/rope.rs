@@ -0,0 +1,262 @@
+//! 🦀 Rope - Editar textos largos sin mover todo el buffer
+//!
+//! `String::insert_str` en mitad de un texto de 1 MB desplaza medio
+//! megabyte cada vez. Una rope guarda el texto en hojas pequeñas
+//! colgadas de un árbol binario: insertar o borrar solo toca las hojas
+//! del rango y el camino hasta ellas, O(log n) con el árbol equilibrado.
+//!
+//! Las posiciones son índices de `char`, no de bytes, como en `char_at`;
+//! así nunca se parte un carácter multibyte.
+
+use std::fmt;
+use std::hint::black_box;
+use std::time::Instant;
+
+/// Bytes máximos de una hoja; una hoja que crece más se parte en dos
+const MAX_LEAF: usize = 1024;
+
+/// Profundidad a partir de la cual se reconstruye el árbol equilibrado
+///
+/// Un árbol equilibrado con hojas de 512 bytes y 1 GB de texto tiene
+/// profundidad 21; pasar de 48 solo ocurre si las inserciones se
+/// concentran en el mismo sitio.
+const MAX_DEPTH: usize = 48;
+
+/// Texto como árbol binario de trozos
+#[derive(Debug, Clone)]
+pub enum Rope {
+    Leaf(String),
+    /// Hijo izquierdo, hijo derecho y `char`s totales de los dos
+    Node(Box<Rope>, Box<Rope>, usize),
+}
+
+impl Default for Rope {
+    fn default() -> Self {
+        Rope::Leaf(String::new())
+    }
+}
+
+/// Offset en bytes del `char` número `pos` de `s` (o `s.len()` si `pos` es el final)
+fn byte_offset(s: &str, pos: usize) -> usize {
+    s.char_indices().nth(pos).map_or(s.len(), |(offset, _)| offset)
+}
+
+/// Parte `s` en trozos de como mucho `max_bytes`, sin cortar ningún `char`
+fn chunks(s: &str, max_bytes: usize) -> Vec<String> {
+    let mut chunks = Vec::with_capacity(s.len() / max_bytes + 1);
+    let mut rest = s;
+    while !rest.is_empty() {
+        let mut cut = max_bytes.min(rest.len());
+        while !rest.is_char_boundary(cut) {
+            cut -= 1;
+        }
+        let (chunk, tail) = rest.split_at(cut);
+        chunks.push(chunk.to_string());
+        rest = tail;
+    }
+    chunks
+}
+
+impl Rope {
+    pub fn new(s: &str) -> Self {
+        let leaves: Vec<Rope> = chunks(s, MAX_LEAF).into_iter().map(Rope::Leaf).collect();
+        Rope::balanced(leaves)
+    }
+    
+    /// Árbol de profundidad mínima con `leaves` en orden
+    fn balanced(mut leaves: Vec<Rope>) -> Self {
+        match leaves.len() {
+            0 => Rope::default(),
+            1 => leaves.pop().unwrap_or_default(),
+            n => {
+                let right = leaves.split_off(n / 2);
+                Rope::concat(Rope::balanced(leaves), Rope::balanced(right))
+            }
+        }
+    }
+    
+    fn concat(left: Rope, right: Rope) -> Self {
+        let len = left.len() + right.len();
+        Rope::Node(Box::new(left), Box::new(right), len)
+    }
+    
+    /// Número de `char`s
+    pub fn len(&self) -> usize {
+        match self {
+            Rope::Leaf(s) => s.chars().count(),
+            Rope::Node(_, _, len) => *len,
+        }
+    }
+    
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    
+    fn depth(&self) -> usize {
+        match self {
+            Rope::Leaf(_) => 0,
+            Rope::Node(left, right, _) => 1 + left.depth().max(right.depth()),
+        }
+    }
+    
+    /// # Panics
+    ///
+    /// Si `pos >= self.len()`.
+    pub fn char_at(&self, pos: usize) -> char {
+        let mut node = self;
+        let mut pos = pos;
+        loop {
+            match node {
+                Rope::Leaf(s) => {
+                    return s
+                        .chars()
+                        .nth(pos)
+                        .unwrap_or_else(|| panic!("posición {} fuera de la rope", pos))
+                }
+                Rope::Node(left, right, _) => {
+                    let left_len = left.len();
+                    if pos < left_len {
+                        node = left;
+                    } else {
+                        node = right;
+                        pos -= left_len;
+                    }
+                }
+            }
+        }
+    }
+    
+    /// Inserta `text` antes del `char` número `pos`
+    ///
+    /// # Panics
+    ///
+    /// Si `pos > self.len()`.
+    pub fn insert(&mut self, pos: usize, text: &str) {
+        assert!(pos <= self.len(), "insert en {} con longitud {}", pos, self.len());
+        if text.is_empty() {
+            return;
+        }
+        // Solo una hoja partida puede hacer crecer la profundidad
+        if self.insert_at(pos, text, text.chars().count()) && self.depth() > MAX_DEPTH {
+            *self = Rope::new(&self.to_string());
+        }
+    }
+    
+    /// Devuelve `true` si alguna hoja se partió
+    fn insert_at(&mut self, pos: usize, text: &str, text_len: usize) -> bool {
+        match self {
+            Rope::Leaf(s) => {
+                s.insert_str(byte_offset(s, pos), text);
+                if s.len() <= MAX_LEAF {
+                    return false;
+                }
+                // CORREGIDO: Trozos de medio MAX_LEAF, para que la siguiente inserción no vuelva a partirlos enseguida
+                let leaves = chunks(s, MAX_LEAF / 2).into_iter().map(Rope::Leaf).collect();
+                *self = Rope::balanced(leaves);
+                true
+            }
+            Rope::Node(left, right, len) => {
+                *len += text_len;
+                let left_len = left.len();
+                if pos <= left_len {
+                    left.insert_at(pos, text, text_len)
+                } else {
+                    right.insert_at(pos - left_len, text, text_len)
+                }
+            }
+        }
+    }
+    
+    /// Borra los `char`s de `start..end`
+    ///
+    /// # Panics
+    ///
+    /// Si `start > end` o `end > self.len()`.
+    pub fn delete(&mut self, start: usize, end: usize) {
+        assert!(
+            start <= end && end <= self.len(),
+            "delete de {}..{} con longitud {}",
+            start,
+            end,
+            self.len()
+        );
+        if start < end {
+            self.delete_range(start, end);
+        }
+    }
+    
+    fn delete_range(&mut self, start: usize, end: usize) {
+        match self {
+            Rope::Leaf(s) => {
+                let (from, to) = (byte_offset(s, start), byte_offset(s, end));
+                s.replace_range(from..to, "");
+            }
+            Rope::Node(left, right, len) => {
+                *len -= end - start;
+                let left_len = left.len();
+                if start < left_len {
+                    left.delete_range(start, end.min(left_len));
+                }
+                if end > left_len {
+                    right.delete_range(start.saturating_sub(left_len), end - left_len);
+                }
+                // Un hijo vacío se elimina y el nodo se sustituye por el otro
+                if left.is_empty() {
+                    *self = std::mem::take(right.as_mut());
+                } else if right.is_empty() {
+                    *self = std::mem::take(left.as_mut());
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Display for Rope {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Pila explícita: las hojas se escriben de izquierda a derecha
+        let mut stack = vec![self];
+        while let Some(node) = stack.pop() {
+            match node {
+                Rope::Leaf(s) => f.write_str(s)?,
+                Rope::Node(left, right, _) => {
+                    stack.push(right);
+                    stack.push(left);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Función que demuestra insertar 10.000 veces en mitad de 1 MB con Rope y con String
+pub fn demonstrate_rope() {
+    println!("\n✅ Demostrando Rope frente a String para editar textos largos...");
+    
+    // Solo ASCII, para que la posición en bytes de String sea la misma que en chars de Rope
+    let base = "Lorem ipsum dolor sit amet. ".repeat(1_000_000 / 28);
+    const INSERTS: usize = 10_000;
+    
+    let start = Instant::now();
+    let mut string = base.clone();
+    for _ in 0..INSERTS {
+        let middle = string.len() / 2;
+        string.insert_str(middle, "<ins>");
+    }
+    black_box(&string);
+    println!("String: {} inserciones en {:?}", INSERTS, start.elapsed());
+    
+    let start = Instant::now();
+    let mut rope = Rope::new(&base);
+    for _ in 0..INSERTS {
+        let middle = rope.len() / 2;
+        rope.insert(middle, "<ins>");
+    }
+    black_box(&rope);
+    println!("Rope:   {} inserciones en {:?} (profundidad {})", INSERTS, start.elapsed(), rope.depth());
+    println!("Mismo texto: {}", rope.to_string() == string);
+    
+    let mut greeting = Rope::new("¡Hola, mundo!");
+    greeting.insert(7, "gran ");
+    greeting.delete(0, 1);
+    println!("'{}': {} chars, char_at(0) = {:?}", greeting, greeting.len(), greeting.char_at(0));
+}
@@ -0,0 +1,80 @@
+//! 🦀 Scoped Timer - Medición de tiempos con RAII
+//!
+//! En lugar de repetir pares `Instant::now()` / `elapsed()`, un guard
+//! mide desde su creación hasta que sale de scope y reporta en `Drop`.
+
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Vec compartido donde los timers registran `(etiqueta, duración)`
+pub type TimingSink = Arc<Mutex<Vec<(String, Duration)>>>;
+
+/// Guard que mide el tiempo del scope que lo contiene
+pub struct ScopedTimer {
+    label: String,
+    start: Instant,
+    sink: Option<TimingSink>,
+}
+
+impl ScopedTimer {
+    /// Timer que imprime la duración al salir de scope
+    pub fn new(label: &str) -> Self {
+        Self {
+            label: label.to_string(),
+            start: Instant::now(),
+            sink: None,
+        }
+    }
+    
+    /// Timer que guarda la duración en `sink` en lugar de imprimirla
+    pub fn with_sink(label: &str, sink: &TimingSink) -> Self {
+        Self {
+            label: label.to_string(),
+            start: Instant::now(),
+            sink: Some(Arc::clone(sink)),
+        }
+    }
+}
+
+impl Drop for ScopedTimer {
+    fn drop(&mut self) {
+        let elapsed = self.start.elapsed();
+        match &self.sink {
+            // CORREGIDO: nunca hacer panic en Drop, ignorar un sink envenenado
+            Some(sink) => {
+                if let Ok(mut records) = sink.lock() {
+                    records.push((self.label.clone(), elapsed));
+                }
+            }
+            None => println!("⏱️  {}: {:?}", self.label, elapsed),
+        }
+    }
+}
+
+/// Función que demuestra ScopedTimer registrando en un sink
+pub fn demonstrate_scoped_timer() {
+    println!("\n✅ Demostrando ScopedTimer (RAII)...");
+    
+    let sink: TimingSink = Arc::new(Mutex::new(Vec::new()));
+    
+    {
+        let _t = ScopedTimer::with_sink("vec build", &sink);
+        let data: Vec<u64> = (0..100_000).collect();
+        println!("Elementos creados: {}", data.len());
+    }
+    
+    {
+        let _t = ScopedTimer::with_sink("sort", &sink);
+        let mut data: Vec<u64> = (0..100_000).rev().collect();
+        data.sort_unstable();
+        println!("Primer elemento tras ordenar: {}", data[0]);
+    }
+    
+    // El guard se guarda en una variable para soltarlo antes que `sink` al final de la función
+    let records = sink.lock();
+    if let Ok(records) = &records {
+        for (label, duration) in records.iter() {
+            println!("Registrado - {}: {:?}", label, duration);
+        }
+    }
+}
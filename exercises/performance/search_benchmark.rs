@@ -0,0 +1,105 @@
+//! 🦀 Search Benchmark - binary_search vs HashSet vs BTreeSet
+//!
+//! `User::find_post` busca en un `Vec<u32>` ordenado con `binary_search`.
+//! Este benchmark lo compara con las alternativas basadas en hashing y
+//! en árboles para colecciones de 10, 100, 1000 y 10_000 IDs.
+//!
+//! Ejecutar con: cargo bench --features benchmarks --bench search_benchmark
+//!
+//! # Cuándo usar cada estructura
+//!
+//! - **`Vec` ordenado + `binary_search`**: datos que cambian poco y se
+//!   consultan mucho. Memoria contigua (sin overhead por elemento) y
+//!   muy buena localidad de caché; con pocos elementos suele ganar a todo.
+//!   Insertar manteniendo el orden es O(n).
+//! - **`HashSet`**: colecciones grandes con inserciones y consultas
+//!   mezcladas. Búsqueda O(1) media, pero paga el coste de hashear cada
+//!   clave (SipHash por defecto) y no conserva orden.
+//! - **`BTreeSet`**: cuando además hace falta recorrer en orden o hacer
+//!   consultas por rango (`range`). O(log n) como `binary_search`, pero
+//!   con nodos en el heap y peor localidad.
+//!
+//! El throughput se muestra en elementos por segundo: cada elemento es
+//! una búsqueda.
+
+use std::collections::{BTreeSet, HashSet};
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use rand::rngs::SmallRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+
+/// Semilla fija: misma colección y mismas consultas en cada ejecución
+const SEED: u64 = 0x5EED_CAFE;
+const SIZES: [usize; 4] = [10, 100, 1000, 10_000];
+const QUERIES: usize = 1000;
+
+/// IDs en orden de inserción aleatorio y consultas (mitad aciertos, mitad fallos)
+fn dataset(size: usize) -> (Vec<u32>, Vec<u32>) {
+    let mut rng = SmallRng::seed_from_u64(SEED);
+    
+    // IDs pares; los impares nunca están, así se garantizan los fallos
+    let mut ids: Vec<u32> = (0..size as u32).map(|i| i * 2).collect();
+    ids.shuffle(&mut rng);
+    
+    let queries = (0..QUERIES)
+        .map(|i| {
+            let id = rng.gen_range(0..size as u32) * 2;
+            if i % 2 == 0 { id } else { id + 1 }
+        })
+        .collect();
+    
+    (ids, queries)
+}
+
+fn bench_binary_search(c: &mut Criterion) {
+    let mut group = c.benchmark_group("vec_binary_search");
+    group.throughput(Throughput::Elements(QUERIES as u64));
+    
+    for size in SIZES {
+        let (mut ids, queries) = dataset(size);
+        ids.sort_unstable();
+        
+        group.bench_with_input(BenchmarkId::from_parameter(size), &queries, |b, queries| {
+            b.iter(|| queries.iter().filter(|q| ids.binary_search(black_box(q)).is_ok()).count())
+        });
+    }
+    
+    group.finish();
+}
+
+fn bench_hash_set(c: &mut Criterion) {
+    let mut group = c.benchmark_group("hash_set");
+    group.throughput(Throughput::Elements(QUERIES as u64));
+    
+    for size in SIZES {
+        let (ids, queries) = dataset(size);
+        let set: HashSet<u32> = ids.into_iter().collect();
+        
+        group.bench_with_input(BenchmarkId::from_parameter(size), &queries, |b, queries| {
+            b.iter(|| queries.iter().filter(|q| set.contains(black_box(q))).count())
+        });
+    }
+    
+    group.finish();
+}
+
+fn bench_btree_set(c: &mut Criterion) {
+    let mut group = c.benchmark_group("btree_set");
+    group.throughput(Throughput::Elements(QUERIES as u64));
+    
+    for size in SIZES {
+        let (ids, queries) = dataset(size);
+        let set: BTreeSet<u32> = ids.into_iter().collect();
+        
+        group.bench_with_input(BenchmarkId::from_parameter(size), &queries, |b, queries| {
+            b.iter(|| queries.iter().filter(|q| set.contains(black_box(q))).count())
+        });
+    }
+    
+    group.finish();
+}
+
+criterion_group!(benches, bench_binary_search, bench_hash_set, bench_btree_set);
+criterion_main!(benches);
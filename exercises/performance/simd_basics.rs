@@ -0,0 +1,93 @@
+//! 🦀 SIMD Basics - Instrucciones específicas de la CPU con `asm!`
+//!
+//! Sin `-C target-cpu=native` (o `target-feature=+popcnt`), el
+//! compilador no puede asumir que la CPU tiene `popcnt` y
+//! `count_ones()` se compila como una secuencia de desplazamientos y
+//! máscaras. Con `asm!` se emite la instrucción directamente, pero solo
+//! es correcto si la CPU la soporta: por eso `popcount` lo comprueba en
+//! tiempo de ejecución y cae a la versión portable si no.
+
+use std::hint::black_box;
+use std::time::Instant;
+
+use super::hyperloglog::xorshift;
+use super::User;
+
+/// Cuenta los bits a 1 con la instrucción `popcnt`
+///
+/// # Safety
+///
+/// La CPU debe soportar `popcnt` (`is_x86_feature_detected!("popcnt")`);
+/// si no, la instrucción es ilegal y el proceso termina con SIGILL.
+#[cfg(target_arch = "x86_64")]
+pub unsafe fn popcount_asm(x: u64) -> u32 {
+    let count: u64;
+    // SAFETY: quien llama garantiza que `popcnt` está disponible. La
+    // instrucción solo lee `x`, escribe `count` y modifica los flags; no
+    // toca memoria ni la pila.
+    unsafe {
+        std::arch::asm!(
+            "popcnt {count}, {x}",
+            x = in(reg) x,
+            count = lateout(reg) count,
+            options(pure, nomem, nostack),
+        );
+    }
+    count as u32
+}
+
+/// Versión portable; el compilador elige las instrucciones según el target
+pub fn popcount_fallback(x: u64) -> u32 {
+    x.count_ones()
+}
+
+/// `popcnt` si la CPU lo tiene, `count_ones` en cualquier otro caso
+pub fn popcount(x: u64) -> u32 {
+    #[cfg(target_arch = "x86_64")]
+    {
+        // La detección se cachea en la primera llamada; después es una lectura atómica
+        if std::arch::is_x86_feature_detected!("popcnt") {
+            // SAFETY: se acaba de comprobar que la CPU soporta `popcnt`.
+            return unsafe { popcount_asm(x) };
+        }
+    }
+    popcount_fallback(x)
+}
+
+/// Bits a 1 entre todos los IDs de posts, tratándolos como un bitmap
+pub fn count_set_bits_in_posts(user: &User) -> u32 {
+    user.get_posts().iter().map(|&post_id| popcount(post_id as u64)).sum()
+}
+
+/// Función que demuestra `popcnt` con `asm!` frente a `count_ones`
+pub fn demonstrate_popcount() {
+    println!("\n✅ Demostrando POPCNT con inline assembly...");
+    
+    let mut state = 0x9E37_79B9_7F4A_7C15;
+    let values: Vec<u64> = (0..1_000_000).map(|_| xorshift(&mut state)).collect();
+    
+    let start = Instant::now();
+    let fallback_total: u64 = values.iter().map(|&x| popcount_fallback(black_box(x)) as u64).sum();
+    println!("count_ones: {} bits en {:?}", fallback_total, start.elapsed());
+    
+    #[cfg(target_arch = "x86_64")]
+    {
+        if std::arch::is_x86_feature_detected!("popcnt") {
+            let start = Instant::now();
+            // SAFETY: se acaba de comprobar que la CPU soporta `popcnt`.
+            let asm_total: u64 = values.iter().map(|&x| unsafe { popcount_asm(black_box(x)) } as u64).sum();
+            println!("popcnt (asm): {} bits en {:?}", asm_total, start.elapsed());
+            println!("Mismo resultado: {}", asm_total == fallback_total);
+        } else {
+            println!("Esta CPU no soporta popcnt; solo se usa count_ones");
+        }
+    }
+    #[cfg(not(target_arch = "x86_64"))]
+    println!("asm! con popcnt solo está disponible en x86_64");
+    
+    let mut user = User::new(1, "Alice".to_string(), "alice@example.com".to_string());
+    for post_id in [0b1, 0b1011, 0xFF] {
+        user.add_post(post_id);
+    }
+    println!("Bits a 1 en los posts {:?}: {}", user.get_posts(), count_set_bits_in_posts(&user));
+}
@@ -0,0 +1,106 @@
+//! 🦀 SIMD Sum - Sumar IDs de posts de 8 en 8 con AVX2
+//!
+//! `std::simd` (`Simd::<u32, 8>`) todavía necesita nightly y
+//! `#![feature(portable_simd)]`. En stable, las intrínsecas de
+//! `std::arch::x86_64` dan el mismo resultado: `_mm256_*` opera sobre
+//! registros de 256 bits, es decir, 8 `u32` por instrucción.
+//!
+//! Sumar `u32` en lanes de `u32` desbordaría enseguida, así que cada
+//! mitad del registro se amplía a 4 lanes `u64` antes de acumular. Como
+//! en `simd_basics`, AVX2 se detecta en tiempo de ejecución en lugar de
+//! exigir `-C target-feature=+avx2` al compilar.
+
+use std::hint::black_box;
+use std::time::Instant;
+
+use super::hyperloglog::xorshift;
+use super::User;
+
+/// Suma de referencia, un elemento cada vez
+pub fn sum_u32_scalar(data: &[u32]) -> u64 {
+    data.iter().map(|&x| x as u64).sum()
+}
+
+/// Suma con AVX2: bloques de 8 elementos y el resto con `sum_u32_scalar`
+///
+/// # Safety
+///
+/// La CPU debe soportar AVX2 (`is_x86_feature_detected!("avx2")`).
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+pub unsafe fn sum_u32_avx2(data: &[u32]) -> u64 {
+    use std::arch::x86_64::{
+        __m256i, _mm256_add_epi64, _mm256_castsi256_si128, _mm256_cvtepu32_epi64, _mm256_extracti128_si256,
+        _mm256_loadu_si256, _mm256_setzero_si256, _mm256_storeu_si256,
+    };
+    
+    let chunks = data.chunks_exact(8);
+    let remainder = chunks.remainder();
+    let mut low = _mm256_setzero_si256();
+    let mut high = _mm256_setzero_si256();
+    
+    for chunk in chunks {
+        // SAFETY: `chunk` tiene exactamente 8 `u32` (32 bytes) y `loadu` no exige alineación
+        let values = unsafe { _mm256_loadu_si256(chunk.as_ptr() as *const __m256i) };
+        low = _mm256_add_epi64(low, _mm256_cvtepu32_epi64(_mm256_castsi256_si128(values)));
+        high = _mm256_add_epi64(high, _mm256_cvtepu32_epi64(_mm256_extracti128_si256::<1>(values)));
+    }
+    
+    let mut lanes = [0u64; 4];
+    // SAFETY: `lanes` son 4 `u64` (32 bytes) y `storeu` no exige alineación
+    unsafe { _mm256_storeu_si256(lanes.as_mut_ptr() as *mut __m256i, _mm256_add_epi64(low, high)) };
+    // CORREGIDO: Los últimos `len % 8` elementos no caben en un bloque y se suman aparte
+    lanes.iter().sum::<u64>() + sum_u32_scalar(remainder)
+}
+
+/// AVX2 si la CPU lo tiene, `sum_u32_scalar` en cualquier otro caso
+pub fn sum_u32_simd(data: &[u32]) -> u64 {
+    #[cfg(target_arch = "x86_64")]
+    {
+        if std::arch::is_x86_feature_detected!("avx2") {
+            // SAFETY: se acaba de comprobar que la CPU soporta AVX2.
+            return unsafe { sum_u32_avx2(data) };
+        }
+    }
+    sum_u32_scalar(data)
+}
+
+/// Suma de los IDs de posts de todos los usuarios
+pub fn sum_post_ids(users: &[User]) -> u64 {
+    users.iter().map(|user| sum_u32_simd(user.get_posts())).sum()
+}
+
+/// Función que demuestra sumar 1M de IDs de posts con AVX2 frente a un bucle escalar
+pub fn demonstrate_simd_sum() {
+    println!("\n✅ Demostrando suma SIMD de IDs de posts...");
+    
+    let mut state = 0x2545_F491_4F6C_DD1D;
+    let post_ids: Vec<u32> = (0..1_000_000).map(|_| xorshift(&mut state) as u32).collect();
+    
+    let start = Instant::now();
+    let scalar = sum_u32_scalar(black_box(&post_ids));
+    println!("Escalar: {} en {:?}", scalar, start.elapsed());
+    
+    let start = Instant::now();
+    let simd = sum_u32_simd(black_box(&post_ids));
+    println!("SIMD:    {} en {:?}", simd, start.elapsed());
+    #[cfg(target_arch = "x86_64")]
+    if !std::arch::is_x86_feature_detected!("avx2") {
+        println!("Esta CPU no soporta AVX2; las dos versiones usan el bucle escalar");
+    }
+    #[cfg(not(target_arch = "x86_64"))]
+    println!("Las intrínsecas AVX2 solo están disponibles en x86_64");
+    println!("Mismo resultado: {}", scalar == simd);
+    
+    let users: Vec<User> = (1..=3)
+        .map(|i| {
+            let mut user = User::new(i, format!("User{}", i), format!("user{}@example.com", i));
+            // 11 posts: un bloque de 8 y 3 en el resto
+            for post in 0..11 {
+                user.add_post(i * 100 + post);
+            }
+            user
+        })
+        .collect();
+    println!("Suma de los posts de {} usuarios: {}", users.len(), sum_post_ids(&users));
+}
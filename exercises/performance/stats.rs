@@ -0,0 +1,105 @@
+//! 🦀 Stats - Histogramas para ver la distribución de un benchmark
+//!
+//! La media de 10.000 mediciones esconde si todas tardan parecido o si
+//! unas pocas tardan diez veces más. Un histograma reparte los valores
+//! en intervalos del mismo ancho entre el mínimo y el máximo y cuenta
+//! cuántos caen en cada uno.
+
+use std::fmt;
+use std::hint::black_box;
+use std::time::Instant;
+
+/// Error al construir un histograma
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HistogramError {
+    /// Se pidieron 0 intervalos
+    ZeroBuckets,
+    /// NaN o infinito en la posición indicada: no tiene sitio entre el mínimo y el máximo
+    NonFinite { index: usize, value: f64 },
+}
+
+impl fmt::Display for HistogramError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            HistogramError::ZeroBuckets => write!(f, "un histograma necesita al menos un intervalo"),
+            HistogramError::NonFinite { index, value } => {
+                write!(f, "valor no finito {} en la posición {}", value, index)
+            }
+        }
+    }
+}
+
+impl std::error::Error for HistogramError {}
+
+/// `(low, high, count)` de cada intervalo, de `min` a `max` de `values`
+///
+/// Los intervalos son `[low, high)` salvo el último, que incluye `max`.
+/// Sin valores el resultado está vacío; si todos son iguales no hay
+/// ancho que repartir y se devuelve un solo intervalo `(v, v, n)`.
+pub fn histogram(values: &[f64], buckets: usize) -> Result<Vec<(f64, f64, usize)>, HistogramError> {
+    if buckets == 0 {
+        return Err(HistogramError::ZeroBuckets);
+    }
+    if let Some((index, &value)) = values.iter().enumerate().find(|(_, value)| !value.is_finite()) {
+        return Err(HistogramError::NonFinite { index, value });
+    }
+    let Some(&first) = values.first() else {
+        return Ok(Vec::new());
+    };
+    
+    let (min, max) = values
+        .iter()
+        .fold((first, first), |(min, max), &value| (min.min(value), max.max(value)));
+    if min == max {
+        return Ok(vec![(min, max, values.len())]);
+    }
+    
+    let width = (max - min) / buckets as f64;
+    let mut counts = vec![0usize; buckets];
+    for &value in values {
+        // CORREGIDO: `max` daría el índice `buckets`, fuera del vector; va al último intervalo
+        let index = (((value - min) / width) as usize).min(buckets - 1);
+        counts[index] += 1;
+    }
+    
+    Ok(counts
+        .into_iter()
+        .enumerate()
+        .map(|(i, count)| {
+            let low = min + width * i as f64;
+            // El último límite es `max` exacto, sin el error de redondeo de sumar anchos
+            let high = if i + 1 == buckets { max } else { min + width * (i + 1) as f64 };
+            (low, high, count)
+        })
+        .collect())
+}
+
+/// Función que demuestra el histograma de los tiempos de 10.000 ordenaciones
+pub fn demonstrate_histogram() {
+    println!("\n✅ Demostrando histograma de tiempos de un benchmark...");
+    
+    let mut data: Vec<u32> = (0..256).rev().collect();
+    let timings: Vec<f64> = (0..10_000)
+        .map(|_| {
+            data.reverse();
+            let start = Instant::now();
+            black_box(&mut data).sort_unstable();
+            start.elapsed().as_nanos() as f64 / 1_000.0
+        })
+        .collect();
+    
+    match histogram(&timings, 8) {
+        Ok(buckets) => {
+            let largest = buckets.iter().map(|&(_, _, count)| count).max().unwrap_or(1);
+            for (low, high, count) in buckets {
+                // Barras de como mucho 40 caracteres, proporcionales al intervalo más lleno
+                let bar = "#".repeat((count * 40).div_ceil(largest));
+                println!("{:>8.2} - {:>8.2} µs | {:>5} {}", low, high, count, bar);
+            }
+        }
+        Err(e) => println!("Error: {}", e),
+    }
+    
+    println!("Todos iguales: {:?}", histogram(&[2.5; 4], 8));
+    println!("Sin intervalos: {:?}", histogram(&timings, 0).map_err(|e| e.to_string()));
+}
@@ -0,0 +1,83 @@
+//! 🦀 Stopwatch - Cronómetro con vueltas para benchmarks por fases
+//!
+//! `ScopedTimer` mide un scope completo. Cuando una ejecución tiene
+//! varias fases seguidas ("build", "search", "cleanup"), un cronómetro
+//! con vueltas las separa sin anidar scopes: cada `lap` cierra la fase
+//! actual y empieza la siguiente.
+
+use std::time::{Duration, Instant};
+
+/// Cronómetro que guarda el instante de cada vuelta
+#[derive(Debug, Clone)]
+pub struct Stopwatch {
+    start: Instant,
+    /// `(etiqueta, tiempo acumulado desde start)` de cada vuelta, en orden
+    laps: Vec<(String, Duration)>,
+}
+
+impl Stopwatch {
+    /// Empieza a contar ya
+    pub fn start() -> Self {
+        Self {
+            start: Instant::now(),
+            laps: Vec::new(),
+        }
+    }
+    
+    /// Cierra la fase actual con `label` y devuelve lo que duró
+    pub fn lap(&mut self, label: &str) -> Duration {
+        let total = self.start.elapsed();
+        let previous = self.laps.last().map_or(Duration::ZERO, |&(_, at)| at);
+        self.laps.push((label.to_string(), total));
+        total - previous
+    }
+    
+    /// Duración de cada fase, en el orden en que se marcaron
+    pub fn report(&self) -> Vec<(String, Duration)> {
+        let mut previous = Duration::ZERO;
+        self.laps
+            .iter()
+            .map(|(label, at)| {
+                let phase = *at - previous;
+                previous = *at;
+                (label.clone(), phase)
+            })
+            .collect()
+    }
+    
+    /// Tiempo acumulado desde `start` al final de cada fase
+    pub fn cumulative_report(&self) -> Vec<(String, Duration)> {
+        self.laps.clone()
+    }
+    
+    /// Tiempo total desde `start`, incluida la fase aún abierta
+    pub fn elapsed(&self) -> Duration {
+        self.start.elapsed()
+    }
+}
+
+/// Función que demuestra medir un benchmark por fases con vueltas
+pub fn demonstrate_stopwatch() {
+    println!("\n✅ Demostrando Stopwatch con vueltas por fase...");
+    
+    let mut stopwatch = Stopwatch::start();
+    
+    let mut ids: Vec<u32> = (0..200_000u32).map(|i| i.wrapping_mul(2_654_435_761)).collect();
+    ids.sort_unstable();
+    stopwatch.lap("build");
+    
+    let found = (0..50_000u32)
+        .filter(|i| ids.binary_search(&i.wrapping_mul(2_654_435_761)).is_ok())
+        .count();
+    stopwatch.lap("search");
+    
+    drop(ids);
+    stopwatch.lap("cleanup");
+    
+    println!("Encontrados: {}", found);
+    // CORREGIDO: Cada fase se mide por separado en lugar de un único total
+    for ((label, phase), (_, cumulative)) in stopwatch.report().iter().zip(stopwatch.cumulative_report()) {
+        println!("   {:<8} {:>12?} (acumulado {:?})", label, phase, cumulative);
+    }
+    println!("Total: {:?}", stopwatch.elapsed());
+}
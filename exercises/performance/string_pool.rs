@@ -0,0 +1,118 @@
+//! 🦀 String Pool - Strings internadas con referencias que no se invalidan
+//!
+//! Un interner sobre `HashSet<String>` devuelve `&str` que toman prestado
+//! todo el set: para internar otra string hace falta `&mut self`, y eso
+//! no compila mientras se conserve la referencia anterior. Además, al
+//! crecer el set los `String` se mueven (aunque sus bytes no).
+//!
+//! `StringPool` guarda cada string en su propio `Box<str>` dentro de un
+//! `Vec`. Cuando el `Vec` crece solo se mueven los punteros a las cajas,
+//! nunca el texto, y las cajas no se liberan hasta que se destruye el
+//! pool. Por eso `intern` puede recibir `&self` y devolver un `&str` que
+//! vive tanto como el pool, aunque después se internen muchas más.
+
+use std::cell::RefCell;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::time::Instant;
+
+/// Pool de strings sin duplicados con direcciones estables
+#[derive(Debug, Default)]
+pub struct StringPool {
+    /// Solo se añaden cajas al final; ninguna se quita ni se modifica
+    strings: RefCell<Vec<Box<str>>>,
+    /// Hash del texto -> índices en `strings` con ese hash
+    lookup: RefCell<HashMap<u64, Vec<usize>>>,
+}
+
+fn hash_str(s: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    s.hash(&mut hasher);
+    hasher.finish()
+}
+
+impl StringPool {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// String número `index`, con la vida del pool en lugar de la del `borrow()`
+    fn get(&self, index: usize) -> &str {
+        let strings = self.strings.borrow();
+        let s: *const str = &*strings[index];
+        // SAFETY: `s` apunta al contenido de un `Box<str>` del pool. Las cajas no se
+        // quitan, no se modifican y no se liberan hasta el drop del pool, y un `push`
+        // en el `Vec` mueve la caja pero no su contenido; el `&str` vale mientras `&self`
+        unsafe { &*s }
+    }
+    
+    /// Devuelve la copia del pool de `s`, creándola la primera vez
+    ///
+    /// Toma `&self`, así que un `&str` devuelto antes sigue siendo usable
+    /// mientras se internan otras strings.
+    pub fn intern(&self, s: &str) -> &str {
+        let hash = hash_str(s);
+        let existing = self
+            .lookup
+            .borrow()
+            .get(&hash)
+            .and_then(|indices| indices.iter().copied().find(|&index| self.get(index) == s));
+        if let Some(index) = existing {
+            return self.get(index);
+        }
+        
+        let index = {
+            let mut strings = self.strings.borrow_mut();
+            strings.push(s.into());
+            strings.len() - 1
+        };
+        self.lookup.borrow_mut().entry(hash).or_default().push(index);
+        self.get(index)
+    }
+    
+    /// Número de strings distintas
+    pub fn len(&self) -> usize {
+        self.strings.borrow().len()
+    }
+    
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    
+    /// Strings del pool en orden de inserción
+    ///
+    /// No mantiene el `RefCell` prestado: se puede internar mientras se
+    /// recorre, y las strings nuevas no aparecen en este recorrido.
+    pub fn iter(&self) -> impl Iterator<Item = &str> + '_ {
+        (0..self.len()).map(move |index| self.get(index))
+    }
+}
+
+/// Función que demuestra internar los tokens repetidos de un log
+pub fn demonstrate_string_pool() {
+    println!("\n✅ Demostrando StringPool con referencias estables...");
+    
+    let pool = StringPool::new();
+    let levels = ["INFO", "WARN", "ERROR", "DEBUG"];
+    let services = ["auth", "posts", "users", "feed", "search"];
+    
+    // CORREGIDO: `first` sigue prestado mientras se internan 200.000 tokens más
+    let first = pool.intern("INFO");
+    let start = Instant::now();
+    let tokens: Vec<&str> = (0..100_000)
+        .flat_map(|i| [levels[i % levels.len()], services[i % services.len()]])
+        .map(|token| pool.intern(token))
+        .collect();
+    println!("{} tokens internados en {:?}, {} distintos", tokens.len(), start.elapsed(), pool.len());
+    
+    // Misma string, misma dirección: comparar punteros basta para saber si son iguales
+    println!(
+        "'{}' sigue en {:p}; tokens[0] está en {:p} (misma caja: {})",
+        first,
+        first.as_ptr(),
+        tokens[0].as_ptr(),
+        std::ptr::eq(first, tokens[0])
+    );
+    println!("Pool: {:?} (vacío: {})", pool.iter().collect::<Vec<_>>(), pool.is_empty());
+}
@@ -0,0 +1,224 @@
+//! 🦀 Suffix Array - Buscar subcadenas en textos grandes sin recorrerlos enteros
+//!
+//! `str::contains` recorre el texto en cada búsqueda. Un suffix array
+//! guarda las posiciones de todos los sufijos del texto ordenados
+//! alfabéticamente: los sufijos que empiezan por un patrón quedan
+//! contiguos, así que basta una búsqueda binaria de O(m log n) para
+//! encontrarlos todos. A cambio hay que construirlo una vez y ocupa
+//! varias veces lo que el texto.
+
+use std::hint::black_box;
+use std::ops::Range;
+use std::time::Instant;
+
+use super::hyperloglog::xorshift;
+
+/// Texto, sus sufijos ordenados y el prefijo común de cada par de sufijos vecinos
+#[derive(Debug, Clone)]
+pub struct SuffixArray {
+    text: Vec<u8>,
+    /// `sa[i]` es la posición en `text` del i-ésimo sufijo en orden alfabético
+    sa: Vec<usize>,
+    /// `lcp[i]` es la longitud del prefijo común de los sufijos `sa[i - 1]` y `sa[i]`; `lcp[0]` es 0
+    lcp: Vec<usize>,
+}
+
+/// Ordena `order` de forma estable por `rank[i]`, con rangos en `0..classes`
+fn counting_sort(order: &[usize], rank: &[usize], classes: usize) -> Vec<usize> {
+    let mut starts = vec![0usize; classes + 1];
+    for &i in order {
+        starts[rank[i] + 1] += 1;
+    }
+    for class in 1..=classes {
+        starts[class] += starts[class - 1];
+    }
+    let mut sorted = vec![0usize; order.len()];
+    for &i in order {
+        sorted[starts[rank[i]]] = i;
+        starts[rank[i]] += 1;
+    }
+    sorted
+}
+
+impl SuffixArray {
+    /// Construye el suffix array por duplicación de prefijos en O(n log n)
+    ///
+    /// En cada ronda los sufijos están ordenados por sus primeros `k`
+    /// bytes; el par (rango de `i`, rango de `i + k`) los ordena por los
+    /// primeros `2k`. Con dos counting sorts estables por ronda, y como
+    /// mucho log n rondas, no hace falta ninguna comparación de sufijos.
+    pub fn new(text: &[u8]) -> Self {
+        let n = text.len();
+        if n == 0 {
+            return Self { text: Vec::new(), sa: Vec::new(), lcp: Vec::new() };
+        }
+        // Rango 0 reservado para "el sufijo se acabó", que va antes que cualquier byte
+        let mut rank: Vec<usize> = text.iter().map(|&b| b as usize + 1).collect();
+        let mut classes = 257;
+        let mut sa = counting_sort(&(0..n).collect::<Vec<_>>(), &rank, classes);
+        let mut next_rank = vec![0usize; n];
+        let mut k = 1;
+        
+        loop {
+            // Orden por la segunda mitad: primero los que no la tienen, luego según `sa`
+            let mut by_second: Vec<usize> = (n.saturating_sub(k)..n).collect();
+            by_second.extend(sa.iter().filter(|&&i| i >= k).map(|&i| i - k));
+            // CORREGIDO: El sort estable por la primera mitad conserva el orden por la segunda
+            sa = counting_sort(&by_second, &rank, classes);
+            
+            let key = |i: usize| (rank[i], rank.get(i + k).copied().unwrap_or(0));
+            next_rank[sa[0]] = 1;
+            for pair in sa.windows(2) {
+                next_rank[pair[1]] = next_rank[pair[0]] + usize::from(key(pair[0]) != key(pair[1]));
+            }
+            std::mem::swap(&mut rank, &mut next_rank);
+            classes = rank[sa[n - 1]] + 1;
+            // Todos los rangos distintos: ningún par de sufijos comparte los primeros 2k bytes
+            if classes == n + 1 {
+                break;
+            }
+            k *= 2;
+        }
+        
+        let lcp = Self::kasai(text, &sa);
+        Self { text: text.to_vec(), sa, lcp }
+    }
+    
+    /// Prefijos comunes de sufijos vecinos en O(n) (algoritmo de Kasai)
+    ///
+    /// Recorre los sufijos en orden de posición: quitar el primer byte a
+    /// un sufijo pierde como mucho 1 del prefijo común con su vecino.
+    fn kasai(text: &[u8], sa: &[usize]) -> Vec<usize> {
+        let n = text.len();
+        let mut position = vec![0usize; n];
+        for (index, &suffix) in sa.iter().enumerate() {
+            position[suffix] = index;
+        }
+        let mut lcp = vec![0usize; n];
+        let mut common = 0;
+        for suffix in 0..n {
+            if position[suffix] == 0 {
+                common = 0;
+                continue;
+            }
+            let previous = sa[position[suffix] - 1];
+            while suffix + common < n && previous + common < n && text[suffix + common] == text[previous + common] {
+                common += 1;
+            }
+            lcp[position[suffix]] = common;
+            common = common.saturating_sub(1);
+        }
+        lcp
+    }
+    
+    pub fn len(&self) -> usize {
+        self.text.len()
+    }
+    
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+    
+    /// Primeros `pattern.len()` bytes del sufijo que empieza en `suffix`
+    fn prefix(&self, suffix: usize, pattern: &[u8]) -> &[u8] {
+        let rest = &self.text[suffix..];
+        &rest[..pattern.len().min(rest.len())]
+    }
+    
+    /// Rango de `sa` con los sufijos que empiezan por `pattern`
+    ///
+    /// Su longitud es el número de apariciones, incluidas las que se
+    /// solapan ("aa" aparece 3 veces en "aaaa"). Un patrón vacío es
+    /// prefijo de todos los sufijos.
+    pub fn find_all(&self, pattern: &[u8]) -> Range<usize> {
+        let start = self.sa.partition_point(|&suffix| self.prefix(suffix, pattern) < pattern);
+        let end = self.sa.partition_point(|&suffix| self.prefix(suffix, pattern) <= pattern);
+        start..end
+    }
+    
+    /// Posición de una aparición de `pattern`, no necesariamente la primera del texto
+    pub fn find(&self, pattern: &[u8]) -> Option<usize> {
+        let range = self.find_all(pattern);
+        self.sa.get(range.start).filter(|_| !range.is_empty()).copied()
+    }
+    
+    /// Posiciones de todas las apariciones de `pattern`, en orden alfabético de sufijo
+    pub fn positions(&self, pattern: &[u8]) -> &[usize] {
+        &self.sa[self.find_all(pattern)]
+    }
+    
+    /// Subcadena más larga que aparece al menos dos veces
+    pub fn longest_repeated(&self) -> &[u8] {
+        match self.lcp.iter().enumerate().max_by_key(|&(_, &common)| common) {
+            Some((index, &common)) => &self.text[self.sa[index]..self.sa[index] + common],
+            None => &[],
+        }
+    }
+}
+
+/// Número de bytes iguales a `needle`
+#[cfg(feature = "simd-csv")]
+fn count_byte(haystack: &[u8], needle: u8) -> usize {
+    memchr::memchr_iter(needle, haystack).count()
+}
+
+#[cfg(not(feature = "simd-csv"))]
+fn count_byte(haystack: &[u8], needle: u8) -> usize {
+    haystack.iter().filter(|&&b| b == needle).count()
+}
+
+/// Función que demuestra buscar en 10 MB de texto con un suffix array frente a memchr y str::find
+pub fn demonstrate_suffix_array() {
+    println!("\n✅ Demostrando suffix array para búsquedas en textos grandes...");
+    
+    // Palabras al azar: con un texto repetido los sufijos compartirían prefijos enormes
+    const WORDS: [&str; 12] = [
+        "rust", "ownership", "borrow", "lifetime", "trait", "async", "thread", "mutex", "arena", "slice", "vector",
+        "zero",
+    ];
+    let mut state = 0x9E37_79B9_7F4A_7C15;
+    let mut text = String::with_capacity(10_000_000 + 16);
+    while text.len() < 10_000_000 {
+        text.push_str(WORDS[(xorshift(&mut state) % WORDS.len() as u64) as usize]);
+        text.push(' ');
+    }
+    
+    let start = Instant::now();
+    let index = SuffixArray::new(text.as_bytes());
+    println!("Construcción para {} MB: {:?}", index.len() / 1_000_000, start.elapsed());
+    
+    // Un solo byte: memchr recorre el texto; el suffix array solo hace dos búsquedas binarias
+    let start = Instant::now();
+    let scanned = count_byte(black_box(text.as_bytes()), b'z');
+    println!("Contar 'z' recorriendo el texto: {} en {:?}", scanned, start.elapsed());
+    #[cfg(not(feature = "simd-csv"))]
+    println!("Compilar con --features simd-csv para recorrerlo con memchr");
+    let start = Instant::now();
+    let indexed = index.find_all(black_box(b"z")).len();
+    println!("Contar 'z' con el suffix array:  {} en {:?}", indexed, start.elapsed());
+    
+    // 100 patrones de 20 bytes sacados del propio texto, así que todos aparecen
+    let patterns: Vec<&str> = (0..100)
+        .map(|_| {
+            // Solo ASCII: cualquier posición es un límite de char
+            let at = (xorshift(&mut state) % (text.len() as u64 - 20)) as usize;
+            &text[at..at + 20]
+        })
+        .collect();
+    let start = Instant::now();
+    let found_by_scan = patterns.iter().filter(|pattern| black_box(&text).find(*pattern).is_some()).count();
+    println!("str::find, 100 patrones de 20 bytes:    {} en {:?}", found_by_scan, start.elapsed());
+    let start = Instant::now();
+    let found_by_index = patterns.iter().filter(|pattern| index.find(pattern.as_bytes()).is_some()).count();
+    println!("SuffixArray::find, los mismos patrones: {} en {:?}", found_by_index, start.elapsed());
+    
+    let small = SuffixArray::new(b"banana");
+    println!(
+        "'ana' en 'banana': {} veces, en {:?}; repetida más larga: {:?}",
+        small.find_all(b"ana").len(),
+        small.positions(b"ana"),
+        String::from_utf8_lossy(small.longest_repeated())
+    );
+    let empty = SuffixArray::new(b"");
+    println!("Texto vacío: is_empty {}, find(\"a\") = {:?}", empty.is_empty(), empty.find(b"a"));
+}
@@ -0,0 +1,116 @@
+//! 🦀 Zero Copy - Parsear CSV sin copiar los campos
+//!
+//! `parse_users_csv` crea un `String` por nombre y por email. Si el
+//! buffer original sigue vivo mientras se usan los datos, basta con
+//! guardar `&str` que apuntan dentro de él: el lifetime `'a` de
+//! `UserRef<'a>` garantiza que el buffer no se libera antes.
+
+use std::time::Instant;
+
+use super::allocator::allocation_count;
+use super::csv_users::{parse_users_csv, serialize_users_csv, CsvParseError, HEADER};
+use super::User;
+
+/// Usuario que toma prestados sus campos de texto del CSV original
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UserRef<'a> {
+    pub id: u32,
+    pub name: &'a str,
+    pub email: &'a str,
+}
+
+/// Recorre las filas de `csv` sin reservar memoria por campo
+///
+/// Es perezoso: cada fila se parsea al pedirla, así que se puede parar
+/// en el primer error o procesar archivos sin acumular resultados. La
+/// columna `posts` se valida como presente pero no se parsea.
+pub fn parse_users_zero_copy<'a>(csv: &'a str) -> impl Iterator<Item = Result<UserRef<'a>, CsvParseError>> + 'a {
+    csv.lines()
+        .enumerate()
+        .filter(|&(index, line)| !(line.trim().is_empty() || (index == 0 && line == HEADER)))
+        .map(|(index, line)| parse_row_ref(line, index + 1))
+}
+
+fn parse_row_ref(line: &str, line_number: usize) -> Result<UserRef<'_>, CsvParseError> {
+    // Solo el camino de error reserva memoria para el mensaje
+    let invalid = |field: &'static str, message: String| CsvParseError::InvalidField {
+        line: line_number,
+        field,
+        message,
+    };
+    
+    let mut columns = line.split(',');
+    let mut next = |field: &'static str| {
+        columns
+            .next()
+            .ok_or_else(|| invalid(field, "campo ausente".to_string()))
+    };
+    
+    let id_str = next("id")?;
+    let name = next("name")?;
+    let email = next("email")?;
+    next("posts")?;
+    if columns.next().is_some() {
+        return Err(invalid("posts", "columnas de más en la fila".to_string()));
+    }
+    
+    let id = id_str
+        .trim()
+        .parse()
+        .map_err(|_| invalid("id", format!("'{}' no es un número válido", id_str)))?;
+    
+    Ok(UserRef { id, name, email })
+}
+
+/// Función que demuestra parsear CSV con slices en lugar de Strings
+pub fn demonstrate_zero_copy_parsing() {
+    println!("\n✅ Demostrando parseo CSV zero-copy...");
+    
+    let users: Vec<User> = (1..=100_000)
+        .map(|i| User::new(i, format!("User{}", i), format!("user{}@example.com", i)))
+        .collect();
+    let csv = serialize_users_csv(&users);
+    drop(users);
+    
+    let allocs_before = allocation_count();
+    let start = Instant::now();
+    let owned = parse_users_csv(&csv);
+    let owned_time = start.elapsed();
+    let owned_allocs = allocation_count() - allocs_before;
+    
+    let allocs_before = allocation_count();
+    let start = Instant::now();
+    let borrowed: Result<Vec<UserRef>, CsvParseError> = parse_users_zero_copy(&csv).collect();
+    let borrowed_time = start.elapsed();
+    let borrowed_allocs = allocation_count() - allocs_before;
+    
+    // CORREGIDO: Sin recoger en un Vec no se reserva nada
+    let allocs_before = allocation_count();
+    let valid = parse_users_zero_copy(&csv).filter(Result::is_ok).count();
+    let streaming_allocs = allocation_count() - allocs_before;
+    
+    println!(
+        "Con String: {} usuarios, {} allocations en {:?}",
+        owned.map(|users| users.len()).unwrap_or(0),
+        owned_allocs,
+        owned_time
+    );
+    println!(
+        "Con &str: {} usuarios, {} allocations en {:?} (solo el Vec de resultados)",
+        borrowed.as_ref().map(|users| users.len()).unwrap_or(0),
+        borrowed_allocs,
+        borrowed_time
+    );
+    println!("Recorriendo sin recoger: {} filas válidas, {} allocations", valid, streaming_allocs);
+    
+    // Un `drop(csv)` antes de esta línea no compilaría: `borrowed` apunta dentro del CSV
+    if let Ok(users) = &borrowed {
+        println!("Primer usuario: {:?}", users[0]);
+    }
+    
+    let mut rows = parse_users_zero_copy("id,name,email,posts\n1,Ana,ana@example.com,\nx,Luis,luis@example.com,3\n");
+    println!("Fila 1: {:?}", rows.next());
+    if let Some(Err(e)) = rows.next() {
+        println!("Fila 2: {}", e);
+    }
+}
@@ -0,0 +1,35 @@
+//! 🦀 Fuzz - `Config::from_toml` nunca hace panic y `to_toml` es reversible
+//!
+//! `cargo +nightly fuzz run fuzz_config_parse`
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+#[path = "../../exercises/error_handling/error_handling_basics_fixed.rs"]
+// Los avisos del ejercicio se revisan en su propio bin; aquí solo se usa una parte
+#[allow(dead_code, unused_comparisons, clippy::all)]
+mod error_handling;
+
+use error_handling::{Config, ConfigError};
+
+fuzz_target!(|data: &[u8]| {
+    let input = String::from_utf8_lossy(data);
+    
+    match Config::from_toml(&input) {
+        Ok(config) => {
+            // Lo que se acepta se tiene que poder guardar y volver a leer igual
+            let serialized = match config.to_toml() {
+                Ok(serialized) => serialized,
+                Err(e) => panic!("to_toml rechazó una configuración leída de TOML: {}\n{:?}", e, config),
+            };
+            match Config::from_toml(&serialized) {
+                Ok(reparsed) => assert_eq!(config, reparsed, "el round-trip cambió la configuración:\n{}", serialized),
+                Err(e) => panic!("to_toml generó TOML que from_toml rechaza: {}\n{}", e, serialized),
+            }
+        }
+        // Los únicos errores que puede producir from_toml
+        Err(ConfigError::TomlParse(_) | ConfigError::InvalidPort(_) | ConfigError::InvalidHost(_) | ConfigError::ParseError(_)) => {}
+        Err(e) => panic!("from_toml devolvió un error inesperado: {:?}", e),
+    }
+});
@@ -0,0 +1,22 @@
+//! 🦀 Fuzz - `validate_port_safe` nunca hace panic con cualquier texto
+//!
+//! `cargo +nightly fuzz run fuzz_validate_port`
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+#[path = "../../exercises/error_handling/error_handling_basics_fixed.rs"]
+// Los avisos del ejercicio se revisan en su propio bin; aquí solo se usa una parte
+#[allow(dead_code, unused_comparisons, clippy::all)]
+mod error_handling;
+
+use error_handling::validate_port_safe;
+
+fuzz_target!(|input: &str| {
+    if let Ok(port) = validate_port_safe(input) {
+        // Un puerto aceptado es válido y su forma canónica también se acepta
+        assert_ne!(port, 0);
+        assert_eq!(validate_port_safe(&port.to_string()), Ok(port));
+    }
+});
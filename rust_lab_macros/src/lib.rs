@@ -0,0 +1,313 @@
+//! 🦀 Rust Lab Macros - Macros procedurales para los ejercicios
+//!
+//! `#[derive(Builder)]` genera, para un struct con campos con nombre:
+//!
+//! - `{Nombre}Builder`, con un `Option<T>` por campo y un setter
+//!   `with_<campo>` para cada uno.
+//! - `build() -> Result<{Nombre}, BuildError>`, que falla si falta un
+//!   campo marcado con `#[builder(required)]`.
+//! - `BuildError`, con el nombre del campo que faltaba. Se genera en el
+//!   mismo módulo, así que solo se puede derivar un `Builder` por módulo.
+//!
+//! Los campos opcionales que no se asignan toman `#[builder(default = expr)]`
+//! o, si no se indica, `Default::default()`.
+//!
+//! ```ignore
+//! #[derive(Builder)]
+//! struct Config {
+//!     #[builder(required)]
+//!     port: u16,
+//!     #[builder(default = 30)]
+//!     timeout: u64,
+//! }
+//!
+//! let config = Config::builder().with_port(8080).build()?;
+//! ```
+//!
+//! `#[derive(Newtype)]` genera, para un struct con un único campo sin
+//! nombre (`struct UserId(u32)`), las conversiones y traits que se
+//! escribirían a mano para usarlo como el tipo que envuelve:
+//!
+//! - `From<T> for UserId` y `From<UserId> for T`.
+//! - `Deref<Target = T>`, para llamar a los métodos de `T` sin `.0`.
+//! - `Display`, `Hash`, `PartialEq` y `Eq` delegando en `T`, que por
+//!   tanto tiene que implementarlos.
+//!
+//! `Debug`, `Clone` o `Copy` se siguen derivando con `#[derive]`.
+//!
+//! ```ignore
+//! #[derive(Debug, Clone, Copy, Newtype)]
+//! struct UserId(u32);
+//!
+//! let id = UserId::from(42);
+//! assert_eq!(format!("{}", id), "42");
+//! assert_eq!(u32::from(id), 42);
+//! ```
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote, quote_spanned};
+use syn::spanned::Spanned;
+use syn::{parse_macro_input, Data, DeriveInput, Expr, Fields, Ident, Type};
+
+/// Cómo se resuelve un campo que no se asignó en el builder
+enum FieldKind {
+    Required,
+    Default(Expr),
+    TypeDefault,
+}
+
+struct BuilderField {
+    name: Ident,
+    ty: Type,
+    kind: FieldKind,
+}
+
+#[proc_macro_derive(Builder, attributes(builder))]
+pub fn derive_builder(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = builder_fields(&input)?;
+    
+    let vis = &input.vis;
+    let name = &input.ident;
+    let builder = format_ident!("{}Builder", name);
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let generics = &input.generics;
+    
+    let names: Vec<&Ident> = fields.iter().map(|field| &field.name).collect();
+    let types: Vec<&Type> = fields.iter().map(|field| &field.ty).collect();
+    let setters = fields.iter().map(|field| {
+        let field_name = &field.name;
+        let ty = &field.ty;
+        let setter = format_ident!("with_{}", field_name);
+        let doc = format!("Asigna `{}`", field_name);
+        quote! {
+            #[doc = #doc]
+            #vis fn #setter(mut self, value: #ty) -> Self {
+                self.#field_name = ::core::option::Option::Some(value);
+                self
+            }
+        }
+    });
+    let resolved = fields.iter().map(|field| {
+        let field_name = &field.name;
+        let label = field_name.to_string();
+        match &field.kind {
+            FieldKind::Required => quote! {
+                #field_name: self.#field_name.ok_or(BuildError::MissingField(#label))?
+            },
+            FieldKind::Default(expr) => quote! {
+                #field_name: self.#field_name.unwrap_or_else(|| #expr)
+            },
+            // El error por falta de `Default` apunta al tipo del campo
+            FieldKind::TypeDefault => quote_spanned! {field.ty.span()=>
+                #field_name: self.#field_name.unwrap_or_default()
+            },
+        }
+    });
+    
+    Ok(quote! {
+        /// Error de `build()` cuando falta un campo obligatorio
+        #[derive(Debug, Clone, PartialEq)]
+        #vis enum BuildError {
+            MissingField(&'static str),
+        }
+        
+        impl ::core::fmt::Display for BuildError {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                match self {
+                    BuildError::MissingField(field) => write!(f, "Falta el campo obligatorio: {}", field),
+                }
+            }
+        }
+        
+        impl ::std::error::Error for BuildError {}
+        
+        #vis struct #builder #generics #where_clause {
+            #(#names: ::core::option::Option<#types>,)*
+        }
+        
+        impl #impl_generics ::core::default::Default for #builder #ty_generics #where_clause {
+            fn default() -> Self {
+                Self {
+                    #(#names: ::core::option::Option::None,)*
+                }
+            }
+        }
+        
+        impl #impl_generics #builder #ty_generics #where_clause {
+            #(#setters)*
+            
+            /// Construye el valor o indica el primer campo obligatorio que falta
+            #vis fn build(self) -> ::core::result::Result<#name #ty_generics, BuildError> {
+                ::core::result::Result::Ok(#name {
+                    #(#resolved,)*
+                })
+            }
+        }
+        
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Builder con todos los campos sin asignar
+            #vis fn builder() -> #builder #ty_generics {
+                ::core::default::Default::default()
+            }
+        }
+    })
+}
+
+fn builder_fields(input: &DeriveInput) -> syn::Result<Vec<BuilderField>> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &input.ident,
+                    "#[derive(Builder)] solo admite structs con campos con nombre",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new(
+                Span::call_site(),
+                "#[derive(Builder)] solo admite structs",
+            ))
+        }
+    };
+    
+    fields
+        .iter()
+        .map(|field| {
+            Ok(BuilderField {
+                name: field.ident.clone().expect("los campos con nombre tienen ident"),
+                ty: field.ty.clone(),
+                kind: field_kind(field)?,
+            })
+        })
+        .collect()
+}
+
+/// Lee `#[builder(required)]` o `#[builder(default = expr)]` del campo
+fn field_kind(field: &syn::Field) -> syn::Result<FieldKind> {
+    let mut kind = FieldKind::TypeDefault;
+    
+    for attr in field.attrs.iter().filter(|attr| attr.path().is_ident("builder")) {
+        attr.parse_nested_meta(|meta| {
+            if !matches!(kind, FieldKind::TypeDefault) {
+                return Err(meta.error("`required` y `default` son excluyentes y no se pueden repetir"));
+            }
+            if meta.path.is_ident("required") {
+                kind = FieldKind::Required;
+                Ok(())
+            } else if meta.path.is_ident("default") {
+                kind = FieldKind::Default(meta.value()?.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("atributo desconocido, se esperaba `required` o `default = ...`"))
+            }
+        })?;
+    }
+    
+    Ok(kind)
+}
+
+#[proc_macro_derive(Newtype)]
+pub fn derive_newtype(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_newtype(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand_newtype(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let inner = newtype_field(&input)?;
+    let name = &input.ident;
+    // Los bounds sobre el tipo envuelto hacen que el error apunte al campo, no a la macro
+    let span = inner.span();
+    
+    Ok(quote_spanned! {span=>
+        impl ::core::convert::From<#inner> for #name {
+            fn from(value: #inner) -> Self {
+                #name(value)
+            }
+        }
+        
+        impl ::core::convert::From<#name> for #inner {
+            fn from(value: #name) -> Self {
+                value.0
+            }
+        }
+        
+        impl ::core::ops::Deref for #name {
+            type Target = #inner;
+            
+            fn deref(&self) -> &#inner {
+                &self.0
+            }
+        }
+        
+        impl ::core::fmt::Display for #name
+        where
+            #inner: ::core::fmt::Display,
+        {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                ::core::fmt::Display::fmt(&self.0, f)
+            }
+        }
+        
+        impl ::core::hash::Hash for #name
+        where
+            #inner: ::core::hash::Hash,
+        {
+            fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                ::core::hash::Hash::hash(&self.0, state)
+            }
+        }
+        
+        impl ::core::cmp::PartialEq for #name
+        where
+            #inner: ::core::cmp::Eq,
+        {
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+        
+        impl ::core::cmp::Eq for #name where #inner: ::core::cmp::Eq {}
+    })
+}
+
+/// Tipo del único campo de `struct Nombre(T)`
+///
+/// Los structs genéricos no se admiten: `impl<T> From<Nombre<T>> for T`
+/// no cumple la regla de huérfanos.
+fn newtype_field(input: &DeriveInput) -> syn::Result<Type> {
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(syn::Error::new(
+                Span::call_site(),
+                "#[derive(Newtype)] solo admite structs",
+            ))
+        }
+    };
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "#[derive(Newtype)] no admite structs genéricos",
+        ));
+    }
+    
+    match fields {
+        Fields::Unnamed(fields) if fields.unnamed.len() == 1 => Ok(fields.unnamed[0].ty.clone()),
+        _ => Err(syn::Error::new_spanned(
+            &input.ident,
+            "#[derive(Newtype)] solo admite structs con un único campo sin nombre, como `struct UserId(u32)`",
+        )),
+    }
+}
@@ -0,0 +1,58 @@
+//! Tests de `#[derive(Builder)]` usados directamente
+
+use rust_lab_macros::Builder;
+
+#[derive(Debug, PartialEq, Builder)]
+struct Config {
+    #[builder(required)]
+    port: u16,
+    #[builder(required)]
+    host: String,
+    #[builder(default = 30)]
+    timeout: u64,
+    #[builder(default = "info".to_string())]
+    debug_level: String,
+}
+
+#[test]
+fn test_builder_all_fields_set() {
+    let config = Config::builder()
+        .with_port(9090)
+        .with_host("example.com".to_string())
+        .with_timeout(60)
+        .with_debug_level("warn".to_string())
+        .build()
+        .unwrap();
+    
+    assert_eq!(
+        config,
+        Config {
+            port: 9090,
+            host: "example.com".to_string(),
+            timeout: 60,
+            debug_level: "warn".to_string(),
+        }
+    );
+}
+
+#[test]
+fn test_builder_optional_fields_use_defaults() {
+    let config = Config::builder()
+        .with_host("localhost".to_string())
+        .with_port(8080)
+        .build()
+        .unwrap();
+    
+    assert_eq!(config.timeout, 30);
+    assert_eq!(config.debug_level, "info");
+}
+
+#[test]
+fn test_builder_missing_required_field() {
+    let result = Config::builder().with_port(8080).build();
+    assert_eq!(result, Err(BuildError::MissingField("host")));
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "Falta el campo obligatorio: host"
+    );
+}
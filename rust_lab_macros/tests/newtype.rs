@@ -0,0 +1,69 @@
+//! Tests de `#[derive(Newtype)]` usados directamente
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+
+use rust_lab_macros::Newtype;
+
+#[derive(Debug, Clone, Copy, Newtype)]
+struct UserId(u32);
+
+#[derive(Debug, Clone, Newtype)]
+struct Email(String);
+
+fn hash_of<T: Hash>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn test_newtype_conversions_and_deref() {
+    let id = UserId::from(42u32);
+    assert_eq!(id, UserId(42u32));
+    assert_eq!(u32::from(id), 42);
+    let raw: u32 = UserId(7).into();
+    assert_eq!(raw, 7);
+    
+    // Deref da acceso a los métodos de u32 sin `.0`
+    assert_eq!(*id + 1, 43);
+    assert_eq!(id.count_ones(), 3);
+    assert!(!id.is_power_of_two());
+    
+    let email = Email::from("ana@example.com".to_string());
+    assert_eq!(email.len(), 15);
+    assert!(email.ends_with("example.com"));
+    assert_eq!(String::from(email), "ana@example.com");
+}
+
+#[test]
+fn test_newtype_eq_and_hash_match_inner_value() {
+    assert_eq!(UserId(42), UserId(42));
+    assert_ne!(UserId(42), UserId(43));
+    assert_eq!(hash_of(&UserId(42)), hash_of(&42u32));
+    
+    let mut posts: HashMap<UserId, Vec<&str>> = HashMap::new();
+    posts.entry(UserId(1)).or_default().push("Hola");
+    posts.entry(UserId(2)).or_default().push("Rust");
+    posts.entry(UserId(1)).or_default().push("Adiós");
+    assert_eq!(posts.len(), 2);
+    assert_eq!(posts[&UserId(1)], ["Hola", "Adiós"]);
+    assert_eq!(posts.get(&UserId(3)), None);
+    
+    let unique: HashSet<Email> = ["a@x.com", "b@x.com", "a@x.com"]
+        .into_iter()
+        .map(|email| Email::from(email.to_string()))
+        .collect();
+    assert_eq!(unique.len(), 2);
+}
+
+#[test]
+fn test_newtype_display_forwards_to_inner() {
+    assert_eq!(format!("{}", UserId(5)), "5");
+    assert_eq!(UserId(42).to_string(), "42");
+    // Los flags de formato también se delegan
+    assert_eq!(format!("{:>4}", UserId(5)), "   5");
+    assert_eq!(format!("{:03}", UserId(5)), "005");
+    assert_eq!(format!("<{}>", Email::from("ana@example.com".to_string())), "<ana@example.com>");
+}
@@ -0,0 +1,8 @@
+//! Tests de compilación de `#[derive(Builder)]` y `#[derive(Newtype)]` con trybuild
+
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/pass_*.rs");
+    t.compile_fail("tests/ui/fail_*.rs");
+}
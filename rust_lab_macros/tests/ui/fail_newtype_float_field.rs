@@ -0,0 +1,6 @@
+use rust_lab_macros::Newtype;
+
+#[derive(Newtype)]
+struct Score(f64);
+
+fn main() {}
@@ -0,0 +1,8 @@
+use rust_lab_macros::Newtype;
+
+#[derive(Newtype)]
+struct UserId {
+    id: u32,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+// Un campo opcional sin `default = ...` necesita un tipo con Default
+use rust_lab_macros::Builder;
+
+struct Port(u16);
+
+#[derive(Builder)]
+struct Config {
+    port: Port,
+}
+
+fn main() {}
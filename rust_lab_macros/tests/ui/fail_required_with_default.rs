@@ -0,0 +1,9 @@
+use rust_lab_macros::Builder;
+
+#[derive(Builder)]
+struct Config {
+    #[builder(required, default = 8080)]
+    port: u16,
+}
+
+fn main() {}
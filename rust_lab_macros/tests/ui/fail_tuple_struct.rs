@@ -0,0 +1,6 @@
+use rust_lab_macros::Builder;
+
+#[derive(Builder)]
+struct Config(u16, String);
+
+fn main() {}
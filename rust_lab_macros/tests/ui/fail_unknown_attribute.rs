@@ -0,0 +1,9 @@
+use rust_lab_macros::Builder;
+
+#[derive(Builder)]
+struct Config {
+    #[builder(optional)]
+    port: u16,
+}
+
+fn main() {}
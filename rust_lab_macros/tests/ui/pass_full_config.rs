@@ -0,0 +1,33 @@
+// Un builder con todos los campos asignados produce el Config esperado
+use rust_lab_macros::Builder;
+
+#[derive(Debug, PartialEq, Builder)]
+pub struct Config {
+    #[builder(required)]
+    port: u16,
+    #[builder(required)]
+    host: String,
+    #[builder(required)]
+    timeout: u64,
+    #[builder(required)]
+    debug_level: String,
+}
+
+fn main() {
+    let config = Config::builder()
+        .with_port(8080)
+        .with_host("localhost".to_string())
+        .with_timeout(30)
+        .with_debug_level("info".to_string())
+        .build();
+    
+    assert_eq!(
+        config,
+        Ok(Config {
+            port: 8080,
+            host: "localhost".to_string(),
+            timeout: 30,
+            debug_level: "info".to_string(),
+        })
+    );
+}
@@ -0,0 +1,16 @@
+use std::collections::HashMap;
+
+use rust_lab_macros::Newtype;
+
+#[derive(Debug, Clone, Copy, Newtype)]
+struct UserId(u32);
+
+#[derive(Debug, Clone, Copy, Newtype)]
+struct PostId(u32);
+
+fn main() {
+    let mut authors: HashMap<PostId, UserId> = HashMap::new();
+    authors.insert(PostId(101), UserId(1));
+    assert_eq!(authors[&PostId::from(101)], UserId(1));
+    assert_eq!(u32::from(authors[&PostId(101)]), 1);
+}
@@ -0,0 +1,21 @@
+// Sin #[builder(required)] en todos los campos, compila y los que
+// faltan toman su valor por defecto en tiempo de ejecución
+use rust_lab_macros::Builder;
+
+#[derive(Debug, PartialEq, Builder)]
+struct Config {
+    #[builder(required)]
+    port: u16,
+    host: String,
+    #[builder(default = 30)]
+    timeout: u64,
+}
+
+fn main() {
+    let config = Config::builder().with_port(8080).build().unwrap();
+    assert_eq!(config.host, "");
+    assert_eq!(config.timeout, 30);
+    
+    // El único campo obligatorio sigue siendo un error si falta
+    assert_eq!(Config::builder().build(), Err(BuildError::MissingField("port")));
+}
@@ -64,6 +64,16 @@ list_exercises() {
     echo "  - memory_management (con bugs intencionales)"
     echo "  - memory_management_fixed (solución corregida)"
     echo ""
+    
+    echo -e "${YELLOW}🔗 FFI:${NC}"
+    echo "  - sort_ffi (qsort de C llamado desde Rust)"
+    echo "  - ffi_callbacks (closures de Rust como callbacks de C)"
+    echo ""
+    
+    echo -e "${YELLOW}🌐 Networking:${NC}"
+    echo "  - tcp_echo (servidor TCP de eco con parada limpia)"
+    echo "  - udp_multicast (datagramas a un grupo multicast)"
+    echo ""
 }
 
 # Función para ejecutar un ejercicio
@@ -0,0 +1,102 @@
+//! Tests para el CountingAllocator de los ejercicios de performance
+//!
+//! Viven en su propio binario de test porque el allocator es global:
+//! otros tests ejecutándose en paralelo alterarían los contadores.
+
+#[cfg(test)]
+mod allocator_tests {
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    
+    // Importar las estructuras del ejercicio
+    struct CountingAllocator {
+        allocated_bytes: AtomicUsize,
+        freed_bytes: AtomicUsize,
+        allocation_count: AtomicUsize,
+    }
+    
+    impl CountingAllocator {
+        const fn new() -> Self {
+            Self {
+                allocated_bytes: AtomicUsize::new(0),
+                freed_bytes: AtomicUsize::new(0),
+                allocation_count: AtomicUsize::new(0),
+            }
+        }
+        
+        fn record_alloc(&self, size: usize) {
+            self.allocated_bytes.fetch_add(size, Ordering::Relaxed);
+            self.allocation_count.fetch_add(1, Ordering::Relaxed);
+        }
+        
+        fn record_free(&self, size: usize) {
+            self.freed_bytes.fetch_add(size, Ordering::Relaxed);
+        }
+    }
+    
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = unsafe { System.alloc(layout) };
+            if !ptr.is_null() {
+                self.record_alloc(layout.size());
+            }
+            ptr
+        }
+        
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            let ptr = unsafe { System.alloc_zeroed(layout) };
+            if !ptr.is_null() {
+                self.record_alloc(layout.size());
+            }
+            ptr
+        }
+        
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            unsafe { System.dealloc(ptr, layout) };
+            self.record_free(layout.size());
+        }
+        
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+            if !new_ptr.is_null() {
+                self.record_free(layout.size());
+                self.record_alloc(new_size);
+            }
+            new_ptr
+        }
+    }
+    
+    #[global_allocator]
+    static GLOBAL: CountingAllocator = CountingAllocator::new();
+    
+    fn current_heap_bytes() -> usize {
+        let freed = GLOBAL.freed_bytes.load(Ordering::Relaxed);
+        let allocated = GLOBAL.allocated_bytes.load(Ordering::Relaxed);
+        allocated.saturating_sub(freed)
+    }
+    
+    fn allocation_count() -> usize {
+        GLOBAL.allocation_count.load(Ordering::Relaxed)
+    }
+    
+    // Un único test: el harness no reserva memoria mientras lo espera,
+    // así que las diferencias medidas son solo las de este thread
+    #[test]
+    fn test_with_capacity_single_allocation() {
+        let heap_before = current_heap_bytes();
+        let allocs_before = allocation_count();
+        
+        let data: Vec<i32> = Vec::with_capacity(1000);
+        
+        let allocated = current_heap_bytes() - heap_before;
+        let allocations = allocation_count() - allocs_before;
+        
+        assert_eq!(allocations, 1);
+        assert_eq!(allocated, 1000 * std::mem::size_of::<i32>());
+        assert!(data.capacity() >= 1000);
+        
+        // Al liberar, el heap vuelve al nivel previo
+        drop(data);
+        assert_eq!(current_heap_bytes(), heap_before);
+    }
+}
@@ -1,30 +1,758 @@
 //! Tests para los ejercicios de concurrency
 
+#[macro_use]
+mod test_helpers;
+
 #[cfg(test)]
 mod concurrency_tests {
-    use std::sync::{Arc, Mutex, RwLock};
+    use std::collections::hash_map::RandomState;
+    use std::collections::HashMap;
+    use std::hash::{BuildHasher, Hash};
+    use std::cell::RefCell;
+    use std::panic::{self, AssertUnwindSafe};
+    use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+    use std::sync::{Arc, Condvar, LockResult, Mutex, MutexGuard, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard, Weak};
     use std::sync::mpsc;
     use std::thread;
-    use std::time::Duration;
+    use std::time::{Duration, Instant};
+    
+    mod poison {
+        use std::sync::{Condvar, Mutex, MutexGuard, RwLock, RwLockReadGuard, RwLockWriteGuard};
+        
+        pub fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+            mutex.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+        }
+        
+        pub fn read<T>(rwlock: &RwLock<T>) -> RwLockReadGuard<'_, T> {
+            rwlock.read().unwrap_or_else(|poisoned| poisoned.into_inner())
+        }
+        
+        pub fn write<T>(rwlock: &RwLock<T>) -> RwLockWriteGuard<'_, T> {
+            rwlock.write().unwrap_or_else(|poisoned| poisoned.into_inner())
+        }
+        
+        pub fn wait<'a, T>(condvar: &Condvar, guard: MutexGuard<'a, T>) -> MutexGuard<'a, T> {
+            condvar.wait(guard).unwrap_or_else(|poisoned| poisoned.into_inner())
+        }
+    }
     
     // Importar las estructuras del ejercicio
-    #[derive(Debug, Clone, PartialEq)]
+    #[derive(Debug, Clone)]
     struct Counter {
         value: i32,
+        subscribers: Vec<mpsc::Sender<i32>>,
     }
     
     impl Counter {
         fn new() -> Self {
-            Self { value: 0 }
+            Self {
+                value: 0,
+                subscribers: Vec::new(),
+            }
         }
         
         fn increment(&mut self) {
             self.value += 1;
+            self.notify();
+        }
+        
+        fn reset(&mut self) {
+            self.value = 0;
+            self.notify();
         }
         
         fn get_value(&self) -> i32 {
             self.value
         }
+        
+        fn subscribe(&mut self) -> mpsc::Receiver<i32> {
+            let (tx, rx) = mpsc::channel();
+            self.subscribers.push(tx);
+            rx
+        }
+        
+        fn subscriber_count(&self) -> usize {
+            self.subscribers.len()
+        }
+        
+        fn notify(&mut self) {
+            let value = self.value;
+            self.subscribers.retain(|tx| tx.send(value).is_ok());
+        }
+    }
+    
+    #[derive(Debug, Clone, PartialEq)]
+    enum ConcurrencyError {
+        LockPoisoned(String),
+        Overflow { total: i32 },
+    }
+    
+    #[derive(Debug, Clone, Default)]
+    struct SharedCounter {
+        total: Arc<Mutex<i32>>,
+    }
+    
+    impl SharedCounter {
+        fn new() -> Self {
+            Self::default()
+        }
+        
+        fn lock(&self) -> Result<MutexGuard<'_, i32>, ConcurrencyError> {
+            self.total
+                .lock()
+                .map_err(|e| ConcurrencyError::LockPoisoned(e.to_string()))
+        }
+        
+        fn add(&self, value: i32) -> Result<i32, ConcurrencyError> {
+            let mut total = self.lock()?;
+            *total = total
+                .checked_add(value)
+                .ok_or(ConcurrencyError::Overflow { total: *total })?;
+            Ok(*total)
+        }
+        
+        fn add_batch(&self, values: &[i32]) -> Result<i32, ConcurrencyError> {
+            let mut total = self.lock()?;
+            *total = values
+                .iter()
+                .try_fold(*total, |acc, &value| acc.checked_add(value))
+                .ok_or(ConcurrencyError::Overflow { total: *total })?;
+            Ok(*total)
+        }
+        
+        fn get(&self) -> Result<i32, ConcurrencyError> {
+            Ok(*self.lock()?)
+        }
+    }
+    
+    #[derive(Debug, Clone, PartialEq)]
+    enum EventKind {
+        LockAcquired,
+        LockReleased(Duration),
+        Incremented(i32),
+        Error(String),
+    }
+    
+    #[derive(Debug, Clone)]
+    struct LogEvent {
+        timestamp: Instant,
+        thread_id: usize,
+        kind: EventKind,
+    }
+    
+    impl LogEvent {
+        fn now(kind: EventKind) -> Self {
+            Self {
+                timestamp: Instant::now(),
+                thread_id: current_thread_id(),
+                kind,
+            }
+        }
+    }
+    
+    static NEXT_THREAD_ID: AtomicUsize = AtomicUsize::new(0);
+    
+    thread_local! {
+        static THREAD_ID: usize = NEXT_THREAD_ID.fetch_add(1, Ordering::Relaxed);
+    }
+    
+    fn current_thread_id() -> usize {
+        THREAD_ID.with(|id| *id)
+    }
+    
+    trait Logger {
+        fn log(&self, event: &LogEvent);
+    }
+    
+    #[derive(Debug, Clone, Default)]
+    struct VecLogger {
+        events: Arc<Mutex<Vec<LogEvent>>>,
+    }
+    
+    impl Logger for VecLogger {
+        fn log(&self, event: &LogEvent) {
+            let mut events = poison::lock(&self.events);
+            events.push(event.clone());
+        }
+    }
+    
+    struct LoggedCounter<L: Logger> {
+        counter: Mutex<Counter>,
+        logger: L,
+    }
+    
+    impl<L: Logger> LoggedCounter<L> {
+        fn new(logger: L) -> Self {
+            Self {
+                counter: Mutex::new(Counter::new()),
+                logger,
+            }
+        }
+        
+        fn emit(&self, kind: EventKind) {
+            self.logger.log(&LogEvent::now(kind));
+        }
+        
+        fn increment(&self) -> Result<i32, ConcurrencyError> {
+            let mut guard = match self.counter.lock() {
+                Ok(guard) => guard,
+                Err(e) => {
+                    let message = e.to_string();
+                    self.emit(EventKind::Error(message.clone()));
+                    return Err(ConcurrencyError::LockPoisoned(message));
+                }
+            };
+            let acquired_at = Instant::now();
+            self.emit(EventKind::LockAcquired);
+            
+            guard.increment();
+            let value = guard.get_value();
+            self.emit(EventKind::Incremented(value));
+            
+            let held = acquired_at.elapsed();
+            drop(guard);
+            self.emit(EventKind::LockReleased(held));
+            Ok(value)
+        }
+    }
+    
+    #[derive(Debug)]
+    struct Bucket {
+        tokens: f64,
+        last_refill: Instant,
+    }
+    
+    #[derive(Debug, Clone)]
+    struct RateLimiter {
+        capacity: u32,
+        refill_per_sec: u32,
+        bucket: Arc<Mutex<Bucket>>,
+    }
+    
+    impl RateLimiter {
+        fn new(capacity: u32, refill_per_sec: u32) -> Self {
+            Self {
+                capacity,
+                refill_per_sec,
+                bucket: Arc::new(Mutex::new(Bucket {
+                    tokens: capacity as f64,
+                    last_refill: Instant::now(),
+                })),
+            }
+        }
+        
+        fn try_acquire(&self, n: u32) -> bool {
+            let mut bucket = poison::lock(&self.bucket);
+            
+            let now = Instant::now();
+            let elapsed = now.duration_since(bucket.last_refill).as_secs_f64();
+            bucket.tokens = (bucket.tokens + elapsed * self.refill_per_sec as f64).min(self.capacity as f64);
+            bucket.last_refill = now;
+            
+            if bucket.tokens >= n as f64 {
+                bucket.tokens -= n as f64;
+                true
+            } else {
+                false
+            }
+        }
+    }
+    
+    struct ShardedMap<K, V> {
+        shards: Vec<RwLock<HashMap<K, V>>>,
+        hasher: RandomState,
+    }
+    
+    impl<K: Hash + Eq, V: Clone> ShardedMap<K, V> {
+        fn new(shard_count: usize) -> Self {
+            Self {
+                shards: (0..shard_count.max(1)).map(|_| RwLock::new(HashMap::new())).collect(),
+                hasher: RandomState::new(),
+            }
+        }
+        
+        fn shard(&self, key: &K) -> &RwLock<HashMap<K, V>> {
+            &self.shards[self.hasher.hash_one(key) as usize % self.shards.len()]
+        }
+        
+        fn insert(&self, key: K, value: V) -> Option<V> {
+            poison::write(self.shard(&key)).insert(key, value)
+        }
+        
+        fn get(&self, key: &K) -> Option<V> {
+            poison::read(self.shard(key)).get(key).cloned()
+        }
+        
+        fn len(&self) -> usize {
+            self.shards.iter().map(|shard| poison::read(shard).len()).sum()
+        }
+    }
+    
+    #[derive(Debug, PartialEq)]
+    enum SendError<T> {
+        Full(T),
+        Disconnected(T),
+    }
+    
+    struct BackoffSender<T> {
+        sender: mpsc::SyncSender<T>,
+        max_attempts: u32,
+        initial_delay: Duration,
+        max_delay: Duration,
+        retries: AtomicUsize,
+    }
+    
+    impl<T> BackoffSender<T> {
+        fn new(sender: mpsc::SyncSender<T>, max_attempts: u32, initial_delay: Duration, max_delay: Duration) -> Self {
+            Self {
+                sender,
+                max_attempts: max_attempts.max(1),
+                initial_delay,
+                max_delay,
+                retries: AtomicUsize::new(0),
+            }
+        }
+        
+        fn send(&self, value: T) -> Result<(), SendError<T>> {
+            let mut value = value;
+            let mut delay = self.initial_delay;
+            
+            for attempt in 1..=self.max_attempts {
+                match self.sender.try_send(value) {
+                    Ok(()) => return Ok(()),
+                    Err(mpsc::TrySendError::Disconnected(v)) => return Err(SendError::Disconnected(v)),
+                    Err(mpsc::TrySendError::Full(v)) if attempt == self.max_attempts => return Err(SendError::Full(v)),
+                    Err(mpsc::TrySendError::Full(v)) => {
+                        value = v;
+                        self.retries.fetch_add(1, Ordering::Relaxed);
+                        thread::sleep(delay);
+                        delay = (delay * 2).min(self.max_delay);
+                    }
+                }
+            }
+            unreachable!("max_attempts es al menos 1")
+        }
+        
+        fn retries(&self) -> usize {
+            self.retries.load(Ordering::Relaxed)
+        }
+    }
+    
+    #[derive(Debug, Clone, Default)]
+    struct CancellationToken {
+        cancelled: Arc<AtomicBool>,
+    }
+    
+    impl CancellationToken {
+        fn new() -> Self {
+            Self::default()
+        }
+        
+        fn cancel(&self) {
+            self.cancelled.store(true, Ordering::Release);
+        }
+        
+        fn is_cancelled(&self) -> bool {
+            self.cancelled.load(Ordering::Acquire)
+        }
+    }
+    
+    #[derive(Debug)]
+    struct Semaphore {
+        permits: Mutex<usize>,
+        released: Condvar,
+    }
+    
+    #[derive(Debug)]
+    struct SemaphoreGuard<'a> {
+        semaphore: &'a Semaphore,
+    }
+    
+    impl Semaphore {
+        fn new(permits: usize) -> Self {
+            Self {
+                permits: Mutex::new(permits),
+                released: Condvar::new(),
+            }
+        }
+        
+        fn lock_permits(&self) -> MutexGuard<'_, usize> {
+            poison::lock(&self.permits)
+        }
+        
+        fn acquire(&self) -> SemaphoreGuard<'_> {
+            let mut permits = self.lock_permits();
+            while *permits == 0 {
+                permits = poison::wait(&self.released, permits);
+            }
+            *permits -= 1;
+            SemaphoreGuard { semaphore: self }
+        }
+        
+        fn try_acquire(&self) -> Option<SemaphoreGuard<'_>> {
+            let mut permits = self.lock_permits();
+            if *permits == 0 {
+                return None;
+            }
+            *permits -= 1;
+            Some(SemaphoreGuard { semaphore: self })
+        }
+        
+        fn available_permits(&self) -> usize {
+            *self.lock_permits()
+        }
+    }
+    
+    impl Drop for SemaphoreGuard<'_> {
+        fn drop(&mut self) {
+            *self.semaphore.lock_permits() += 1;
+            self.semaphore.released.notify_one();
+        }
+    }
+    
+    #[derive(Debug, Default)]
+    struct Latch {
+        is_set: Mutex<bool>,
+        opened: Condvar,
+    }
+    
+    impl Latch {
+        fn new() -> Self {
+            Self::default()
+        }
+        
+        fn lock_flag(&self) -> MutexGuard<'_, bool> {
+            poison::lock(&self.is_set)
+        }
+        
+        fn wait(&self) {
+            let mut is_set = self.lock_flag();
+            while !*is_set {
+                is_set = poison::wait(&self.opened, is_set);
+            }
+        }
+        
+        fn set(&self) {
+            *self.lock_flag() = true;
+            self.opened.notify_all();
+        }
+        
+        fn is_set(&self) -> bool {
+            *self.lock_flag()
+        }
+    }
+    
+    #[derive(Debug)]
+    struct DoubleBuffer<T: Clone> {
+        slots: [RwLock<T>; 2],
+        live: AtomicUsize,
+        writer: Mutex<()>,
+    }
+    
+    impl<T: Clone> DoubleBuffer<T> {
+        fn new(initial: T) -> Self {
+            Self {
+                slots: [RwLock::new(initial.clone()), RwLock::new(initial)],
+                live: AtomicUsize::new(0),
+                writer: Mutex::new(()),
+            }
+        }
+        
+        fn read_slot(&self, index: usize) -> RwLockReadGuard<'_, T> {
+            poison::read(&self.slots[index])
+        }
+        
+        fn write_slot(&self, index: usize) -> RwLockWriteGuard<'_, T> {
+            poison::write(&self.slots[index])
+        }
+        
+        fn read(&self) -> T {
+            let live = self.live.load(Ordering::Acquire);
+            self.read_slot(live).clone()
+        }
+        
+        fn update(&self, f: impl FnOnce(&mut T)) {
+            let _writer = poison::lock(&self.writer);
+            let live = self.live.load(Ordering::Relaxed);
+            let next = 1 - live;
+            
+            let mut slot = self.write_slot(next);
+            slot.clone_from(&self.read_slot(live));
+            f(&mut slot);
+            self.live.store(next, Ordering::Release);
+        }
+    }
+    
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct RwLockStats {
+        read_locks: usize,
+        write_locks: usize,
+        current_readers: usize,
+    }
+    
+    #[derive(Debug, Default)]
+    struct InstrumentedRwLock<T> {
+        inner: RwLock<T>,
+        read_locks: AtomicUsize,
+        write_locks: AtomicUsize,
+        current_readers: AtomicUsize,
+    }
+    
+    struct InstrumentedReadGuard<'a, T> {
+        guard: RwLockReadGuard<'a, T>,
+        current_readers: &'a AtomicUsize,
+    }
+    
+    impl<T> std::ops::Deref for InstrumentedReadGuard<'_, T> {
+        type Target = T;
+        
+        fn deref(&self) -> &T {
+            &self.guard
+        }
+    }
+    
+    impl<T> Drop for InstrumentedReadGuard<'_, T> {
+        fn drop(&mut self) {
+            self.current_readers.fetch_sub(1, Ordering::Relaxed);
+        }
+    }
+    
+    impl<T> InstrumentedRwLock<T> {
+        fn new(value: T) -> Self {
+            Self {
+                inner: RwLock::new(value),
+                read_locks: AtomicUsize::new(0),
+                write_locks: AtomicUsize::new(0),
+                current_readers: AtomicUsize::new(0),
+            }
+        }
+        
+        fn wrap_read<'a>(&'a self, guard: RwLockReadGuard<'a, T>) -> InstrumentedReadGuard<'a, T> {
+            self.read_locks.fetch_add(1, Ordering::Relaxed);
+            self.current_readers.fetch_add(1, Ordering::Relaxed);
+            InstrumentedReadGuard {
+                guard,
+                current_readers: &self.current_readers,
+            }
+        }
+        
+        fn read(&self) -> LockResult<InstrumentedReadGuard<'_, T>> {
+            match self.inner.read() {
+                Ok(guard) => Ok(self.wrap_read(guard)),
+                Err(poisoned) => Err(PoisonError::new(self.wrap_read(poisoned.into_inner()))),
+            }
+        }
+        
+        fn write(&self) -> LockResult<RwLockWriteGuard<'_, T>> {
+            let result = self.inner.write();
+            self.write_locks.fetch_add(1, Ordering::Relaxed);
+            result
+        }
+        
+        fn stats(&self) -> RwLockStats {
+            RwLockStats {
+                read_locks: self.read_locks.load(Ordering::Relaxed),
+                write_locks: self.write_locks.load(Ordering::Relaxed),
+                current_readers: self.current_readers.load(Ordering::Relaxed),
+            }
+        }
+    }
+    
+    #[derive(Debug, Default)]
+    struct BarrierState {
+        arrived: usize,
+        generation: usize,
+    }
+    
+    struct FnBarrier {
+        n: usize,
+        state: Mutex<BarrierState>,
+        released: Condvar,
+        on_complete: Box<dyn Fn() + Send + Sync>,
+    }
+    
+    impl FnBarrier {
+        fn new(n: usize, on_complete: impl Fn() + Send + Sync + 'static) -> Self {
+            Self {
+                n: n.max(1),
+                state: Mutex::new(BarrierState::default()),
+                released: Condvar::new(),
+                on_complete: Box::new(on_complete),
+            }
+        }
+        
+        fn lock_state(&self) -> MutexGuard<'_, BarrierState> {
+            poison::lock(&self.state)
+        }
+        
+        fn wait(&self) -> bool {
+            let mut state = self.lock_state();
+            let generation = state.generation;
+            state.arrived += 1;
+            
+            if state.arrived < self.n {
+                while state.generation == generation {
+                    state = poison::wait(&self.released, state);
+                }
+                return false;
+            }
+            
+            let result = panic::catch_unwind(AssertUnwindSafe(|| (self.on_complete)()));
+            state.arrived = 0;
+            state.generation += 1;
+            drop(state);
+            self.released.notify_all();
+            
+            if let Err(payload) = result {
+                panic::resume_unwind(payload);
+            }
+            true
+        }
+    }
+    
+    #[derive(Debug, Clone, Default)]
+    struct WaitGroup {
+        inner: Arc<(Mutex<usize>, Condvar)>,
+    }
+    
+    impl WaitGroup {
+        fn new() -> Self {
+            Self::default()
+        }
+        
+        fn lock_pending(&self) -> MutexGuard<'_, usize> {
+            poison::lock(&self.inner.0)
+        }
+        
+        fn add(&self, n: usize) {
+            *self.lock_pending() += n;
+        }
+        
+        fn done(&self) {
+            let mut pending = self.lock_pending();
+            *pending = pending.checked_sub(1).expect("WaitGroup::done sin un add pendiente");
+            if *pending == 0 {
+                self.inner.1.notify_all();
+            }
+        }
+        
+        fn wait(&self) {
+            let mut pending = self.lock_pending();
+            while *pending > 0 {
+                pending = poison::wait(&self.inner.1, pending);
+            }
+        }
+    }
+    
+    fn parallel_find<T: Sync>(data: &[T], pred: impl Fn(&T) -> bool + Send + Sync) -> Option<usize> {
+        let threads = thread::available_parallelism().map_or(4, |n| n.get());
+        let chunk_size = data.len().div_ceil(threads).max(1);
+        let found = AtomicBool::new(false);
+        
+        thread::scope(|scope| {
+            let handles: Vec<_> = data
+                .chunks(chunk_size)
+                .enumerate()
+                .map(|(chunk_index, chunk)| {
+                    let (found, pred) = (&found, &pred);
+                    scope.spawn(move || {
+                        for (offset, item) in chunk.iter().enumerate() {
+                            if found.load(Ordering::Relaxed) {
+                                return None;
+                            }
+                            if pred(item) {
+                                found.store(true, Ordering::Relaxed);
+                                return Some(chunk_index * chunk_size + offset);
+                            }
+                        }
+                        None
+                    })
+                })
+                .collect();
+            
+            handles
+                .into_iter()
+                .filter_map(|handle| handle.join().unwrap_or_else(|panic| std::panic::resume_unwind(panic)))
+                .min()
+        })
+    }
+    
+    static NEXT_COUNTER_ID: AtomicUsize = AtomicUsize::new(0);
+    
+    thread_local! {
+        static LOCAL_TALLIES: RefCell<HashMap<usize, Weak<AtomicU64>>> = RefCell::new(HashMap::new());
+    }
+    
+    #[derive(Debug)]
+    struct ThreadLocalCounter {
+        id: usize,
+        tallies: Mutex<Vec<Arc<AtomicU64>>>,
+    }
+    
+    impl ThreadLocalCounter {
+        fn new() -> Self {
+            Self {
+                id: NEXT_COUNTER_ID.fetch_add(1, Ordering::Relaxed),
+                tallies: Mutex::new(Vec::new()),
+            }
+        }
+        
+        fn local(&self) -> LocalTally {
+            LOCAL_TALLIES.with(|tallies| {
+                let mut tallies = tallies.borrow_mut();
+                if let Some(tally) = tallies.get(&self.id).and_then(Weak::upgrade) {
+                    return LocalTally { tally };
+                }
+                
+                // CORREGIDO: Sin esto el mapa crece con cada contador que el thread haya tocado
+                tallies.retain(|_, tally| tally.strong_count() > 0);
+                let tally = Arc::new(AtomicU64::new(0));
+                poison::lock(&self.tallies).push(Arc::clone(&tally));
+                tallies.insert(self.id, Arc::downgrade(&tally));
+                LocalTally { tally }
+            })
+        }
+        
+        fn increment(&self) {
+            self.add(1);
+        }
+        
+        fn add(&self, n: u64) {
+            self.local().add(n);
+        }
+        
+        fn merge(&self) -> u64 {
+            poison::lock(&self.tallies)
+                .iter()
+                .map(|tally| tally.load(Ordering::Relaxed))
+                .sum()
+        }
+        
+        fn thread_count(&self) -> usize {
+            poison::lock(&self.tallies).len()
+        }
+    }
+    
+    #[derive(Debug, Clone)]
+    struct LocalTally {
+        tally: Arc<AtomicU64>,
+    }
+    
+    impl LocalTally {
+        fn add(&self, n: u64) {
+            self.tally.fetch_add(n, Ordering::Relaxed);
+        }
+    }
+    
+    
+    fn recover_poisoned<T>(m: &Mutex<T>) -> MutexGuard<'_, T> {
+        match m.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => {
+                println!("⚠️  Mutex envenenado, recuperando el guard");
+                poisoned.into_inner()
+            }
+        }
     }
     
     #[test]
@@ -44,6 +772,33 @@ mod concurrency_tests {
         assert_eq!(counter.get_value(), 3);
     }
     
+    #[test]
+    fn test_counter_subscribe_receives_values_in_order() {
+        let mut counter = Counter::new();
+        let rx = counter.subscribe();
+        
+        counter.increment();
+        counter.increment();
+        counter.increment();
+        
+        assert_eq!(rx.try_iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+    }
+    
+    #[test]
+    fn test_counter_prunes_dropped_subscribers() {
+        let mut counter = Counter::new();
+        let kept = counter.subscribe();
+        let dropped = counter.subscribe();
+        assert_eq!(counter.subscriber_count(), 2);
+        
+        drop(dropped);
+        counter.increment();
+        counter.reset();
+        
+        assert_eq!(counter.subscriber_count(), 1);
+        assert_eq!(kept.try_iter().collect::<Vec<_>>(), vec![1, 0]);
+    }
+    
     #[test]
     fn test_arc_mutex_basic() {
         let counter = Arc::new(Mutex::new(Counter::new()));
@@ -280,6 +1035,120 @@ mod concurrency_tests {
         assert_eq!(data_guard.len(), 8); // 3 originales + 5 nuevos
     }
     
+    #[test]
+    fn test_recover_poisoned() {
+        let data = Arc::new(Mutex::new(vec![1, 2, 3]));
+        
+        // Envenenar el mutex con un panic mientras se tiene el lock
+        let data_clone = Arc::clone(&data);
+        let handle = thread::spawn(move || {
+            let mut guard = data_clone.lock().unwrap();
+            guard.push(4);
+            panic!("Panic intencional");
+        });
+        assert!(handle.join().is_err());
+        
+        assert!(data.is_poisoned());
+        assert!(data.lock().is_err());
+        
+        // Los datos siguen intactos, incluida la escritura previa al panic
+        let guard = recover_poisoned(&data);
+        assert_eq!(*guard, vec![1, 2, 3, 4]);
+    }
+    
+    #[test]
+    fn test_recover_poisoned_healthy_mutex() {
+        let data = Mutex::new(42);
+        
+        let mut guard = recover_poisoned(&data);
+        *guard += 1;
+        drop(guard);
+        
+        assert_eq!(*data.lock().unwrap(), 43);
+    }
+    
+    #[test]
+    fn test_shared_counter_add_batch() {
+        let counter = SharedCounter::new();
+        assert_eq!(counter.add_batch(&[1, 2, 3]), Ok(6));
+        assert_eq!(counter.add_batch(&[]), Ok(6));
+        assert_eq!(counter.get(), Ok(6));
+    }
+    
+    #[test]
+    fn test_shared_counter_batch_matches_per_element() {
+        let values: Vec<i32> = (1..=100).collect();
+        
+        let per_element = SharedCounter::new();
+        for &value in &values {
+            per_element.add(value).unwrap();
+        }
+        
+        let batch = SharedCounter::new();
+        assert_eq!(batch.add_batch(&values), per_element.get());
+        assert_eq!(batch.get(), Ok(5050));
+    }
+    
+    #[test]
+    fn test_shared_counter_batch_overflow_leaves_total() {
+        let counter = SharedCounter::new();
+        counter.add(5).unwrap();
+        
+        assert_eq!(
+            counter.add_batch(&[i32::MAX, 1]),
+            Err(ConcurrencyError::Overflow { total: 5 })
+        );
+        assert_eq!(counter.get(), Ok(5));
+        
+        let empty = SharedCounter::new();
+        assert_eq!(
+            empty.add_batch(&[i32::MAX, 1]),
+            Err(ConcurrencyError::Overflow { total: 0 })
+        );
+        assert_eq!(empty.get(), Ok(0));
+    }
+    
+    #[test]
+    fn test_shared_counter_add_overflow() {
+        let counter = SharedCounter::new();
+        counter.add(i32::MAX).unwrap();
+        
+        assert_eq!(counter.add(1), Err(ConcurrencyError::Overflow { total: i32::MAX }));
+        assert_eq!(counter.get(), Ok(i32::MAX));
+    }
+    
+    #[test]
+    fn test_shared_counter_batches_across_threads() {
+        let counter = SharedCounter::new();
+        let mut handles = vec![];
+        
+        for _ in 0..4 {
+            let counter_clone = counter.clone();
+            handles.push(thread::spawn(move || {
+                counter_clone.add_batch(&[10, 20, 30]).unwrap();
+            }));
+        }
+        
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        
+        assert_eq!(counter.get(), Ok(240));
+    }
+    
+    #[test]
+    fn test_shared_counter_poisoned() {
+        let counter = SharedCounter::new();
+        let counter_clone = counter.clone();
+        let handle = thread::spawn(move || {
+            let _guard = counter_clone.total.lock().unwrap();
+            panic!("Panic intencional");
+        });
+        assert!(handle.join().is_err());
+        
+        assert!(matches!(counter.add_batch(&[1]), Err(ConcurrencyError::LockPoisoned(_))));
+    }
+    
     #[test]
     fn test_thread_local_storage() {
         use std::cell::RefCell;
@@ -314,6 +1183,575 @@ mod concurrency_tests {
         });
     }
     
+    #[test]
+    fn test_logged_counter_records_every_increment() {
+        let logger = VecLogger::default();
+        let counter = Arc::new(LoggedCounter::new(logger.clone()));
+        
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let counter = Arc::clone(&counter);
+                thread::spawn(move || {
+                    for _ in 0..25 {
+                        counter.increment().unwrap();
+                    }
+                    current_thread_id()
+                })
+            })
+            .collect();
+        let mut thread_ids: Vec<usize> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+        thread_ids.sort();
+        thread_ids.dedup();
+        assert_eq!(thread_ids.len(), 4);
+        
+        let events = logger.events.lock().unwrap();
+        assert_eq!(events.len(), 300);
+        
+        // Cada incremento aparece una vez, con valores 1..=100 en orden
+        let increments: Vec<i32> = events
+            .iter()
+            .filter_map(|event| match event.kind {
+                EventKind::Incremented(value) => Some(value),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(increments, (1..=100).collect::<Vec<_>>());
+        
+        // Con el lock tomado solo se emiten LockAcquired e Incremented, y del mismo thread
+        let under_lock: Vec<&LogEvent> = events
+            .iter()
+            .filter(|event| !matches!(event.kind, EventKind::LockReleased(_)))
+            .collect();
+        for op in under_lock.chunks(2) {
+            assert_eq!(op[0].kind, EventKind::LockAcquired);
+            assert!(matches!(op[1].kind, EventKind::Incremented(_)));
+            assert_eq!(op[0].thread_id, op[1].thread_id);
+        }
+        
+        // Cada thread ve sus operaciones completas y en orden
+        for &id in &thread_ids {
+            let own: Vec<&LogEvent> = events.iter().filter(|event| event.thread_id == id).collect();
+            assert_eq!(own.len(), 75);
+            for op in own.chunks(3) {
+                assert_eq!(op[0].kind, EventKind::LockAcquired);
+                assert!(matches!(op[1].kind, EventKind::Incremented(_)));
+                assert!(matches!(op[2].kind, EventKind::LockReleased(_)));
+            }
+            assert!(own.windows(2).all(|w| w[0].timestamp <= w[1].timestamp));
+        }
+    }
+    
+    /// Logger que, al recibir `LockReleased`, comprueba si el lock ya está libre
+    struct ReleaseProbe {
+        counter: Weak<LoggedCounter<ReleaseProbe>>,
+        lock_free_on_release: Mutex<Vec<bool>>,
+    }
+    
+    impl Logger for ReleaseProbe {
+        fn log(&self, event: &LogEvent) {
+            if let EventKind::LockReleased(_) = event.kind {
+                let counter = self.counter.upgrade().unwrap();
+                let free = counter.counter.try_lock().is_ok();
+                self.lock_free_on_release.lock().unwrap().push(free);
+            }
+        }
+    }
+    
+    #[test]
+    fn test_logged_counter_logs_release_after_unlocking() {
+        let counter = Arc::new_cyclic(|weak| {
+            LoggedCounter::new(ReleaseProbe {
+                counter: weak.clone(),
+                lock_free_on_release: Mutex::new(Vec::new()),
+            })
+        });
+        
+        for _ in 0..3 {
+            counter.increment().unwrap();
+        }
+        
+        assert_eq!(*counter.logger.lock_free_on_release.lock().unwrap(), vec![true; 3]);
+    }
+    
+    #[test]
+    fn test_logged_counter_logs_poisoned_lock() {
+        let logger = VecLogger::default();
+        let counter = Arc::new(LoggedCounter::new(logger.clone()));
+        
+        let poisoner = Arc::clone(&counter);
+        let _ = thread::spawn(move || {
+            let _guard = poisoner.counter.lock().unwrap();
+            panic!("panic con el lock tomado");
+        })
+        .join();
+        
+        assert!(matches!(counter.increment(), Err(ConcurrencyError::LockPoisoned(_))));
+        let events = logger.events.lock().unwrap();
+        assert_eq!(events.len(), 1);
+        assert!(matches!(events[0].kind, EventKind::Error(_)));
+    }
+    
+    #[test]
+    fn test_rate_limiter_burst_then_refill() {
+        // 20 tokens/s: un token cada 50ms
+        let limiter = RateLimiter::new(5, 20);
+        
+        for i in 0..5 {
+            assert!(limiter.try_acquire(1), "la petición {} de la ráfaga debería pasar", i);
+        }
+        assert!(!limiter.try_acquire(1));
+        
+        assert_eventually!(
+            limiter.try_acquire(1),
+            Duration::from_millis(500),
+            Duration::from_millis(5),
+            "el bucket debería recuperar un token en ~50ms"
+        );
+        assert!(!limiter.try_acquire(1));
+    }
+    
+    #[test]
+    fn test_rate_limiter_shared_between_threads() {
+        // Sin relleno: entre todos los threads solo caben `capacity` peticiones
+        let limiter = RateLimiter::new(10, 0);
+        
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let limiter = limiter.clone();
+                thread::spawn(move || (0..5).filter(|_| limiter.try_acquire(1)).count())
+            })
+            .collect();
+        let accepted: usize = handles.into_iter().map(|h| h.join().unwrap()).sum();
+        
+        assert_eq!(accepted, 10);
+        assert!(!limiter.try_acquire(1));
+        
+        // Una petición mayor que los tokens disponibles no consume nada
+        let limiter = RateLimiter::new(3, 0);
+        assert!(!limiter.try_acquire(4));
+        assert!(limiter.try_acquire(3));
+    }
+    
+    #[test]
+    fn test_sharded_map_concurrent_disjoint_inserts() {
+        const THREADS: u32 = 16;
+        const KEYS_PER_THREAD: u32 = 1_000;
+        
+        // Menos shards que threads para forzar que compartan locks
+        let map = Arc::new(ShardedMap::new(8));
+        
+        let handles: Vec<_> = (0..THREADS)
+            .map(|t| {
+                let map = Arc::clone(&map);
+                thread::spawn(move || {
+                    for key in t * KEYS_PER_THREAD..(t + 1) * KEYS_PER_THREAD {
+                        assert_eq!(map.insert(key, key * 2), None);
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        
+        assert_eq!(map.len(), (THREADS * KEYS_PER_THREAD) as usize);
+        for key in 0..THREADS * KEYS_PER_THREAD {
+            assert_eq!(map.get(&key), Some(key * 2));
+        }
+        assert_eq!(map.get(&(THREADS * KEYS_PER_THREAD)), None);
+        
+        // Reinsertar devuelve el valor anterior
+        assert_eq!(map.insert(7, 0), Some(14));
+        assert_eq!(map.get(&7), Some(0));
+    }
+    
+    #[test]
+    fn test_backoff_sender_slow_receiver_receives_all_in_order() {
+        let (tx, rx) = mpsc::sync_channel(1);
+        let sender = BackoffSender::new(tx, 10, Duration::from_millis(1), Duration::from_millis(64));
+        
+        let consumer = thread::spawn(move || {
+            let mut received = Vec::new();
+            for value in rx {
+                thread::sleep(Duration::from_millis(15));
+                received.push(value);
+            }
+            received
+        });
+        
+        for i in 0..5 {
+            sender.send(i).unwrap();
+        }
+        let retries = sender.retries();
+        drop(sender);
+        
+        assert_eq!(consumer.join().unwrap(), vec![0, 1, 2, 3, 4]);
+        // Capacidad 1 y un consumidor lento: el productor tiene que esperar varias veces
+        assert!(retries >= 4, "solo {} reintentos", retries);
+    }
+    
+    #[test]
+    fn test_backoff_sender_gives_value_back() {
+        // Sin consumidor: el canal se llena y se agotan los intentos
+        let (tx, rx) = mpsc::sync_channel(1);
+        let sender = BackoffSender::new(tx, 3, Duration::from_millis(1), Duration::from_millis(4));
+        sender.send("a").unwrap();
+        assert_eq!(sender.send("b"), Err(SendError::Full("b")));
+        assert_eq!(sender.retries(), 2);
+        
+        drop(rx);
+        assert_eq!(sender.send("c"), Err(SendError::Disconnected("c")));
+    }
+    
+    #[test]
+    fn test_cancellation_token_stops_worker() {
+        let token = CancellationToken::new();
+        let counter = Arc::new(AtomicUsize::new(0));
+        
+        let worker = {
+            let token = token.clone();
+            let counter = Arc::clone(&counter);
+            thread::spawn(move || {
+                while !token.is_cancelled() {
+                    counter.fetch_add(1, Ordering::Relaxed);
+                    thread::yield_now();
+                }
+            })
+        };
+        
+        assert_eventually!(
+            counter.load(Ordering::Relaxed) > 0,
+            Duration::from_secs(1),
+            Duration::from_millis(1),
+            "el worker debería empezar a contar"
+        );
+        
+        let cancelled_at = Instant::now();
+        token.cancel();
+        worker.join().unwrap();
+        // Una sola iteración entre comprobaciones: el worker sale casi al instante
+        assert!(cancelled_at.elapsed() < Duration::from_millis(100), "tardó {:?}", cancelled_at.elapsed());
+        
+        let final_count = counter.load(Ordering::Relaxed);
+        thread::sleep(Duration::from_millis(10));
+        assert_eq!(counter.load(Ordering::Relaxed), final_count);
+        assert!(token.is_cancelled());
+    }
+    
+    #[test]
+    fn test_thread_local_counter_merge_equals_n_times_m() {
+        const THREADS: usize = 8;
+        const INCREMENTS: u64 = 10_000;
+        let counter = Arc::new(ThreadLocalCounter::new());
+        
+        let handles: Vec<_> = (0..THREADS)
+            .map(|_| {
+                let counter = Arc::clone(&counter);
+                thread::spawn(move || {
+                    for _ in 0..INCREMENTS {
+                        counter.increment();
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        
+        assert_eq!(counter.merge(), THREADS as u64 * INCREMENTS);
+        assert_eq!(counter.thread_count(), THREADS);
+    }
+    
+    #[test]
+    fn test_thread_local_counters_are_independent() {
+        let first = ThreadLocalCounter::new();
+        let second = ThreadLocalCounter::new();
+        
+        first.add(5);
+        let tally = first.local();
+        tally.add(2);
+        second.increment();
+        
+        // La misma cuenta del thread se reutiliza en cada llamada
+        assert_eq!(first.thread_count(), 1);
+        assert_eq!(first.merge(), 7);
+        assert_eq!(second.merge(), 1);
+    }
+    
+    #[test]
+    fn test_thread_local_tallies_of_dropped_counters_are_released() {
+        let entries = thread::spawn(|| {
+            for _ in 0..100 {
+                let counter = ThreadLocalCounter::new();
+                counter.increment();
+                assert_eq!(counter.merge(), 1);
+            }
+            LOCAL_TALLIES.with(|tallies| tallies.borrow().len())
+        })
+        .join()
+        .unwrap();
+        
+        // Solo queda la entrada del último contador, que se quitará en el siguiente registro
+        assert_eq!(entries, 1);
+    }
+    
+    #[test]
+    fn test_semaphore_caps_concurrent_holders() {
+        let semaphore = Arc::new(Semaphore::new(2));
+        let active = Arc::new(AtomicUsize::new(0));
+        let max_active = Arc::new(AtomicUsize::new(0));
+        
+        let handles: Vec<_> = (0..5)
+            .map(|_| {
+                let semaphore = Arc::clone(&semaphore);
+                let active = Arc::clone(&active);
+                let max_active = Arc::clone(&max_active);
+                thread::spawn(move || {
+                    for _ in 0..3 {
+                        let _permit = semaphore.acquire();
+                        let now_active = active.fetch_add(1, Ordering::SeqCst) + 1;
+                        max_active.fetch_max(now_active, Ordering::SeqCst);
+                        thread::sleep(Duration::from_millis(5));
+                        active.fetch_sub(1, Ordering::SeqCst);
+                    }
+                })
+            })
+            .collect();
+        
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        
+        let max = max_active.load(Ordering::SeqCst);
+        assert!(max <= 2, "hubo {} threads a la vez con 2 permisos", max);
+        assert!(max >= 1);
+        assert_eq!(semaphore.available_permits(), 2);
+    }
+    
+    #[test]
+    fn test_semaphore_try_acquire_and_release_on_drop() {
+        let semaphore = Semaphore::new(1);
+        
+        let guard = semaphore.try_acquire().expect("hay un permiso libre");
+        assert!(semaphore.try_acquire().is_none());
+        
+        drop(guard);
+        assert_eq!(semaphore.available_permits(), 1);
+        assert!(semaphore.try_acquire().is_some());
+    }
+    
+    #[test]
+    fn test_latch_releases_all_waiters() {
+        const THREADS: usize = 5;
+        let latch = Arc::new(Latch::new());
+        let proceeded = Arc::new(AtomicUsize::new(0));
+        
+        let handles: Vec<_> = (0..THREADS)
+            .map(|_| {
+                let latch = Arc::clone(&latch);
+                let proceeded = Arc::clone(&proceeded);
+                thread::spawn(move || {
+                    latch.wait();
+                    proceeded.fetch_add(1, Ordering::SeqCst);
+                })
+            })
+            .collect();
+        
+        thread::sleep(Duration::from_millis(20));
+        assert!(!latch.is_set());
+        assert_eq!(proceeded.load(Ordering::SeqCst), 0, "ningún thread debe pasar antes de set");
+        
+        latch.set();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        
+        assert!(latch.is_set());
+        assert_eq!(proceeded.load(Ordering::SeqCst), THREADS);
+        // Tras set, wait no bloquea
+        latch.wait();
+    }
+    
+    #[test]
+    fn test_wait_group_waits_for_all_workers() {
+        const WORKERS: usize = 5;
+        let wait_group = WaitGroup::new();
+        let finished = Arc::new(AtomicUsize::new(0));
+        
+        wait_group.add(WORKERS);
+        for i in 0..WORKERS {
+            let wait_group = wait_group.clone();
+            let finished = Arc::clone(&finished);
+            // Los handles se descartan: la espera es solo con el WaitGroup
+            thread::spawn(move || {
+                thread::sleep(Duration::from_millis(10 * (i as u64 + 1)));
+                finished.fetch_add(1, Ordering::SeqCst);
+                wait_group.done();
+            });
+        }
+        
+        wait_group.wait();
+        assert_eq!(finished.load(Ordering::SeqCst), WORKERS);
+        // Sin tareas pendientes, wait no bloquea
+        wait_group.wait();
+    }
+    
+    #[test]
+    #[should_panic(expected = "WaitGroup::done sin un add pendiente")]
+    fn test_wait_group_done_without_add_panics() {
+        WaitGroup::new().done();
+    }
+    
+    #[test]
+    fn test_fn_barrier_runs_callback_once_on_last_thread() {
+        const THREADS: usize = 4;
+        let completed = Arc::new(AtomicUsize::new(0));
+        let barrier = {
+            let completed = Arc::clone(&completed);
+            Arc::new(FnBarrier::new(THREADS, move || {
+                completed.fetch_add(1, Ordering::SeqCst);
+            }))
+        };
+        
+        let handles: Vec<_> = (0..THREADS)
+            .map(|_| {
+                let barrier = Arc::clone(&barrier);
+                let completed = Arc::clone(&completed);
+                thread::spawn(move || {
+                    let is_leader = barrier.wait();
+                    // Nadie sale de wait antes de que termine el callback
+                    assert_eq!(completed.load(Ordering::SeqCst), 1);
+                    is_leader
+                })
+            })
+            .collect();
+        
+        let leaders = handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .filter(|&is_leader| is_leader)
+            .count();
+        assert_eq!(leaders, 1);
+        assert_eq!(completed.load(Ordering::SeqCst), 1);
+    }
+    
+    #[test]
+    fn test_double_buffer_readers_see_only_committed_versions() {
+        #[derive(Debug, Clone)]
+        struct State {
+            version: u64,
+            values: Vec<u64>,
+        }
+        
+        const FLIPS: u64 = 50;
+        let buffer = Arc::new(DoubleBuffer::new(State { version: 0, values: vec![0; 32] }));
+        let done = Arc::new(AtomicBool::new(false));
+        
+        let readers: Vec<_> = (0..6)
+            .map(|_| {
+                let buffer = Arc::clone(&buffer);
+                let done = Arc::clone(&done);
+                thread::spawn(move || {
+                    let mut last_version = 0;
+                    let mut reads = 0u64;
+                    while !done.load(Ordering::Acquire) {
+                        let state = buffer.read();
+                        assert!(
+                            state.values.iter().all(|&v| v == state.version),
+                            "estado a medias en la versión {}",
+                            state.version
+                        );
+                        assert!(state.version >= last_version, "la versión retrocedió de {} a {}", last_version, state.version);
+                        last_version = state.version;
+                        reads += 1;
+                    }
+                    reads
+                })
+            })
+            .collect();
+        
+        for _ in 0..FLIPS {
+            buffer.update(|state| {
+                state.version += 1;
+                for value in &mut state.values {
+                    *value = state.version;
+                }
+            });
+            thread::sleep(Duration::from_millis(1));
+        }
+        done.store(true, Ordering::Release);
+        
+        let total_reads: u64 = readers.into_iter().map(|handle| handle.join().unwrap()).sum();
+        assert!(total_reads > 0);
+        assert_eq!(buffer.read().version, FLIPS);
+    }
+    
+    #[test]
+    fn test_instrumented_rwlock_counts_locks() {
+        let lock = InstrumentedRwLock::new(0);
+        assert_eq!(lock.stats(), RwLockStats { read_locks: 0, write_locks: 0, current_readers: 0 });
+        
+        {
+            let readers: Vec<_> = (0..3).map(|_| lock.read().unwrap()).collect();
+            assert_eq!(readers.iter().map(|r| **r).sum::<i32>(), 0);
+            assert_eq!(lock.stats(), RwLockStats { read_locks: 3, write_locks: 0, current_readers: 3 });
+        }
+        assert_eq!(lock.stats().current_readers, 0, "los guards soltados se descuentan");
+        
+        for _ in 0..2 {
+            *lock.write().unwrap() += 1;
+        }
+        assert_eq!(*lock.read().unwrap(), 2);
+        assert_eq!(lock.stats(), RwLockStats { read_locks: 4, write_locks: 2, current_readers: 0 });
+        
+        // Con varios threads, el resultado tras los join es exacto
+        let lock = Arc::new(lock);
+        let handles: Vec<_> = (0..4)
+            .map(|i| {
+                let lock = Arc::clone(&lock);
+                thread::spawn(move || {
+                    for _ in 0..25 {
+                        let _value = *lock.read().unwrap();
+                    }
+                    if i == 0 {
+                        *lock.write().unwrap() += 1;
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        assert_eq!(lock.stats(), RwLockStats { read_locks: 104, write_locks: 3, current_readers: 0 });
+    }
+    
+    #[test]
+    fn test_parallel_find_single_match_in_large_slice() {
+        let data: Vec<u32> = (0..2_000_000).collect();
+        assert_eq!(parallel_find(&data, |&x| x == 1_234_567), Some(1_234_567));
+        assert_eq!(parallel_find(&data, |&x| x == 0), Some(0));
+        assert_eq!(parallel_find(&data, |&x| x == 1_999_999), Some(1_999_999));
+        
+        let mut flags = vec![false; 1_000_003];
+        flags[999_999] = true;
+        assert_eq!(parallel_find(&flags, |&flag| flag), Some(999_999));
+    }
+    
+    #[test]
+    fn test_parallel_find_no_match_returns_none() {
+        let data: Vec<u32> = (0..1_000_000).collect();
+        let examined = AtomicUsize::new(0);
+        let found = parallel_find(&data, |&x| {
+            examined.fetch_add(1, Ordering::Relaxed);
+            x > 1_000_000
+        });
+        assert_eq!(found, None);
+        assert_eq!(examined.load(Ordering::Relaxed), data.len());
+        
+        let empty: [u32; 0] = [];
+        assert_eq!(parallel_find(&empty, |_| true), None);
+    }
+    
     #[test]
     fn test_arc_weak_references() {
         use std::rc::{Rc, Weak};
@@ -339,6 +1777,108 @@ mod concurrency_tests {
             panic!("Weak reference debería ser inválida");
         }
     }
+    
+    // Tests de propiedades: `cargo test --features quickcheck`
+    #[cfg(feature = "quickcheck")]
+    mod counter_properties {
+        use super::Counter;
+        use quickcheck::{Arbitrary, Gen, QuickCheck, TestResult};
+        use std::sync::mpsc;
+        use std::sync::{Arc, Mutex};
+        use std::thread;
+        
+        impl Arbitrary for Counter {
+            fn arbitrary(g: &mut Gen) -> Self {
+                Self {
+                    value: (u32::arbitrary(g) % 1000) as i32,
+                    subscribers: Vec::new(),
+                }
+            }
+            
+            fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+                Box::new(self.value.shrink().map(|value| Self {
+                    value,
+                    subscribers: Vec::new(),
+                }))
+            }
+        }
+        
+        /// Convierte el resultado de una propiedad en un `TestResult` que incluye su descripción
+        fn check(description: &str, expected: i32, actual: i32) -> TestResult {
+            if expected == actual {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!("{}: esperado {}, obtenido {}", description, expected, actual))
+            }
+        }
+        
+        #[test]
+        fn prop_increment_n_times_adds_n() {
+            fn prop(start: Counter, n: u8) -> TestResult {
+                let mut counter = start.clone();
+                for _ in 0..n {
+                    counter.increment();
+                }
+                check(
+                    "incrementar n veces suma exactamente n",
+                    start.get_value() + n as i32,
+                    counter.get_value(),
+                )
+            }
+            QuickCheck::new().tests(100).quickcheck(prop as fn(Counter, u8) -> TestResult);
+        }
+        
+        #[test]
+        fn prop_threads_increment_k_times_m() {
+            fn prop(start: Counter, k: u8, m: u8) -> TestResult {
+                // Acotados para que cada caso tarde poco
+                let (k, m) = (k % 8 + 1, m % 64);
+                let counter = Arc::new(Mutex::new(start.clone()));
+                
+                let handles: Vec<_> = (0..k)
+                    .map(|_| {
+                        let counter = Arc::clone(&counter);
+                        thread::spawn(move || {
+                            for _ in 0..m {
+                                counter.lock().unwrap().increment();
+                            }
+                        })
+                    })
+                    .collect();
+                for handle in handles {
+                    handle.join().unwrap();
+                }
+                
+                let value = counter.lock().unwrap().get_value();
+                check(
+                    "k threads incrementando m veces suman k*m",
+                    start.get_value() + k as i32 * m as i32,
+                    value,
+                )
+            }
+            QuickCheck::new().tests(100).quickcheck(prop as fn(Counter, u8, u8) -> TestResult);
+        }
+        
+        #[test]
+        fn prop_channel_delivers_one_message_per_sender() {
+            fn prop(n: u8) -> TestResult {
+                let n = n % 32 + 1;
+                let (tx, rx) = mpsc::channel();
+                
+                for id in 0..n {
+                    let tx = tx.clone();
+                    thread::spawn(move || tx.send(id).unwrap());
+                }
+                // El receptor termina cuando se liberan todos los senders
+                drop(tx);
+                
+                check(
+                    "n senders enviando un mensaje entregan n mensajes",
+                    n as i32,
+                    rx.iter().count() as i32,
+                )
+            }
+            QuickCheck::new().tests(100).quickcheck(prop as fn(u8) -> TestResult);
+        }
+    }
 }
-
-
@@ -2,9 +2,17 @@
 
 #[cfg(test)]
 mod error_handling_tests {
-    use std::fs::File;
-    use std::io::{self, Write};
+    use std::cell::Cell;
+    use std::collections::HashMap;
+    use std::env;
+    use std::fs::{self, File};
+    use std::io::{self, BufRead, Read, Write};
     use std::num::ParseIntError;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{mpsc, Arc, Barrier, Mutex, MutexGuard, OnceLock};
+    use std::thread;
+    use std::path::{Path, PathBuf};
+    use std::time::{Duration, SystemTime};
     
     // Importar las estructuras del ejercicio
     #[derive(Debug, Clone, PartialEq)]
@@ -26,21 +34,800 @@ mod error_handling_tests {
         }
         
         fn set_debug_level(&mut self, level: &str) -> Result<(), String> {
-            let valid_levels = ["trace", "debug", "info", "warn", "error"];
+            check_debug_level(level)?;
+            self.debug_level = level.to_string();
+            Ok(())
+        }
+        
+        fn get_debug_level(&self) -> &str {
+            &self.debug_level
+        }
+        
+        fn validate_port_range(&self, min: u16, max: u16) -> Result<(), ConfigError> {
+            assert!(min <= max, "rango de puertos vacío: {}..={}", min, max);
             
-            if valid_levels.contains(&level) {
-                self.debug_level = level.to_string();
+            if (min..=max).contains(&self.port) {
                 Ok(())
             } else {
-                Err(format!("Nivel de debug inválido: {}. Niveles válidos: {:?}", level, valid_levels))
+                Err(ConfigError::PortOutOfRange { port: self.port, min, max })
             }
         }
         
-        fn get_debug_level(&self) -> &str {
-            &self.debug_level
+        fn from_env(prefix: &str) -> Result<Config, ConfigError> {
+            let var = |field: &str| format!("{}_{}", prefix, field);
+            
+            let port_var = var("PORT");
+            let port = match env::var(&port_var) {
+                Ok(value) => parse_env_port(&port_var, &value)?,
+                Err(_) => return Err(ConfigError::MissingField(port_var)),
+            };
+            
+            let mut config = Config {
+                port,
+                ..Config::default()
+            };
+            
+            let host_var = var("HOST");
+            if let Ok(value) = env::var(&host_var) {
+                config.host = parse_env_host(&host_var, &value)?;
+            }
+            
+            let timeout_var = var("TIMEOUT");
+            if let Ok(value) = env::var(&timeout_var) {
+                config.timeout = parse_env_timeout(&timeout_var, &value)?;
+            }
+            
+            if let Ok(value) = env::var(var("DEBUG_LEVEL")) {
+                config.set_debug_level(value.trim()).map_err(ConfigError::ParseError)?;
+            }
+            
+            Ok(config)
+        }
+        
+        fn from_env_or_default() -> Config {
+            let mut config = Config::default();
+            
+            if let Some(port) = env::var("APP_PORT").ok().and_then(|v| parse_env_port("APP_PORT", &v).ok()) {
+                config.port = port;
+            }
+            if let Some(host) = env::var("APP_HOST").ok().and_then(|v| parse_env_host("APP_HOST", &v).ok()) {
+                config.host = host;
+            }
+            if let Some(timeout) = env::var("APP_TIMEOUT").ok().and_then(|v| parse_env_timeout("APP_TIMEOUT", &v).ok()) {
+                config.timeout = timeout;
+            }
+            if let Ok(level) = env::var("APP_DEBUG_LEVEL") {
+                let _ = config.set_debug_level(level.trim());
+            }
+            
+            config
+        }
+        
+        fn from_file(path: &str) -> ParseResult<Config> {
+            let contents = read_config_file(path)
+                .map_err(|e| ConfigError::FileNotFound(format!("{} ({})", path, e)))?;
+            Config::parse(&contents)
+        }
+        
+        fn parse(contents: &str) -> ParseResult<Config> {
+            contents
+                .lines()
+                .enumerate()
+                .map(|(index, raw_line)| (index + 1, raw_line.trim()))
+                .filter(|(_, trimmed)| !trimmed.is_empty() && !trimmed.starts_with('#'))
+                .try_fold(Config::default(), |config, (line, trimmed)| {
+                    let entry = ConfigEntry::try_from(trimmed).map_err(|e| match e {
+                        ConfigError::ParseError(message) => ConfigError::ParseAt { line, message },
+                        other => other,
+                    })?;
+                    Ok(config.with_entry(entry))
+                })
+        }
+        
+        fn with_entry(mut self, entry: ConfigEntry) -> Config {
+            match entry {
+                ConfigEntry::Port(port) => self.port = port,
+                ConfigEntry::Host(host) => self.host = host,
+                ConfigEntry::Timeout(timeout) => self.timeout = timeout,
+                ConfigEntry::DebugLevel(level) => self.debug_level = level,
+            }
+            self
+        }
+        
+        fn apply_cli_overrides(&mut self, args: &[String]) -> Result<(), ConfigError> {
+            let mut config = self.clone();
+            let mut args = args.iter();
+            
+            while let Some(flag) = args.next() {
+                if !matches!(flag.as_str(), "--port" | "--host" | "--timeout" | "--debug-level") {
+                    return Err(ConfigError::UnknownFlag(flag.clone()));
+                }
+                let value = match args.next() {
+                    Some(value) if !value.starts_with("--") => value,
+                    _ => return Err(ConfigError::MissingFlagValue(flag.clone())),
+                };
+                
+                match flag.as_str() {
+                    "--port" => config.port = parse_env_port(flag, value)?,
+                    "--host" => config.host = parse_env_host(flag, value)?,
+                    "--timeout" => config.timeout = parse_env_timeout(flag, value)?,
+                    _ => config.set_debug_level(value.trim()).map_err(ConfigError::ParseError)?,
+                }
+            }
+            
+            *self = config;
+            Ok(())
+        }
+    }
+    
+    #[derive(Debug, PartialEq)]
+    enum ConfigError {
+        FileNotFound(String),
+        ParseError(String),
+        ParseAt { line: usize, message: String },
+        MissingField(String),
+        PortOutOfRange { port: u16, min: u16, max: u16 },
+        UnknownFlag(String),
+        MissingFlagValue(String),
+    }
+    
+    type ParseResult<T> = Result<T, ConfigError>;
+    
+    impl Default for Config {
+        fn default() -> Self {
+            Config::new(8080, "localhost".to_string(), 30)
+        }
+    }
+    
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    enum Either<L, R> {
+        Left(L),
+        Right(R),
+    }
+    
+    impl<L, R> Either<L, R> {
+        fn is_left(&self) -> bool {
+            matches!(self, Either::Left(_))
+        }
+        
+        fn is_right(&self) -> bool {
+            !self.is_left()
+        }
+        
+        fn map_left<T>(self, f: impl FnOnce(L) -> T) -> Either<T, R> {
+            match self {
+                Either::Left(left) => Either::Left(f(left)),
+                Either::Right(right) => Either::Right(right),
+            }
+        }
+        
+        fn map_right<T>(self, f: impl FnOnce(R) -> T) -> Either<L, T> {
+            match self {
+                Either::Left(left) => Either::Left(left),
+                Either::Right(right) => Either::Right(f(right)),
+            }
+        }
+        
+        fn into_result(self) -> Result<L, R> {
+            match self {
+                Either::Left(left) => Ok(left),
+                Either::Right(right) => Err(right),
+            }
+        }
+    }
+    
+    fn parse_number_or_word(s: &str) -> Either<i64, String> {
+        let trimmed = s.trim();
+        match trimmed.parse() {
+            Ok(number) => Either::Left(number),
+            Err(_) => Either::Right(trimmed.to_string()),
+        }
+    }
+    
+    const DEBUG_LEVELS: [&str; 5] = ["trace", "debug", "info", "warn", "error"];
+    
+    fn check_debug_level(level: &str) -> Result<(), String> {
+        if DEBUG_LEVELS.contains(&level) {
+            Ok(())
+        } else {
+            Err(format!("Nivel de debug inválido: {}. Niveles válidos: {:?}", level, DEBUG_LEVELS))
+        }
+    }
+    
+    #[derive(Debug, Clone, PartialEq)]
+    enum ConfigEntry {
+        Port(u16),
+        Host(String),
+        Timeout(u64),
+        DebugLevel(String),
+    }
+    
+    impl TryFrom<&str> for ConfigEntry {
+        type Error = ConfigError;
+        
+        fn try_from(line: &str) -> Result<Self, Self::Error> {
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| ConfigError::ParseError(format!("se esperaba 'clave=valor', encontrado '{}'", line.trim())))?;
+            let (key, value) = (key.trim(), value.trim());
+            
+            match key {
+                "port" => validate_port_safe(value).map(ConfigEntry::Port).map_err(ConfigError::ParseError),
+                "host" if value.is_empty() => Err(ConfigError::ParseError("el host no puede estar vacío".to_string())),
+                "host" => Ok(ConfigEntry::Host(value.to_string())),
+                "timeout" => value
+                    .parse()
+                    .map(ConfigEntry::Timeout)
+                    .map_err(|e: ParseIntError| ConfigError::ParseError(format!("timeout '{}' inválido: {}", value, e))),
+                "debug_level" => check_debug_level(value)
+                    .map(|()| ConfigEntry::DebugLevel(value.to_string()))
+                    .map_err(ConfigError::ParseError),
+                _ => Err(ConfigError::ParseError(format!("clave desconocida '{}'", key))),
+            }
+        }
+    }
+    
+    struct LazyConfig {
+        cell: OnceLock<Config>,
+    }
+    
+    impl LazyConfig {
+        const fn new() -> Self {
+            Self {
+                cell: OnceLock::new(),
+            }
+        }
+        
+        fn get_or_init(&self, init: impl FnOnce() -> Config) -> &Config {
+            self.cell.get_or_init(init)
+        }
+    }
+    
+    // Cuenta las ejecuciones del inicializador de `global()`
+    static GLOBAL_INIT_CALLS: AtomicUsize = AtomicUsize::new(0);
+    static GLOBAL_CONFIG: LazyConfig = LazyConfig::new();
+    
+    fn global() -> &'static Config {
+        GLOBAL_CONFIG.get_or_init(|| {
+            GLOBAL_INIT_CALLS.fetch_add(1, Ordering::SeqCst);
+            Config::default()
+        })
+    }
+    
+    #[derive(Debug)]
+    struct ReloadableConfig {
+        path: PathBuf,
+        config: Config,
+        modified: SystemTime,
+    }
+    
+    impl ReloadableConfig {
+        fn new(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+            let path = path.as_ref().to_path_buf();
+            let modified = modified_time(&path)?;
+            let config = read_config(&path)?;
+            Ok(Self { path, config, modified })
+        }
+        
+        fn config(&self) -> &Config {
+            &self.config
+        }
+        
+        fn reload_if_changed(&mut self) -> Result<bool, ConfigError> {
+            let modified = modified_time(&self.path)?;
+            if modified <= self.modified {
+                return Ok(false);
+            }
+            
+            self.config = read_config(&self.path)?;
+            self.modified = modified;
+            Ok(true)
+        }
+    }
+    
+    fn modified_time(path: &Path) -> Result<SystemTime, ConfigError> {
+        fs::metadata(path)
+            .and_then(|metadata| metadata.modified())
+            .map_err(|e| ConfigError::FileNotFound(format!("{} ({})", path.display(), e)))
+    }
+    
+    fn read_config(path: &Path) -> Result<Config, ConfigError> {
+        let contents = fs::read_to_string(path)
+            .map_err(|e| ConfigError::FileNotFound(format!("{} ({})", path.display(), e)))?;
+        Config::parse(&contents)
+    }
+    
+    fn write_config(path: &Path, contents: &str, modified: SystemTime) -> io::Result<()> {
+        let mut tmp = path.as_os_str().to_owned();
+        tmp.push(".tmp");
+        let tmp = PathBuf::from(tmp);
+        
+        let written = File::create(&tmp).and_then(|mut file| {
+            file.write_all(contents.as_bytes())?;
+            file.set_modified(modified)
+        });
+        written.and_then(|()| fs::rename(&tmp, path)).inspect_err(|_| {
+            let _ = fs::remove_file(&tmp);
+        })
+    }
+    
+    #[derive(Debug)]
+    enum DemoError {
+        Config(ConfigError),
+        Io(io::Error),
+    }
+    
+    impl std::fmt::Display for DemoError {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            match self {
+                DemoError::Config(e) => write!(f, "Error de configuración: {:?}", e),
+                DemoError::Io(e) => write!(f, "Error de E/S: {}", e),
+            }
+        }
+    }
+    
+    impl From<ConfigError> for DemoError {
+        fn from(e: ConfigError) -> Self {
+            DemoError::Config(e)
+        }
+    }
+    
+    impl From<io::Error> for DemoError {
+        fn from(e: io::Error) -> Self {
+            DemoError::Io(e)
+        }
+    }
+    
+    fn run_reloadable_config_demo(path: &Path) -> Result<(), DemoError> {
+        let mut reloadable = ReloadableConfig::new(path)?;
+        let start = reloadable.modified;
+        reloadable.reload_if_changed()?;
+        
+        write_config(path, "port=9090\nhost=localhost\n", start + Duration::from_secs(1))?;
+        reloadable.reload_if_changed()?;
+        
+        write_config(path, "port=abc\n", start + Duration::from_secs(2))?;
+        let _ = reloadable.reload_if_changed();
+        Ok(())
+    }
+    
+    type OnChange = Arc<dyn Fn(Result<Config, ConfigError>) + Send + Sync>;
+    
+    struct ConfigWatcher {
+        stop: Option<mpsc::Sender<()>>,
+        handle: Option<thread::JoinHandle<()>>,
+    }
+    
+    impl ConfigWatcher {
+        fn new<F>(path: PathBuf, poll_interval: Duration, on_change: F) -> Self
+        where
+            F: Fn(Result<Config, ConfigError>) + Send + Sync + 'static,
+        {
+            let on_change: OnChange = Arc::new(on_change);
+            let last_modified = modified_time(&path).ok();
+            let (stop, stopped) = mpsc::channel::<()>();
+            
+            let handle = thread::spawn(move || {
+                let mut last_modified = last_modified;
+                while let Err(mpsc::RecvTimeoutError::Timeout) = stopped.recv_timeout(poll_interval) {
+                    last_modified = check_for_change(&path, last_modified, &on_change);
+                }
+            });
+            
+            Self {
+                stop: Some(stop),
+                handle: Some(handle),
+            }
+        }
+        
+        fn stop(mut self) {
+            self.shutdown();
+        }
+        
+        fn shutdown(&mut self) {
+            drop(self.stop.take());
+            if let Some(handle) = self.handle.take() {
+                let _ = handle.join();
+            }
+        }
+    }
+    
+    impl Drop for ConfigWatcher {
+        fn drop(&mut self) {
+            self.shutdown();
+        }
+    }
+    
+    fn check_for_change(path: &Path, last_modified: Option<SystemTime>, on_change: &OnChange) -> Option<SystemTime> {
+        let modified = modified_time(path).ok();
+        if modified.is_none() || modified == last_modified {
+            return last_modified;
+        }
+        
+        on_change(read_config(path));
+        modified
+    }
+    
+    #[derive(Debug, Clone, PartialEq)]
+    enum Command {
+        Get { key: String },
+        Set { key: String, value: String },
+        Delete { key: String },
+        Unknown(String),
+    }
+    
+    #[derive(Debug, Clone, PartialEq)]
+    enum ParseError {
+        EmptyLine,
+        MalformedField(String),
+        MissingField { command: String, field: &'static str },
+        UnexpectedField { command: String, field: String },
+        DuplicateField { command: String, field: String },
+        Io(String),
+    }
+    
+    struct ProtocolParser;
+    
+    impl ProtocolParser {
+        fn parse_line(line: &str) -> Result<Command, ParseError> {
+            let mut tokens = line.split_whitespace();
+            let name = tokens.next().ok_or(ParseError::EmptyLine)?;
+            
+            let mut fields = Vec::new();
+            for token in tokens {
+                match token.split_once('=') {
+                    Some((key, value)) if !key.is_empty() => fields.push((key, value)),
+                    _ => return Err(ParseError::MalformedField(token.to_string())),
+                }
+            }
+            
+            match name {
+                "GET" => {
+                    let [key] = expect_fields(name, &fields, ["key"])?;
+                    Ok(Command::Get { key })
+                }
+                "SET" => {
+                    let [key, value] = expect_fields(name, &fields, ["key", "value"])?;
+                    Ok(Command::Set { key, value })
+                }
+                "DELETE" => {
+                    let [key] = expect_fields(name, &fields, ["key"])?;
+                    Ok(Command::Delete { key })
+                }
+                other => Ok(Command::Unknown(other.to_string())),
+            }
+        }
+        
+        fn parse_stream(reader: impl BufRead) -> impl Iterator<Item = Result<Command, ParseError>> {
+            reader
+                .lines()
+                .filter(|line| !matches!(line, Ok(text) if text.trim().is_empty()))
+                .map(|line| {
+                    let line = line.map_err(|e| ParseError::Io(e.to_string()))?;
+                    ProtocolParser::parse_line(&line)
+                })
+        }
+    }
+    
+    const MAX_BODY_SIZE: usize = 1024 * 1024;
+    
+    #[derive(Debug, Clone, PartialEq)]
+    enum Method {
+        Get,
+        Post,
+        Put,
+        Delete,
+        Other(String),
+    }
+    
+    impl Method {
+        fn from_token(token: &str) -> Self {
+            match token {
+                "GET" => Method::Get,
+                "POST" => Method::Post,
+                "PUT" => Method::Put,
+                "DELETE" => Method::Delete,
+                other => Method::Other(other.to_string()),
+            }
+        }
+    }
+    
+    #[derive(Debug, Clone, PartialEq)]
+    struct HttpRequest {
+        method: Method,
+        path: String,
+        version: (u8, u8),
+        headers: HashMap<String, String>,
+        body: Vec<u8>,
+    }
+    
+    #[derive(Debug, Clone, PartialEq)]
+    enum HttpParseError {
+        MalformedRequestLine(String),
+        InvalidHeader(String),
+        BodyTooLarge { size: usize, max: usize },
+        Incomplete,
+    }
+    
+    struct Cursor<'a> {
+        bytes: &'a [u8],
+        pos: usize,
+    }
+    
+    impl<'a> Cursor<'a> {
+        fn next_line(&mut self) -> Option<&'a [u8]> {
+            let start = self.pos;
+            let mut i = start;
+            while i < self.bytes.len() {
+                if self.bytes[i] == b'\n' {
+                    self.pos = i + 1;
+                    let line = &self.bytes[start..i];
+                    return Some(line.strip_suffix(b"\r").unwrap_or(line));
+                }
+                i += 1;
+            }
+            None
+        }
+        
+        fn rest(&self) -> &'a [u8] {
+            &self.bytes[self.pos..]
+        }
+    }
+    
+    fn lossy(bytes: &[u8]) -> String {
+        String::from_utf8_lossy(bytes).into_owned()
+    }
+    
+    fn parse_request_line(line: &[u8]) -> Result<(Method, String, (u8, u8)), HttpParseError> {
+        let malformed = || HttpParseError::MalformedRequestLine(lossy(line));
+        
+        let mut parts: Vec<&[u8]> = Vec::with_capacity(3);
+        let mut start = 0;
+        for (i, &byte) in line.iter().enumerate() {
+            if byte == b' ' {
+                parts.push(&line[start..i]);
+                start = i + 1;
+            }
+        }
+        parts.push(&line[start..]);
+        
+        let [method, path, version] = parts[..] else {
+            return Err(malformed());
+        };
+        if method.is_empty() || !method.iter().all(|b| b.is_ascii_uppercase()) {
+            return Err(malformed());
+        }
+        if !(path.first() == Some(&b'/') || path == b"*") || !path.iter().all(|b| b.is_ascii_graphic()) {
+            return Err(malformed());
+        }
+        let version = match version {
+            [b'H', b'T', b'T', b'P', b'/', major, b'.', minor] if major.is_ascii_digit() && minor.is_ascii_digit() => {
+                (major - b'0', minor - b'0')
+            }
+            _ => return Err(malformed()),
+        };
+        
+        Ok((Method::from_token(&lossy(method)), lossy(path), version))
+    }
+    
+    fn parse_header(line: &[u8]) -> Result<(String, String), HttpParseError> {
+        let invalid = || HttpParseError::InvalidHeader(lossy(line));
+        
+        let colon = line.iter().position(|&b| b == b':').ok_or_else(invalid)?;
+        let name = &line[..colon];
+        if name.is_empty() || !name.iter().all(|b| b.is_ascii_graphic()) {
+            return Err(invalid());
+        }
+        
+        let value = line[colon + 1..].trim_ascii();
+        if value.iter().any(|&b| b.is_ascii_control() && b != b'\t') {
+            return Err(invalid());
+        }
+        let value = std::str::from_utf8(value).map_err(|_| invalid())?;
+        Ok((lossy(name).to_ascii_lowercase(), value.to_string()))
+    }
+    
+    fn parse_request(raw: &[u8]) -> Result<HttpRequest, HttpParseError> {
+        let mut cursor = Cursor { bytes: raw, pos: 0 };
+        
+        let request_line = cursor.next_line().ok_or(HttpParseError::Incomplete)?;
+        let (method, path, version) = parse_request_line(request_line)?;
+        
+        let mut headers: HashMap<String, String> = HashMap::new();
+        loop {
+            let line = cursor.next_line().ok_or(HttpParseError::Incomplete)?;
+            if line.is_empty() {
+                break;
+            }
+            let (name, value) = parse_header(line)?;
+            headers
+                .entry(name)
+                .and_modify(|existing| {
+                    existing.push_str(", ");
+                    existing.push_str(&value);
+                })
+                .or_insert(value);
+        }
+        
+        let body = match headers.get("content-length") {
+            None => Vec::new(),
+            Some(length) => {
+                let size: usize = length
+                    .parse()
+                    .map_err(|_| HttpParseError::InvalidHeader(format!("content-length: {}", length)))?;
+                if size > MAX_BODY_SIZE {
+                    return Err(HttpParseError::BodyTooLarge { size, max: MAX_BODY_SIZE });
+                }
+                cursor.rest().get(..size).ok_or(HttpParseError::Incomplete)?.to_vec()
+            }
+        };
+        
+        Ok(HttpRequest {
+            method,
+            path,
+            version,
+            headers,
+            body,
+        })
+    }
+    
+    fn retry<T, E>(attempts: u32, delay: Duration, mut op: impl FnMut() -> Result<T, E>) -> Result<T, E> {
+        let mut attempt = 1;
+        loop {
+            match op() {
+                Ok(value) => return Ok(value),
+                Err(e) if attempt >= attempts => return Err(e),
+                Err(_) => {
+                    thread::sleep(delay);
+                    attempt += 1;
+                }
+            }
         }
     }
     
+    trait Retriable {
+        fn is_retriable(&self) -> bool;
+    }
+    
+    fn retry_smart<T, E: Retriable>(
+        attempts: u32,
+        delay: Duration,
+        mut op: impl FnMut() -> Result<T, E>,
+    ) -> Result<T, E> {
+        let mut attempt = 1;
+        loop {
+            match op() {
+                Ok(value) => return Ok(value),
+                Err(e) if !e.is_retriable() || attempt >= attempts => return Err(e),
+                Err(_) => {
+                    thread::sleep(delay);
+                    attempt += 1;
+                }
+            }
+        }
+    }
+    
+    macro_rules! retry {
+        ($attempts:expr, $delay:expr, $body:block) => {{
+            let attempts: u32 = $attempts;
+            let delay: ::std::time::Duration = $delay;
+            let mut attempt = 1;
+            loop {
+                match $body {
+                    ::core::result::Result::Ok(value) => break ::core::result::Result::Ok(value),
+                    ::core::result::Result::Err(e) if attempt >= attempts => break ::core::result::Result::Err(e),
+                    ::core::result::Result::Err(_) => {
+                        ::std::thread::sleep(delay);
+                        attempt += 1;
+                    }
+                }
+            }
+        }};
+    }
+    
+    fn expect_fields<const N: usize>(
+        command: &str,
+        fields: &[(&str, &str)],
+        expected: [&'static str; N],
+    ) -> Result<[String; N], ParseError> {
+        let mut values: [Option<String>; N] = std::array::from_fn(|_| None);
+        
+        for &(key, value) in fields {
+            let Some(index) = expected.iter().position(|&name| name == key) else {
+                return Err(ParseError::UnexpectedField {
+                    command: command.to_string(),
+                    field: key.to_string(),
+                });
+            };
+            if values[index].is_some() {
+                return Err(ParseError::DuplicateField {
+                    command: command.to_string(),
+                    field: key.to_string(),
+                });
+            }
+            values[index] = Some(value.to_string());
+        }
+        
+        let mut result: [String; N] = std::array::from_fn(|_| String::new());
+        for (i, value) in values.into_iter().enumerate() {
+            result[i] = value.ok_or_else(|| ParseError::MissingField {
+                command: command.to_string(),
+                field: expected[i],
+            })?;
+        }
+        Ok(result)
+    }
+    
+    fn parse_env_port(var: &str, value: &str) -> Result<u16, ConfigError> {
+        validate_port_safe(value.trim()).map_err(|e| ConfigError::ParseError(format!("{}: {}", var, e)))
+    }
+    
+    fn parse_env_host(var: &str, value: &str) -> Result<String, ConfigError> {
+        let host = value.trim();
+        if host.is_empty() {
+            return Err(ConfigError::ParseError(format!("{} no puede estar vacío", var)));
+        }
+        Ok(host.to_string())
+    }
+    
+    fn parse_env_timeout(var: &str, value: &str) -> Result<u64, ConfigError> {
+        value
+            .trim()
+            .parse()
+            .map_err(|e: ParseIntError| ConfigError::ParseError(format!("{} '{}' inválido: {}", var, value, e)))
+    }
+    
+    // El entorno es global al proceso: los tests que lo tocan se serializan
+    static ENV_LOCK: Mutex<()> = Mutex::new(());
+    const ENV_VARS: [&str; 6] = [
+        "APP_PORT", "APP_HOST", "APP_TIMEOUT", "APP_DEBUG_LEVEL", "MYAPP_PORT", "MYAPP_HOST",
+    ];
+    
+    /// Toma el lock del entorno y limpia las variables al crearse y al destruirse
+    struct EnvGuard {
+        _lock: MutexGuard<'static, ()>,
+    }
+    
+    impl EnvGuard {
+        fn new() -> Self {
+            let lock = ENV_LOCK.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+            for var in ENV_VARS {
+                env::remove_var(var);
+            }
+            Self { _lock: lock }
+        }
+        
+        fn set(&self, var: &str, value: &str) {
+            env::set_var(var, value);
+        }
+    }
+    
+    impl Drop for EnvGuard {
+        fn drop(&mut self) {
+            for var in ENV_VARS {
+                env::remove_var(var);
+            }
+        }
+    }
+    
+    fn read_config_file(filename: &str) -> Result<String, io::Error> {
+        let mut file = File::open(filename)?;
+        let mut contents = String::new();
+        file.read_to_string(&mut contents)?;
+        Ok(contents)
+    }
+    
+    fn validate_port_safe(port_str: &str) -> Result<u16, String> {
+        let port: u16 = port_str.parse()
+            .map_err(|_| format!("'{}' no es un número válido", port_str))?;
+        
+        if port == 0 {
+            return Err("Puerto no puede ser 0".to_string());
+        }
+        
+        Ok(port)
+    }
+    
     #[test]
     fn test_config_creation() {
         let config = Config::new(8080, "localhost".to_string(), 30);
@@ -99,6 +886,578 @@ mod error_handling_tests {
         std::fs::remove_file(temp_file).unwrap();
     }
     
+    #[test]
+    fn test_config_parse_reports_line_number() {
+        let contents = "host=localhost\ntimeout=30\nport=abc";
+        
+        match Config::parse(contents) {
+            Err(ConfigError::ParseAt { line, message }) => {
+                assert_eq!(line, 3);
+                assert!(message.contains("abc"));
+            }
+            other => panic!("Se esperaba ParseAt, obtenido {:?}", other),
+        }
+    }
+    
+    #[test]
+    fn test_config_parse_counts_comments_and_blank_lines() {
+        let contents = "# configuración\n\nhost=localhost\n   \n# puerto\nport=0";
+        
+        let err = Config::parse(contents).unwrap_err();
+        assert!(matches!(err, ConfigError::ParseAt { line: 6, .. }));
+        
+        // Línea sin '=' y clave desconocida también llevan su línea
+        assert!(matches!(Config::parse("\nport"), Err(ConfigError::ParseAt { line: 2, .. })));
+        assert!(matches!(Config::parse("#\n#\ncolor=rojo"), Err(ConfigError::ParseAt { line: 3, .. })));
+    }
+    
+    #[test]
+    fn test_config_parse_valid() {
+        let contents = "# servidor\nport = 9090\nhost=example.com\n\ntimeout=60\ndebug_level=warn\n";
+        let config = Config::parse(contents).unwrap();
+        
+        assert_eq!(config.port, 9090);
+        assert_eq!(config.host, "example.com");
+        assert_eq!(config.timeout, 60);
+        assert_eq!(config.get_debug_level(), "warn");
+    }
+    
+    #[test]
+    fn test_config_from_file() {
+        let temp_file = "test_config_parse_at.txt";
+        let mut file = File::create(temp_file).unwrap();
+        file.write_all(b"port=8080\n\ntimeout=treinta").unwrap();
+        drop(file);
+        
+        let result = Config::from_file(temp_file);
+        std::fs::remove_file(temp_file).unwrap();
+        assert!(matches!(result, Err(ConfigError::ParseAt { line: 3, .. })));
+        
+        let missing = Config::from_file("archivo_inexistente.txt");
+        assert!(matches!(missing, Err(ConfigError::FileNotFound(_))));
+    }
+    
+    #[test]
+    fn test_reloadable_config_picks_up_new_port() {
+        let path = env::temp_dir().join("test_reloadable_config.txt");
+        let start = SystemTime::now();
+        let write = |contents: &str, modified: SystemTime| {
+            let mut file = File::create(&path).unwrap();
+            file.write_all(contents.as_bytes()).unwrap();
+            file.set_modified(modified).unwrap();
+        };
+        
+        write("port=8080\n", start);
+        let mut reloadable = ReloadableConfig::new(&path).unwrap();
+        assert_eq!(reloadable.config().port, 8080);
+        assert_eq!(reloadable.reload_if_changed(), Ok(false));
+        
+        // El mtime se adelanta a mano: algunos sistemas de archivos solo guardan segundos
+        write("port=9090\n", start + Duration::from_secs(1));
+        assert_eq!(reloadable.reload_if_changed(), Ok(true));
+        assert_eq!(reloadable.config().port, 9090);
+        assert_eq!(reloadable.reload_if_changed(), Ok(false));
+        
+        // Un archivo inválido no sustituye la configuración y se reintenta en la siguiente llamada
+        write("port=abc\n", start + Duration::from_secs(2));
+        assert!(matches!(reloadable.reload_if_changed(), Err(ConfigError::ParseAt { line: 1, .. })));
+        assert_eq!(reloadable.config().port, 9090);
+        assert!(reloadable.reload_if_changed().is_err());
+        
+        fs::remove_file(&path).unwrap();
+        assert!(matches!(reloadable.reload_if_changed(), Err(ConfigError::FileNotFound(_))));
+    }
+    
+    #[test]
+    fn test_config_watcher_calls_back_with_new_config() {
+        let path = env::temp_dir().join(format!("test_config_watcher_{}.txt", std::process::id()));
+        let start = SystemTime::now();
+        let replace = |contents: &str, modified: SystemTime| write_config(&path, contents, modified).unwrap();
+        replace("port=8080\n", start);
+        
+        let poll_interval = Duration::from_millis(100);
+        let (tx, rx) = mpsc::channel();
+        let watcher = ConfigWatcher::new(path.clone(), poll_interval, move |reload| {
+            let _ = tx.send(reload);
+        });
+        assert!(rx.recv_timeout(poll_interval * 2).is_err(), "el archivo inicial no dispara el callback");
+        
+        replace("port=9090\nhost=example.com\n", start + Duration::from_secs(1));
+        let config = rx
+            .recv_timeout(poll_interval * 2)
+            .expect("el callback debería llegar en 2 intervalos")
+            .expect("la configuración nueva es válida");
+        assert_eq!(config.port, 9090);
+        assert_eq!(config.host, "example.com");
+        
+        // Un archivo roto llega al callback como error, una sola vez
+        replace("port=abc\n", start + Duration::from_secs(2));
+        let rejected = rx.recv_timeout(poll_interval * 2).expect("el rechazo también llega al callback");
+        assert!(matches!(rejected, Err(ConfigError::ParseAt { line: 1, .. })), "{:?}", rejected);
+        assert!(rx.recv_timeout(poll_interval * 2).is_err());
+        
+        // Después de stop ya no se vigila el archivo
+        watcher.stop();
+        replace("port=7070\n", start + Duration::from_secs(3));
+        assert!(rx.recv_timeout(poll_interval * 2).is_err());
+        fs::remove_file(&path).unwrap();
+    }
+    
+    #[test]
+    fn test_reloadable_config_demo_fails_on_missing_file() {
+        let missing = env::temp_dir().join("rust_lab_no_existe").join("config.txt");
+        let result = run_reloadable_config_demo(&missing);
+        assert!(matches!(result, Err(DemoError::Config(ConfigError::FileNotFound(_)))));
+        
+        // Un directorio que no existe tampoco se puede escribir
+        let io_error = DemoError::from(write_config(&missing, "port=8080\n", SystemTime::now()).unwrap_err());
+        assert!(io_error.to_string().starts_with("Error de E/S"));
+        
+        let path = env::temp_dir().join("test_reloadable_config_demo.txt");
+        write_config(&path, "port=8080\n", SystemTime::now()).unwrap();
+        let result = run_reloadable_config_demo(&path);
+        fs::remove_file(&path).unwrap();
+        assert!(result.is_ok());
+    }
+    
+    #[test]
+    fn test_validate_port_range() {
+        let user_port = Config::new(8080, "localhost".to_string(), 30);
+        assert_eq!(user_port.validate_port_range(1024, 49151), Ok(()));
+        // Los extremos están incluidos
+        assert_eq!(user_port.validate_port_range(8080, 8080), Ok(()));
+        
+        let privileged = Config::new(80, "localhost".to_string(), 30);
+        assert_eq!(
+            privileged.validate_port_range(1024, 49151),
+            Err(ConfigError::PortOutOfRange { port: 80, min: 1024, max: 49151 })
+        );
+    }
+    
+    #[test]
+    #[should_panic(expected = "rango de puertos vacío")]
+    fn test_validate_port_range_min_greater_than_max_panics() {
+        let config = Config::new(8080, "localhost".to_string(), 30);
+        let _ = config.validate_port_range(49151, 1024);
+    }
+    
+    #[test]
+    fn test_config_from_env_complete() {
+        let env = EnvGuard::new();
+        env.set("APP_PORT", "9090");
+        env.set("APP_HOST", "example.com");
+        env.set("APP_TIMEOUT", "60");
+        env.set("APP_DEBUG_LEVEL", "warn");
+        
+        let config = Config::from_env("APP").unwrap();
+        assert_eq!(config.port, 9090);
+        assert_eq!(config.host, "example.com");
+        assert_eq!(config.timeout, 60);
+        assert_eq!(config.get_debug_level(), "warn");
+    }
+    
+    #[test]
+    fn test_config_from_env_missing_port() {
+        let env = EnvGuard::new();
+        env.set("APP_HOST", "example.com");
+        env.set("APP_TIMEOUT", "60");
+        env.set("APP_DEBUG_LEVEL", "warn");
+        
+        assert_eq!(Config::from_env("APP"), Err(ConfigError::MissingField("APP_PORT".to_string())));
+    }
+    
+    #[test]
+    fn test_config_from_env_invalid_values() {
+        let env = EnvGuard::new();
+        env.set("APP_PORT", "abc");
+        env.set("APP_HOST", "example.com");
+        env.set("APP_TIMEOUT", "60");
+        env.set("APP_DEBUG_LEVEL", "warn");
+        assert!(matches!(Config::from_env("APP"), Err(ConfigError::ParseError(_))));
+        
+        env.set("APP_PORT", "8080");
+        env.set("APP_TIMEOUT", "-5");
+        assert!(matches!(Config::from_env("APP"), Err(ConfigError::ParseError(_))));
+        
+        env.set("APP_TIMEOUT", "5");
+        env.set("APP_DEBUG_LEVEL", "verbose");
+        assert!(matches!(Config::from_env("APP"), Err(ConfigError::ParseError(_))));
+    }
+    
+    #[test]
+    fn test_config_from_env_optional_fields() {
+        let env = EnvGuard::new();
+        env.set("MYAPP_PORT", "3000");
+        env.set("MYAPP_HOST", "api.local");
+        // Variables con otro prefijo no influyen
+        env.set("APP_TIMEOUT", "99");
+        
+        let config = Config::from_env("MYAPP").unwrap();
+        assert_eq!(config.port, 3000);
+        assert_eq!(config.host, "api.local");
+        assert_eq!(config.timeout, 30);
+        assert_eq!(config.get_debug_level(), "info");
+        
+        assert_eq!(
+            Config::from_env("OTHER"),
+            Err(ConfigError::MissingField("OTHER_PORT".to_string()))
+        );
+    }
+    
+    #[test]
+    fn test_cli_overrides_port() {
+        let args = |args: &[&str]| args.iter().map(|arg| arg.to_string()).collect::<Vec<String>>();
+        let mut config = Config::default();
+        config.apply_cli_overrides(&args(&["--port", "9090"])).unwrap();
+        assert_eq!(config.port, 9090);
+        assert_eq!(config.host, "localhost");
+        
+        config
+            .apply_cli_overrides(&args(&["--host", "example.com", "--timeout", "5", "--debug-level", "warn", "--port", "3000"]))
+            .unwrap();
+        assert_eq!((config.port, config.host.as_str(), config.timeout), (3000, "example.com", 5));
+        assert_eq!(config.get_debug_level(), "warn");
+        
+        // Un valor inválido no deja aplicados los flags anteriores
+        let before = config.clone();
+        assert!(matches!(
+            config.apply_cli_overrides(&args(&["--port", "4000", "--timeout", "-1"])),
+            Err(ConfigError::ParseError(_))
+        ));
+        assert_eq!(config, before);
+    }
+    
+    #[test]
+    fn test_cli_overrides_unknown_flag() {
+        let mut config = Config::default();
+        let args = vec!["--port".to_string(), "9090".to_string(), "--verbose".to_string()];
+        assert_eq!(config.apply_cli_overrides(&args), Err(ConfigError::UnknownFlag("--verbose".to_string())));
+        assert_eq!(config, Config::default());
+        
+        let args = vec!["9090".to_string()];
+        assert_eq!(config.apply_cli_overrides(&args), Err(ConfigError::UnknownFlag("9090".to_string())));
+    }
+    
+    #[test]
+    fn test_cli_overrides_port_without_value() {
+        let mut config = Config::default();
+        let args = vec!["--port".to_string()];
+        assert_eq!(config.apply_cli_overrides(&args), Err(ConfigError::MissingFlagValue("--port".to_string())));
+        
+        let args = vec!["--port".to_string(), "--host".to_string(), "example.com".to_string()];
+        assert_eq!(config.apply_cli_overrides(&args), Err(ConfigError::MissingFlagValue("--port".to_string())));
+        assert_eq!(config, Config::default());
+    }
+    
+    #[test]
+    fn test_parse_number_or_word() {
+        assert_eq!(parse_number_or_word("42"), Either::Left(42));
+        assert_eq!(parse_number_or_word(" -7 "), Either::Left(-7));
+        assert_eq!(parse_number_or_word("hello"), Either::Right("hello".to_string()));
+        // Fuera de rango de i64 o con decimales ya no es un entero
+        assert_eq!(parse_number_or_word("3.5"), Either::Right("3.5".to_string()));
+        assert!(parse_number_or_word("99999999999999999999").is_right());
+        assert!(parse_number_or_word("").is_right());
+    }
+    
+    #[test]
+    fn test_either_map_and_into_result() {
+        assert_eq!(parse_number_or_word("42").map_left(|n| n * 2), Either::Left(84));
+        assert_eq!(parse_number_or_word("hello").map_left(|n| n * 2), Either::Right("hello".to_string()));
+        assert_eq!(parse_number_or_word("hello").map_right(|w| w.len()), Either::Right(5));
+        assert_eq!(parse_number_or_word("42").map_right(|w| w.len()), Either::Left(42));
+        
+        assert!(parse_number_or_word("42").is_left());
+        assert_eq!(parse_number_or_word("42").into_result(), Ok(42));
+        assert_eq!(parse_number_or_word("hello").into_result(), Err("hello".to_string()));
+    }
+    
+    #[test]
+    fn test_config_entry_parses_each_line_type() {
+        assert_eq!(ConfigEntry::try_from("port=9090"), Ok(ConfigEntry::Port(9090)));
+        assert_eq!(ConfigEntry::try_from(" host = example.com "), Ok(ConfigEntry::Host("example.com".to_string())));
+        assert_eq!(ConfigEntry::try_from("timeout=60"), Ok(ConfigEntry::Timeout(60)));
+        assert_eq!(ConfigEntry::try_from("debug_level=warn"), Ok(ConfigEntry::DebugLevel("warn".to_string())));
+        
+        assert!(matches!(ConfigEntry::try_from("port=0"), Err(ConfigError::ParseError(_))));
+        assert!(matches!(ConfigEntry::try_from("host="), Err(ConfigError::ParseError(_))));
+        assert!(matches!(ConfigEntry::try_from("timeout=-1"), Err(ConfigError::ParseError(_))));
+        assert!(matches!(ConfigEntry::try_from("debug_level=loud"), Err(ConfigError::ParseError(_))));
+        assert!(matches!(ConfigEntry::try_from("port 9090"), Err(ConfigError::ParseError(_))));
+    }
+    
+    #[test]
+    fn test_config_entry_unknown_key_errors_and_parse_adds_line() {
+        assert_eq!(
+            ConfigEntry::try_from("color=rojo"),
+            Err(ConfigError::ParseError("clave desconocida 'color'".to_string()))
+        );
+        assert_eq!(
+            Config::parse("port=9090\n\ncolor=rojo"),
+            Err(ConfigError::ParseAt { line: 3, message: "clave desconocida 'color'".to_string() })
+        );
+        
+        let config = ["port=9090", "timeout=5", "port=7070"]
+            .into_iter()
+            .map(ConfigEntry::try_from)
+            .try_fold(Config::default(), |config, entry| entry.map(|entry| config.with_entry(entry)))
+            .unwrap();
+        assert_eq!(config, Config { port: 7070, timeout: 5, ..Config::default() });
+    }
+    
+    #[test]
+    fn test_lazy_config_global_initialized_once() {
+        let barrier = Arc::new(Barrier::new(8));
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let barrier = Arc::clone(&barrier);
+                thread::spawn(move || {
+                    // Todos los threads compiten por el primer acceso
+                    barrier.wait();
+                    global() as *const Config as usize
+                })
+            })
+            .collect();
+        
+        let addresses: Vec<usize> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+        assert!(addresses.iter().all(|&address| address == addresses[0]));
+        assert_eq!(addresses[0], global() as *const Config as usize);
+        assert_eq!(GLOBAL_INIT_CALLS.load(Ordering::SeqCst), 1);
+        assert_eq!(*global(), Config::default());
+    }
+    
+    #[test]
+    fn test_retry_macro_matches_function() {
+        // Falla las primeras `failures` llamadas
+        fn fallible_fn(calls: &Cell<u32>, failures: u32) -> Result<u32, String> {
+            calls.set(calls.get() + 1);
+            if calls.get() <= failures {
+                Err(format!("fallo {}", calls.get()))
+            } else {
+                Ok(calls.get())
+            }
+        }
+        
+        for failures in 0..5 {
+            let fn_calls = Cell::new(0);
+            let from_fn = retry(3, Duration::from_millis(1), || fallible_fn(&fn_calls, failures));
+            
+            let macro_calls = Cell::new(0);
+            let from_macro = retry!(3, Duration::from_millis(1), { fallible_fn(&macro_calls, failures) });
+            
+            assert_eq!(from_fn, from_macro, "resultados distintos con {} fallos", failures);
+            assert_eq!(fn_calls.get(), macro_calls.get());
+        }
+        
+        // Tres fallos con tres intentos: se devuelve el último error
+        let calls = Cell::new(0);
+        assert_eq!(
+            retry!(3, Duration::from_millis(1), { fallible_fn(&calls, 3) }),
+            Err("fallo 3".to_string())
+        );
+        
+        // Cero intentos se trata como uno, igual que la función
+        let calls = Cell::new(0);
+        assert!(retry!(0, Duration::from_millis(1), { fallible_fn(&calls, 1) }).is_err());
+        assert_eq!(calls.get(), 1);
+    }
+    
+    #[test]
+    fn test_retry_macro_question_mark_exits_caller() {
+        fn load(limit: &str, calls: &Cell<u32>) -> Result<u32, Box<dyn std::error::Error>> {
+            let value = retry!(3, Duration::from_millis(1), {
+                let limit: u32 = limit.parse()?;
+                calls.set(calls.get() + 1);
+                if calls.get() < limit { Err("transitorio") } else { Ok(calls.get()) }
+            })?;
+            Ok(value)
+        }
+        
+        let calls = Cell::new(0);
+        assert_eq!(load("2", &calls).unwrap(), 2);
+        
+        // El error de `?` no se reintenta: sale en el primer intento
+        let calls = Cell::new(0);
+        assert!(load("dos", &calls).is_err());
+        assert_eq!(calls.get(), 0);
+    }
+    
+    #[test]
+    fn test_retry_smart_stops_on_fatal_error() {
+        #[derive(Debug, PartialEq)]
+        enum FetchError {
+            Timeout,
+            NotFound,
+        }
+        
+        impl Retriable for FetchError {
+            fn is_retriable(&self) -> bool {
+                matches!(self, FetchError::Timeout)
+            }
+        }
+        
+        // NotFound no se reintenta: un solo intento
+        let calls = Cell::new(0);
+        let result: Result<(), FetchError> = retry_smart(5, Duration::from_millis(1), || {
+            calls.set(calls.get() + 1);
+            Err(FetchError::NotFound)
+        });
+        assert_eq!(result, Err(FetchError::NotFound));
+        assert_eq!(calls.get(), 1);
+        
+        // Timeout se reintenta hasta agotar los intentos...
+        let calls = Cell::new(0);
+        let result: Result<(), FetchError> = retry_smart(5, Duration::from_millis(1), || {
+            calls.set(calls.get() + 1);
+            Err(FetchError::Timeout)
+        });
+        assert_eq!(result, Err(FetchError::Timeout));
+        assert_eq!(calls.get(), 5);
+        
+        // ...o hasta que tiene éxito
+        let calls = Cell::new(0);
+        let result = retry_smart(5, Duration::from_millis(1), || {
+            calls.set(calls.get() + 1);
+            if calls.get() < 3 { Err(FetchError::Timeout) } else { Ok(calls.get()) }
+        });
+        assert_eq!(result, Ok(3));
+        
+        // Un Timeout seguido de NotFound para en el NotFound
+        let calls = Cell::new(0);
+        let result: Result<(), FetchError> = retry_smart(5, Duration::from_millis(1), || {
+            calls.set(calls.get() + 1);
+            Err(if calls.get() == 1 { FetchError::Timeout } else { FetchError::NotFound })
+        });
+        assert_eq!(result, Err(FetchError::NotFound));
+        assert_eq!(calls.get(), 2);
+    }
+    
+    #[test]
+    fn test_protocol_parse_stream_with_error_in_middle() {
+        let transcript = "SET key=a value=1\nGET key=a\nSET key=b\n\nDELETE key=a\nPING\n";
+        let results: Vec<_> = ProtocolParser::parse_stream(transcript.as_bytes()).collect();
+        
+        assert_eq!(
+            results,
+            vec![
+                Ok(Command::Set { key: "a".to_string(), value: "1".to_string() }),
+                Ok(Command::Get { key: "a".to_string() }),
+                Err(ParseError::MissingField { command: "SET".to_string(), field: "value" }),
+                Ok(Command::Delete { key: "a".to_string() }),
+                Ok(Command::Unknown("PING".to_string())),
+            ]
+        );
+    }
+    
+    #[test]
+    fn test_protocol_parse_line_errors() {
+        assert_eq!(ProtocolParser::parse_line("   \n"), Err(ParseError::EmptyLine));
+        assert_eq!(
+            ProtocolParser::parse_line("GET key=a extra=1"),
+            Err(ParseError::UnexpectedField { command: "GET".to_string(), field: "extra".to_string() })
+        );
+        assert_eq!(
+            ProtocolParser::parse_line("DELETE key=a key=b"),
+            Err(ParseError::DuplicateField { command: "DELETE".to_string(), field: "key".to_string() })
+        );
+        assert_eq!(
+            ProtocolParser::parse_line("SET key value=1"),
+            Err(ParseError::MalformedField("key".to_string()))
+        );
+        
+        // El orden de los campos no importa y el salto de línea es opcional
+        assert_eq!(
+            ProtocolParser::parse_line("SET value=1 key=a\n"),
+            Ok(Command::Set { key: "a".to_string(), value: "1".to_string() })
+        );
+    }
+    
+    #[test]
+    fn test_http_parse_get_request() {
+        let raw = b"GET /users?id=7 HTTP/1.1\r\nHost: example.com\r\nAccept:  */*  \r\n\r\n";
+        let request = parse_request(raw).expect("petición GET válida");
+        
+        assert_eq!(request.method, Method::Get);
+        assert_eq!(request.path, "/users?id=7");
+        assert_eq!(request.version, (1, 1));
+        assert_eq!(request.headers.len(), 2);
+        assert_eq!(request.headers.get("host").map(String::as_str), Some("example.com"));
+        assert_eq!(request.headers.get("accept").map(String::as_str), Some("*/*"));
+        assert!(request.body.is_empty());
+    }
+    
+    #[test]
+    fn test_http_parse_post_request_with_body() {
+        let raw = b"POST /users HTTP/1.0\r\nContent-Type: text/plain\r\nContent-Length: 5\r\n\r\nhello, sobra";
+        let request = parse_request(raw).expect("petición POST válida");
+        
+        assert_eq!(request.method, Method::Post);
+        assert_eq!(request.path, "/users");
+        assert_eq!(request.version, (1, 0));
+        assert_eq!(request.headers.get("content-type").map(String::as_str), Some("text/plain"));
+        // Solo se leen los bytes indicados por Content-Length
+        assert_eq!(request.body, b"hello");
+        
+        let other = parse_request(b"PATCH / HTTP/1.1\r\n\r\n").unwrap();
+        assert_eq!(other.method, Method::Other("PATCH".to_string()));
+    }
+    
+    #[test]
+    fn test_http_parse_errors() {
+        for raw in [
+            &b"GET /users\r\n\r\n"[..],
+            b"GET  /users HTTP/1.1\r\n\r\n",
+            b"get / HTTP/1.1\r\n\r\n",
+            b"GET users HTTP/1.1\r\n\r\n",
+            b"GET / HTTP/11\r\n\r\n",
+        ] {
+            assert!(
+                matches!(parse_request(raw), Err(HttpParseError::MalformedRequestLine(_))),
+                "{:?}",
+                String::from_utf8_lossy(raw)
+            );
+        }
+        
+        assert_eq!(
+            parse_request(b"GET / HTTP/1.1\r\nHost example.com\r\n\r\n"),
+            Err(HttpParseError::InvalidHeader("Host example.com".to_string()))
+        );
+        assert!(matches!(
+            parse_request(b"GET / HTTP/1.1\r\nHost : a\r\n\r\n"),
+            Err(HttpParseError::InvalidHeader(_))
+        ));
+        assert!(matches!(
+            parse_request(b"POST / HTTP/1.1\r\nContent-Length: -1\r\n\r\n"),
+            Err(HttpParseError::InvalidHeader(_))
+        ));
+        assert_eq!(
+            parse_request(b"POST / HTTP/1.1\r\nContent-Length: 2000000\r\n\r\n"),
+            Err(HttpParseError::BodyTooLarge { size: 2_000_000, max: MAX_BODY_SIZE })
+        );
+        assert_eq!(parse_request(b"GET / HTTP/1.1\r\nHost: a\r\n"), Err(HttpParseError::Incomplete));
+        assert_eq!(
+            parse_request(b"POST / HTTP/1.1\r\nContent-Length: 10\r\n\r\nabc"),
+            Err(HttpParseError::Incomplete)
+        );
+    }
+    
+    #[test]
+    fn test_config_from_env_or_default() {
+        let env = EnvGuard::new();
+        
+        // Sin variables: todo por defecto
+        assert_eq!(Config::from_env_or_default(), Config::default());
+        
+        // Cada variable cae a su valor por defecto de forma independiente
+        env.set("APP_HOST", "example.com");
+        env.set("APP_TIMEOUT", "no_es_numero");
+        let config = Config::from_env_or_default();
+        assert_eq!(config.port, 8080);
+        assert_eq!(config.host, "example.com");
+        assert_eq!(config.timeout, 30);
+        assert_eq!(config.get_debug_level(), "info");
+    }
+    
     #[test]
     fn test_option_handling() {
         let mut numbers = vec![1, 2, 3, 4, 5];
@@ -242,10 +1601,26 @@ mod error_handling_tests {
         
         // Test or_else
         let result: Result<i32, &str> = Err("error");
-        let recovered = result.or_else(|_| Ok(0));
+        let recovered: Result<i32, &str> = result.or_else(|_| Ok(0));
         assert_eq!(recovered, Ok(0));
     }
     
+    #[test]
+    fn test_collect_results_keeps_both_sides_in_order() {
+        let inputs = vec![Ok(1), Err("a"), Ok(2), Err("b"), Err("c"), Ok(3)];
+        
+        let (oks, errs) = collect_results(inputs.into_iter());
+        assert_eq!(oks, vec![1, 2, 3]);
+        assert_eq!(errs, vec!["a", "b", "c"]);
+        
+        // collect::<Result<..>>() se detiene en el primer error
+        let stopped: Result<Vec<i32>, &str> = vec![Ok(1), Err("a"), Ok(2)].into_iter().collect();
+        assert_eq!(stopped, Err("a"));
+        
+        let (oks, errs) = collect_results(std::iter::empty::<Result<i32, String>>());
+        assert!(oks.is_empty() && errs.is_empty());
+    }
+    
     #[test]
     fn test_option_combinators() {
         // Test map
@@ -273,6 +1648,16 @@ mod error_handling_tests {
         let value = option.unwrap_or_else(|| 42);
         assert_eq!(value, 42);
     }
+    
+    fn collect_results<T, E>(iter: impl Iterator<Item = Result<T, E>>) -> (Vec<T>, Vec<E>) {
+        let mut oks = Vec::new();
+        let mut errs = Vec::new();
+        for result in iter {
+            match result {
+                Ok(value) => oks.push(value),
+                Err(e) => errs.push(e),
+            }
+        }
+        (oks, errs)
+    }
 }
-
-
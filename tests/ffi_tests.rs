@@ -0,0 +1,244 @@
+//! Tests para los ejercicios de FFI
+
+#[cfg(test)]
+mod ffi_tests {
+    #![deny(unsafe_op_in_unsafe_fn, clippy::undocumented_unsafe_blocks)]
+    
+    use std::cell::Cell;
+    use std::ffi::{c_int, c_void};
+    use std::mem::size_of;
+    use std::panic::{self, AssertUnwindSafe};
+    use std::ptr;
+    use std::rc::Rc;
+    
+    // Importar las estructuras del ejercicio
+    #[allow(non_camel_case_types)]
+    type size_t = usize;
+    
+    type Comparator = extern "C" fn(*const c_void, *const c_void) -> c_int;
+    
+    // SAFETY: la firma coincide con la de `qsort` en <stdlib.h>.
+    unsafe extern "C" {
+        fn qsort(base: *mut c_void, nmemb: size_t, size: size_t, compar: Option<Comparator>);
+    }
+    
+    #[derive(Debug, Clone, PartialEq)]
+    struct User {
+        id: u32,
+        name: String,
+    }
+    
+    impl User {
+        fn new(id: u32, name: &str) -> Self {
+            Self {
+                id,
+                name: name.to_string(),
+            }
+        }
+    }
+    
+    unsafe fn qsort_slice<T>(data: &mut [T], compar: Comparator) {
+        if data.len() < 2 {
+            return;
+        }
+        // SAFETY: `data` es un slice válido y exclusivo; quien llama garantiza
+        // que `compar` es válido para `T`.
+        unsafe {
+            qsort(data.as_mut_ptr().cast(), data.len(), size_of::<T>(), Some(compar));
+        }
+    }
+    
+    extern "C" fn compare_i32(a: *const c_void, b: *const c_void) -> c_int {
+        // SAFETY: solo se usa con slices de `i32`.
+        let (a, b) = unsafe { (*a.cast::<i32>(), *b.cast::<i32>()) };
+        a.cmp(&b) as c_int
+    }
+    
+    extern "C" fn compare_users_by_id(a: *const c_void, b: *const c_void) -> c_int {
+        // SAFETY: solo se usa con slices de `User`.
+        let (a, b) = unsafe { (&*a.cast::<User>(), &*b.cast::<User>()) };
+        a.id.cmp(&b.id) as c_int
+    }
+    
+    fn sort_ints_via_ffi(data: &mut [i32]) {
+        // SAFETY: `compare_i32` trata los elementos como `i32` y no hace panic.
+        unsafe { qsort_slice(data, compare_i32) }
+    }
+    
+    fn sort_users_via_ffi(users: &mut [User]) {
+        // SAFETY: `compare_users_by_id` trata los elementos como `User` y no hace panic.
+        unsafe { qsort_slice(users, compare_users_by_id) }
+    }
+    
+    type Callback = extern "C" fn(i32) -> i32;
+    
+    type BoxedClosure = Box<dyn FnMut(i32) -> i32>;
+    
+    extern "C" fn identity(arg: i32) -> i32 {
+        arg
+    }
+    
+    thread_local! {
+        static REGISTERED: Cell<Callback> = const { Cell::new(identity) };
+        static ACTIVE_CLOSURE: Cell<*mut BoxedClosure> = const { Cell::new(ptr::null_mut()) };
+    }
+    
+    fn register_callback(cb: Callback) -> Callback {
+        REGISTERED.with(|registered| registered.replace(cb))
+    }
+    
+    fn current_callback() -> Callback {
+        REGISTERED.with(Cell::get)
+    }
+    
+    unsafe fn invoke_from_c_context(cb: Callback, arg: i32) -> i32 {
+        cb(arg)
+    }
+    
+    fn fire_event(arg: i32) -> i32 {
+        // SAFETY: solo se registran funciones de este archivo, que atrapan cualquier panic.
+        unsafe { invoke_from_c_context(current_callback(), arg) }
+    }
+    
+    extern "C" fn trampoline(arg: i32) -> i32 {
+        let raw = ACTIVE_CLOSURE.with(|active| active.replace(ptr::null_mut()));
+        if raw.is_null() {
+            return arg;
+        }
+        // SAFETY: `raw` viene de `Box::into_raw` en `CallbackGuard::new`, sigue
+        // vivo hasta `Drop` y se ha sacado del slot, así que es la única referencia.
+        let closure = unsafe { &mut *raw };
+        let result = panic::catch_unwind(AssertUnwindSafe(|| closure(arg))).unwrap_or(arg);
+        ACTIVE_CLOSURE.with(|active| active.set(raw));
+        result
+    }
+    
+    struct CallbackGuard {
+        closure: *mut BoxedClosure,
+        previous_closure: *mut BoxedClosure,
+        previous_callback: Callback,
+    }
+    
+    impl CallbackGuard {
+        fn new(closure: impl FnMut(i32) -> i32 + 'static) -> Self {
+            let boxed: BoxedClosure = Box::new(closure);
+            let closure = Box::into_raw(Box::new(boxed));
+            let previous_closure = ACTIVE_CLOSURE.with(|active| active.replace(closure));
+            let previous_callback = register_callback(trampoline);
+            Self {
+                closure,
+                previous_closure,
+                previous_callback,
+            }
+        }
+    }
+    
+    impl Drop for CallbackGuard {
+        fn drop(&mut self) {
+            register_callback(self.previous_callback);
+            ACTIVE_CLOSURE.with(|active| active.set(self.previous_closure));
+            // SAFETY: `closure` viene de `Box::into_raw` en `new` y ya no está en el slot.
+            drop(unsafe { Box::from_raw(self.closure) });
+        }
+    }
+    
+    #[test]
+    fn test_sort_ints_via_ffi_matches_std_sort() {
+        let mut data = vec![42, -7, 0, i32::MAX, 13, i32::MIN, 5, 13];
+        let mut expected = data.clone();
+        expected.sort();
+        
+        sort_ints_via_ffi(&mut data);
+        assert_eq!(data, expected);
+    }
+    
+    #[test]
+    fn test_sort_ints_via_ffi_empty_and_single() {
+        let mut empty: Vec<i32> = Vec::new();
+        sort_ints_via_ffi(&mut empty);
+        assert!(empty.is_empty());
+        
+        let mut single = vec![7];
+        sort_ints_via_ffi(&mut single);
+        assert_eq!(single, vec![7]);
+    }
+    
+    #[test]
+    fn test_sort_users_via_ffi_by_id() {
+        let mut users = vec![User::new(3, "Eva"), User::new(1, "Ana"), User::new(2, "Luis")];
+        sort_users_via_ffi(&mut users);
+        
+        let ids: Vec<u32> = users.iter().map(|u| u.id).collect();
+        assert_eq!(ids, vec![1, 2, 3]);
+        // Los String se mueven byte a byte junto con su User, sin corromperse
+        assert_eq!(users[0], User::new(1, "Ana"));
+        assert_eq!(users[2].name, "Eva");
+    }
+    
+    #[test]
+    fn test_register_callback_returns_previous() {
+        extern "C" fn negate(arg: i32) -> i32 {
+            -arg
+        }
+        
+        assert_eq!(fire_event(4), 4);
+        let previous = register_callback(negate);
+        assert_eq!(fire_event(4), -4);
+        
+        let replaced = register_callback(previous);
+        // SAFETY: `replaced` es `negate`, una función válida que no hace panic.
+        assert_eq!(unsafe { invoke_from_c_context(replaced, 4) }, -4);
+        assert_eq!(fire_event(4), 4);
+    }
+    
+    #[test]
+    fn test_callback_guard_closure_mutates_captured_state() {
+        let total = Rc::new(Cell::new(0));
+        {
+            let total = Rc::clone(&total);
+            let _guard = CallbackGuard::new(move |arg| {
+                total.set(total.get() + arg);
+                total.get()
+            });
+            
+            assert_eq!(fire_event(5), 5);
+            assert_eq!(fire_event(7), 12);
+        }
+        assert_eq!(total.get(), 12);
+        // El closure se liberó junto con su copia del Rc
+        assert_eq!(Rc::strong_count(&total), 1);
+        assert_eq!(fire_event(5), 5);
+    }
+    
+    #[test]
+    fn test_callback_guard_drops_on_panic() {
+        let captured = Rc::new(());
+        let in_closure = Rc::clone(&captured);
+        
+        let result = panic::catch_unwind(AssertUnwindSafe(move || {
+            let _guard = CallbackGuard::new(move |arg| {
+                let _keep = &in_closure;
+                arg * 2
+            });
+            assert_eq!(fire_event(21), 42);
+            panic!("panic con la guarda viva");
+        }));
+        
+        assert!(result.is_err());
+        assert_eq!(Rc::strong_count(&captured), 1);
+        assert_eq!(fire_event(21), 21);
+    }
+    
+    #[test]
+    fn test_callback_panic_does_not_cross_extern_c() {
+        let _guard = CallbackGuard::new(|arg| {
+            if arg < 0 {
+                panic!("argumento negativo");
+            }
+            arg + 1
+        });
+        
+        assert_eq!(fire_event(-3), -3);
+        assert_eq!(fire_event(1), 2);
+    }
+}
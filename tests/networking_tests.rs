@@ -0,0 +1,186 @@
+//! Tests para los ejercicios de networking
+
+#[cfg(test)]
+mod networking_tests {
+    use std::io::{self, BufRead, BufReader, ErrorKind, Write};
+    use std::net::{Ipv4Addr, TcpListener, TcpStream, UdpSocket};
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+    use std::thread;
+    use std::time::{Duration, Instant};
+    
+    // Importar las funciones del ejercicio
+    const POLL_INTERVAL: Duration = Duration::from_millis(10);
+    
+    fn serve_echo(listener: TcpListener, stop: Arc<AtomicBool>) -> io::Result<()> {
+        listener.set_nonblocking(true)?;
+        let mut connections = Vec::new();
+        
+        while !stop.load(Ordering::Acquire) {
+            match listener.accept() {
+                Ok((stream, _peer)) => {
+                    let stop = Arc::clone(&stop);
+                    connections.push(thread::spawn(move || {
+                        let _ = handle_connection(stream, &stop);
+                    }));
+                }
+                Err(e) if e.kind() == ErrorKind::WouldBlock => thread::sleep(POLL_INTERVAL),
+                Err(e) => return Err(e),
+            }
+            connections.retain(|handle| !handle.is_finished());
+        }
+        
+        for handle in connections {
+            let _ = handle.join();
+        }
+        Ok(())
+    }
+    
+    fn handle_connection(stream: TcpStream, stop: &AtomicBool) -> io::Result<()> {
+        stream.set_nonblocking(false)?;
+        stream.set_read_timeout(Some(POLL_INTERVAL))?;
+        let mut writer = stream.try_clone()?;
+        let mut reader = BufReader::new(stream);
+        let mut line = Vec::new();
+        
+        loop {
+            match reader.read_until(b'\n', &mut line) {
+                Ok(0) => return Ok(()),
+                Ok(_) => {
+                    writer.write_all(&line)?;
+                    line.clear();
+                }
+                Err(e) if matches!(e.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) => {
+                    if stop.load(Ordering::Acquire) {
+                        return Ok(());
+                    }
+                }
+                Err(e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+    }
+    
+    fn test_echo_client(addr: &str, messages: &[&str]) -> io::Result<Vec<String>> {
+        let mut stream = TcpStream::connect(addr)?;
+        let mut reader = BufReader::new(stream.try_clone()?);
+        let mut replies = Vec::with_capacity(messages.len());
+        
+        for message in messages {
+            writeln!(stream, "{}", message)?;
+            let mut reply = String::new();
+            if reader.read_line(&mut reply)? == 0 {
+                return Err(io::Error::new(ErrorKind::UnexpectedEof, "el servidor cerró la conexión"));
+            }
+            replies.push(reply.trim_end_matches('\n').to_string());
+        }
+        Ok(replies)
+    }
+    
+    fn parse_group(group: &str) -> io::Result<Ipv4Addr> {
+        let addr: Ipv4Addr = group
+            .parse()
+            .map_err(|e| io::Error::new(ErrorKind::InvalidInput, format!("'{}': {}", group, e)))?;
+        if !addr.is_multicast() {
+            return Err(io::Error::new(ErrorKind::InvalidInput, format!("{} no es multicast", addr)));
+        }
+        Ok(addr)
+    }
+    
+    fn run_multicast_sender(group: &str, port: u16, message: &str) -> io::Result<()> {
+        let group = parse_group(group)?;
+        let socket = UdpSocket::bind("0.0.0.0:0")?;
+        socket.set_multicast_ttl_v4(1)?;
+        socket.set_multicast_loop_v4(true)?;
+        socket.send_to(message.as_bytes(), (group, port))?;
+        Ok(())
+    }
+    
+    fn join_multicast_group(group: &str, port: u16) -> io::Result<UdpSocket> {
+        let group = parse_group(group)?;
+        let socket = UdpSocket::bind((Ipv4Addr::UNSPECIFIED, port))?;
+        socket.join_multicast_v4(&group, &Ipv4Addr::UNSPECIFIED)?;
+        socket.set_read_timeout(Some(Duration::from_secs(2)))?;
+        Ok(socket)
+    }
+    
+    fn receive_message(socket: &UdpSocket) -> io::Result<String> {
+        let mut buf = [0u8; 1500];
+        let (len, _from) = socket.recv_from(&mut buf)?;
+        String::from_utf8(buf[..len].to_vec()).map_err(|e| io::Error::new(ErrorKind::InvalidData, e))
+    }
+    
+    /// Arranca el servidor en un puerto libre y devuelve su dirección, el flag y el thread
+    fn spawn_server() -> (String, Arc<AtomicBool>, thread::JoinHandle<io::Result<()>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("bind a un puerto libre");
+        let addr = listener.local_addr().unwrap().to_string();
+        let stop = Arc::new(AtomicBool::new(false));
+        let server = {
+            let stop = Arc::clone(&stop);
+            thread::spawn(move || serve_echo(listener, stop))
+        };
+        (addr, stop, server)
+    }
+    
+    #[test]
+    fn test_echo_server_returns_every_message() {
+        let (addr, stop, server) = spawn_server();
+        
+        let messages: Vec<String> = (0..10).map(|i| format!("mensaje {} ✓", i)).collect();
+        let messages: Vec<&str> = messages.iter().map(String::as_str).collect();
+        let replies = test_echo_client(&addr, &messages).expect("el cliente recibe todas las respuestas");
+        assert_eq!(replies, messages);
+        
+        stop.store(true, Ordering::Release);
+        server.join().unwrap().expect("el servidor termina sin error");
+    }
+    
+    #[test]
+    fn test_echo_server_stops_with_idle_connection_open() {
+        let (addr, stop, server) = spawn_server();
+        
+        // Un cliente conectado que no envía nada no debe bloquear la parada
+        let _idle = TcpStream::connect(&addr).unwrap();
+        let replies = test_echo_client(&addr, &["a", "b"]).unwrap();
+        assert_eq!(replies, ["a", "b"]);
+        
+        let start = Instant::now();
+        stop.store(true, Ordering::Release);
+        server.join().unwrap().unwrap();
+        assert!(start.elapsed() < Duration::from_secs(2), "la parada tardó {:?}", start.elapsed());
+    }
+    
+    #[test]
+    fn test_multicast_receiver_gets_sent_message() {
+        const GROUP: &str = "224.0.0.1";
+        // Sin multicast (algunos contenedores y CI) no hay nada que comprobar
+        let socket = match join_multicast_group(GROUP, 0) {
+            Ok(socket) => socket,
+            Err(e) => {
+                eprintln!("multicast no disponible, se omite el test: {}", e);
+                return;
+            }
+        };
+        let port = socket.local_addr().unwrap().port();
+        
+        if let Err(e) = run_multicast_sender(GROUP, port, "hola, grupo") {
+            eprintln!("sin ruta multicast, se omite el test: {}", e);
+            return;
+        }
+        
+        match receive_message(&socket) {
+            Ok(message) => assert_eq!(message, "hola, grupo"),
+            Err(e) if matches!(e.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) => {
+                eprintln!("la red descarta el multicast, se omite el test: {}", e);
+            }
+            Err(e) => panic!("error inesperado al recibir: {}", e),
+        }
+    }
+    
+    #[test]
+    fn test_multicast_rejects_non_group_address() {
+        let err = join_multicast_group("127.0.0.1", 0).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+        assert_eq!(run_multicast_sender("no-es-ip", 9, "x").unwrap_err().kind(), ErrorKind::InvalidInput);
+    }
+}
@@ -2,9 +2,17 @@
 
 #[cfg(test)]
 mod ownership_tests {
-    use std::collections::HashMap;
+    use std::collections::{HashMap, HashSet};
     
     // Importar las estructuras del ejercicio (necesitarías moverlas a un módulo compartido)
+    #[derive(Debug, Clone, PartialEq)]
+    enum UserFieldChange {
+        Name { old: String, new: String },
+        Email { old: String, new: String },
+        PostsAdded(Vec<u32>),
+        PostsRemoved(Vec<u32>),
+    }
+    
     #[derive(Debug, Clone, PartialEq)]
     struct User {
         id: u32,
@@ -34,6 +42,39 @@ mod ownership_tests {
         fn get_name_owned(&self) -> String {
             self.name.clone()
         }
+        
+        fn diff(&self, other: &User) -> Vec<UserFieldChange> {
+            let mut changes = Vec::new();
+            
+            if self.name != other.name {
+                changes.push(UserFieldChange::Name {
+                    old: self.name.clone(),
+                    new: other.name.clone(),
+                });
+            }
+            
+            if self.email != other.email {
+                changes.push(UserFieldChange::Email {
+                    old: self.email.clone(),
+                    new: other.email.clone(),
+                });
+            }
+            
+            let old_posts: HashSet<&u32> = self.posts.iter().collect();
+            let new_posts: HashSet<&u32> = other.posts.iter().collect();
+            
+            let added: Vec<u32> = other.posts.iter().filter(|id| !old_posts.contains(id)).copied().collect();
+            if !added.is_empty() {
+                changes.push(UserFieldChange::PostsAdded(added));
+            }
+            
+            let removed: Vec<u32> = self.posts.iter().filter(|id| !new_posts.contains(id)).copied().collect();
+            if !removed.is_empty() {
+                changes.push(UserFieldChange::PostsRemoved(removed));
+            }
+            
+            changes
+        }
     }
     
     #[test]
@@ -194,5 +235,57 @@ mod ownership_tests {
             }
         }
     }
+    
+    #[test]
+    fn test_user_diff_name_only() {
+        let before = User::new(1, "Alice".to_string(), "alice@example.com".to_string());
+        let mut after = before.clone();
+        after.name = "Alicia".to_string();
+        
+        assert_eq!(
+            before.diff(&after),
+            vec![UserFieldChange::Name {
+                old: "Alice".to_string(),
+                new: "Alicia".to_string(),
+            }]
+        );
+    }
+    
+    #[test]
+    fn test_user_diff_identical_users() {
+        let mut user = User::new(1, "Bob".to_string(), "bob@example.com".to_string());
+        user.add_post(1);
+        user.add_post(2);
+        
+        assert!(user.diff(&user.clone()).is_empty());
+    }
+    
+    #[test]
+    fn test_user_diff_posts_added_and_removed() {
+        let mut before = User::new(1, "Carol".to_string(), "carol@example.com".to_string());
+        before.add_post(10);
+        before.add_post(20);
+        before.add_post(30);
+        
+        let mut after = User::new(1, "Carol".to_string(), "carol@example.com".to_string());
+        after.add_post(20);
+        after.add_post(40);
+        after.add_post(50);
+        
+        assert_eq!(
+            before.diff(&after),
+            vec![
+                UserFieldChange::PostsAdded(vec![40, 50]),
+                UserFieldChange::PostsRemoved(vec![10, 30]),
+            ]
+        );
+        // En sentido contrario los papeles se invierten
+        assert_eq!(
+            after.diff(&before),
+            vec![
+                UserFieldChange::PostsAdded(vec![10, 30]),
+                UserFieldChange::PostsRemoved(vec![40, 50]),
+            ]
+        );
+    }
 }
-
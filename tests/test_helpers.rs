@@ -0,0 +1,83 @@
+//! Utilidades compartidas por los tests
+//!
+//! Se incluye desde otros tests con `#[macro_use] mod test_helpers;`.
+
+/// Comprueba `condition` cada `poll_interval` hasta que sea `true`
+///
+/// Hace panic con el mensaje (admite formato como `panic!`) si la
+/// condición sigue siendo falsa al cumplirse `timeout`. La última
+/// comprobación se hace justo en el límite, nunca después.
+#[allow(unused_macros)]
+macro_rules! assert_eventually {
+    ($condition:expr, $timeout:expr, $poll_interval:expr, $($message:tt)+) => {{
+        let timeout: ::std::time::Duration = $timeout;
+        let poll_interval: ::std::time::Duration = $poll_interval;
+        let start = ::std::time::Instant::now();
+        loop {
+            if $condition {
+                break;
+            }
+            let elapsed = start.elapsed();
+            if elapsed >= timeout {
+                panic!(
+                    "assert_eventually: la condición no se cumplió en {:?}: {}",
+                    timeout,
+                    format_args!($($message)+)
+                );
+            }
+            // No dormir más allá del límite
+            ::std::thread::sleep(poll_interval.min(timeout - elapsed));
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_helpers_tests {
+    use std::panic;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+    use std::thread;
+    use std::time::{Duration, Instant};
+    
+    #[test]
+    fn test_assert_eventually_detects_late_condition() {
+        let ready = Arc::new(AtomicBool::new(false));
+        let setter = Arc::clone(&ready);
+        let start = Instant::now();
+        
+        let handle = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(50));
+            setter.store(true, Ordering::SeqCst);
+        });
+        
+        assert_eventually!(
+            ready.load(Ordering::SeqCst),
+            Duration::from_millis(500),
+            Duration::from_millis(5),
+            "el flag debería activarse a los 50ms"
+        );
+        let elapsed = start.elapsed();
+        handle.join().unwrap();
+        
+        assert!(elapsed >= Duration::from_millis(50));
+        assert!(elapsed < Duration::from_millis(100), "detectado tarde: {:?}", elapsed);
+    }
+    
+    #[test]
+    fn test_assert_eventually_panics_at_timeout() {
+        let timeout = Duration::from_millis(80);
+        let start = Instant::now();
+        
+        // Intervalo que no divide al timeout: la última espera se recorta
+        let result = panic::catch_unwind(|| {
+            assert_eventually!(false, timeout, Duration::from_millis(30), "nunca se cumple ({})", 42);
+        });
+        let elapsed = start.elapsed();
+        
+        let payload = result.unwrap_err();
+        let message = payload.downcast_ref::<String>().unwrap();
+        assert!(message.contains("nunca se cumple (42)"), "mensaje: {}", message);
+        assert!(elapsed >= timeout);
+        assert!(elapsed < timeout + Duration::from_millis(50), "panic tardío: {:?}", elapsed);
+    }
+}
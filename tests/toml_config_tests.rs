@@ -0,0 +1,238 @@
+//! Tests para la configuración TOML de los ejercicios de error handling
+//!
+//! Requieren la feature: cargo test --features toml-config
+
+#![cfg(feature = "toml-config")]
+
+#[cfg(test)]
+mod toml_config_tests {
+    use serde::{Deserialize, Serialize};
+    
+    // Importar las estructuras del ejercicio
+    #[derive(Debug, Clone, PartialEq)]
+    struct Config {
+        port: u16,
+        host: String,
+        timeout: u64,
+        debug_level: String,
+    }
+    
+    #[derive(Debug, PartialEq)]
+    enum ConfigError {
+        InvalidPort(String),
+        InvalidHost(String),
+        ParseError(String),
+        TomlParse(toml::de::Error),
+        TomlSerialize(toml::ser::Error),
+    }
+    
+    #[derive(Debug, Serialize, Deserialize)]
+    struct TomlFile {
+        server: ServerSection,
+        app: AppSection,
+    }
+    
+    #[derive(Debug, Serialize, Deserialize)]
+    struct ServerSection {
+        port: u16,
+        host: String,
+    }
+    
+    #[derive(Debug, Serialize, Deserialize)]
+    struct AppSection {
+        timeout: u64,
+        #[serde(default = "default_debug_level")]
+        debug_level: String,
+    }
+    
+    fn default_debug_level() -> String {
+        "info".to_string()
+    }
+    
+    #[derive(Debug, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct StrictTomlFile {
+        server: StrictServerSection,
+        app: StrictAppSection,
+    }
+    
+    #[derive(Debug, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct StrictServerSection {
+        port: u16,
+        host: String,
+    }
+    
+    #[derive(Debug, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct StrictAppSection {
+        timeout: u64,
+        #[serde(default = "default_debug_level")]
+        debug_level: String,
+    }
+    
+    impl From<StrictTomlFile> for TomlFile {
+        fn from(file: StrictTomlFile) -> Self {
+            TomlFile {
+                server: ServerSection {
+                    port: file.server.port,
+                    host: file.server.host,
+                },
+                app: AppSection {
+                    timeout: file.app.timeout,
+                    debug_level: file.app.debug_level,
+                },
+            }
+        }
+    }
+    
+    impl TomlFile {
+        fn into_config(self) -> Result<Config, ConfigError> {
+            if self.server.port == 0 {
+                return Err(ConfigError::InvalidPort("Puerto no puede ser 0".to_string()));
+            }
+            if self.server.host.trim().is_empty() {
+                return Err(ConfigError::InvalidHost("Host no puede estar vacío".to_string()));
+            }
+            
+            let mut config = Config::new(self.server.port, self.server.host, self.app.timeout);
+            config.set_debug_level(&self.app.debug_level).map_err(ConfigError::ParseError)?;
+            Ok(config)
+        }
+    }
+    
+    impl Config {
+        fn new(port: u16, host: String, timeout: u64) -> Self {
+            Self {
+                port,
+                host,
+                timeout,
+                debug_level: "info".to_string(),
+            }
+        }
+        
+        fn set_debug_level(&mut self, level: &str) -> Result<(), String> {
+            let valid_levels = ["trace", "debug", "info", "warn", "error"];
+            
+            if valid_levels.contains(&level) {
+                self.debug_level = level.to_string();
+                Ok(())
+            } else {
+                Err(format!("Nivel de debug inválido: {}. Niveles válidos: {:?}", level, valid_levels))
+            }
+        }
+        
+        fn from_toml(toml_str: &str) -> Result<Config, ConfigError> {
+            let file: TomlFile = toml::from_str(toml_str).map_err(ConfigError::TomlParse)?;
+            file.into_config()
+        }
+        
+        fn from_toml_strict(toml_str: &str) -> Result<Config, ConfigError> {
+            let file: StrictTomlFile = toml::from_str(toml_str).map_err(ConfigError::TomlParse)?;
+            TomlFile::from(file).into_config()
+        }
+        
+        fn to_toml(&self) -> Result<String, ConfigError> {
+            let file = TomlFile {
+                server: ServerSection {
+                    port: self.port,
+                    host: self.host.clone(),
+                },
+                app: AppSection {
+                    timeout: self.timeout,
+                    debug_level: self.debug_level.clone(),
+                },
+            };
+            toml::to_string(&file).map_err(ConfigError::TomlSerialize)
+        }
+    }
+    
+    const VALID: &str = "[server]\nport = 8080\nhost = \"localhost\"\n\n[app]\ntimeout = 30\n";
+    
+    #[test]
+    fn test_toml_round_trip() {
+        let mut config = Config::new(9090, "example.com".to_string(), 45);
+        config.set_debug_level("debug").unwrap();
+        
+        let toml_str = config.to_toml().unwrap();
+        assert!(toml_str.contains("[server]"));
+        assert!(toml_str.contains("[app]"));
+        
+        assert_eq!(Config::from_toml(&toml_str), Ok(config.clone()));
+        assert_eq!(Config::from_toml_strict(&toml_str), Ok(config));
+    }
+    
+    #[test]
+    fn test_toml_valid_schema_with_default_debug_level() {
+        let config = Config::from_toml(VALID).unwrap();
+        assert_eq!(config, Config::new(8080, "localhost".to_string(), 30));
+    }
+    
+    #[test]
+    fn test_toml_error_identifies_bad_field() {
+        let bad_port = "[server]\nport = \"abc\"\nhost = \"localhost\"\n\n[app]\ntimeout = 30\n";
+        match Config::from_toml(bad_port) {
+            Err(ConfigError::TomlParse(e)) => {
+                let message = e.to_string();
+                assert!(message.contains("line 2"), "{}", message);
+                assert!(message.contains("port"), "{}", message);
+            }
+            other => panic!("Se esperaba TomlParse, obtenido {:?}", other),
+        }
+        
+        let missing_timeout = "[server]\nport = 8080\nhost = \"localhost\"\n\n[app]\n";
+        match Config::from_toml(missing_timeout) {
+            Err(ConfigError::TomlParse(e)) => assert!(e.to_string().contains("timeout")),
+            other => panic!("Se esperaba TomlParse, obtenido {:?}", other),
+        }
+        
+        assert!(matches!(Config::from_toml("[server\nport = 1"), Err(ConfigError::TomlParse(_))));
+    }
+    
+    #[test]
+    fn test_toml_semantic_validation() {
+        let zero_port = VALID.replace("8080", "0");
+        assert!(matches!(Config::from_toml(&zero_port), Err(ConfigError::InvalidPort(_))));
+        
+        let empty_host = VALID.replace("\"localhost\"", "\"  \"");
+        assert!(matches!(Config::from_toml(&empty_host), Err(ConfigError::InvalidHost(_))));
+        
+        let bad_level = format!("{}debug_level = \"verbose\"\n", VALID);
+        assert!(matches!(Config::from_toml(&bad_level), Err(ConfigError::ParseError(_))));
+    }
+    
+    #[test]
+    fn test_toml_strict_rejects_unknown_keys() {
+        let extra_key = "[server]\nport = 8080\nhost = \"localhost\"\nworkers = 4\n\n[app]\ntimeout = 30\n";
+        assert!(Config::from_toml(extra_key).is_ok());
+        
+        match Config::from_toml_strict(extra_key) {
+            Err(ConfigError::TomlParse(e)) => {
+                assert!(e.message().contains("`workers`"));
+                assert_eq!(e.span().map(|span| &extra_key[span]), Some("workers"));
+            }
+            other => panic!("Se esperaba TomlParse, obtenido {:?}", other),
+        }
+        
+        let extra_section = format!("{}\n[cache]\nsize = 10\n", VALID);
+        match Config::from_toml_strict(&extra_section) {
+            Err(ConfigError::TomlParse(e)) => {
+                assert!(e.message().contains("`cache`"));
+                assert!(e.to_string().contains("line 8"));
+            }
+            other => panic!("Se esperaba TomlParse, obtenido {:?}", other),
+        }
+        
+        assert!(Config::from_toml_strict(VALID).is_ok());
+    }
+    
+    #[test]
+    fn test_to_toml_rejects_timeout_beyond_i64() {
+        let config = Config::new(8080, "localhost".to_string(), u64::MAX);
+        assert!(matches!(config.to_toml(), Err(ConfigError::TomlSerialize(_))));
+        
+        let largest = Config::new(8080, "localhost".to_string(), i64::MAX as u64);
+        let toml_str = largest.to_toml().unwrap();
+        assert_eq!(Config::from_toml(&toml_str), Ok(largest));
+    }
+}